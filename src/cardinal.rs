@@ -1,5 +1,7 @@
 //! Cardinal points, only integer angles (N, NE, E, ..) are supported
 
+use crate::dms::ParseError;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -55,20 +57,40 @@ impl core::fmt::Display for Cardinal {
     }
 }
 
+impl core::str::FromStr for Cardinal {
+    type Err = ParseError;
+    /// Parses a cardinal letter ("N", "SW", ..), case insensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0_u8; 2];
+        let bytes = s.as_bytes();
+        if bytes.is_empty() || bytes.len() > 2 {
+            return Err(ParseError::InvalidCardinal);
+        }
+        for (i, b) in bytes.iter().enumerate() {
+            buf[i] = b.to_ascii_uppercase();
+        }
+        match &buf[..bytes.len()] {
+            b"N" => Ok(Cardinal::North),
+            b"NE" => Ok(Cardinal::NorthEast),
+            b"E" => Ok(Cardinal::East),
+            b"SE" => Ok(Cardinal::SouthEast),
+            b"S" => Ok(Cardinal::South),
+            b"SW" => Ok(Cardinal::SouthWest),
+            b"W" => Ok(Cardinal::West),
+            b"NW" => Ok(Cardinal::NorthWest),
+            _ => Err(ParseError::InvalidCardinal),
+        }
+    }
+}
+
 impl Cardinal {
     /// Returns True if Self matches a latitude cardinal
     pub fn is_latitude(&self) -> bool {
-        match self {
-            Cardinal::North | Cardinal::South => true,
-            _ => false,
-        }
+        matches!(self, Cardinal::North | Cardinal::South)
     }
     /// Returns True if Self matches a longitude cardinal
     pub fn is_longitude(&self) -> bool {
-        match self {
-            Cardinal::East | Cardinal::West => true,
-            _ => false,
-        }
+        matches!(self, Cardinal::East | Cardinal::West)
     }
     /// Returns True if Cardinal and `rhs` represents
     /// same kind of coordinates
@@ -77,35 +99,35 @@ impl Cardinal {
     }
     /// Returns True if Self is a Northern cardinal
     pub fn is_northern(&self) -> bool {
-        match self {
-            Cardinal::North | Cardinal::NorthEast | Cardinal::NorthWest => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Cardinal::North | Cardinal::NorthEast | Cardinal::NorthWest
+        )
     }
     /// Returns True if Self is a Southern cardinal
     pub fn is_southern(&self) -> bool {
-        match self {
-            Cardinal::South | Cardinal::SouthEast | Cardinal::SouthWest => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Cardinal::South | Cardinal::SouthEast | Cardinal::SouthWest
+        )
     }
     /// Returns True if Self is an Eastern cardinal
     pub fn is_eastern(&self) -> bool {
-        match self {
-            Cardinal::East | Cardinal::NorthEast | Cardinal::SouthEast => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Cardinal::East | Cardinal::NorthEast | Cardinal::SouthEast
+        )
     }
     /// Returns True if Self is a Western cardinal
     pub fn is_western(&self) -> bool {
-        match self {
-            Cardinal::West | Cardinal::NorthWest | Cardinal::SouthWest => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Cardinal::West | Cardinal::NorthWest | Cardinal::SouthWest
+        )
     }
     /// Returns True if Self matches a subquadrant cardinal, like NE or SW
     pub fn is_sub_quadrant(&self) -> bool {
-        (self.to_angle() / 45) % 2 > 0
+        !(self.to_angle() / 45).is_multiple_of(2)
     }
     /// Returns compass angle (in D°) associated to Self,
     /// 0° being North Cardinal
@@ -142,4 +164,11 @@ impl Cardinal {
             Cardinal::NorthWest
         }
     }
+
+    /// Returns nearest Cardinal (on an 8 point compass rose)
+    /// to given azimuth, expressed in decimal degrees
+    pub fn nearest(azimuth: f64) -> Cardinal {
+        let sector = (azimuth.rem_euclid(360.0) / 45.0).round() as u16 % 8;
+        Self::from_angle(sector * 45)
+    }
 }
@@ -0,0 +1,241 @@
+//! Geodetic datums and transformations between them.
+//!
+//! A datum is described by its reference ellipsoid and the 7 parameter
+//! Helmert transformation from its ECEF frame to the WGS84 ECEF frame.
+//! Coordinates are transformed from one datum to another through WGS84,
+//! either with the Helmert transformation on ECEF coordinates (exact for
+//! given parameters, altitude included), or with the standard Molodensky
+//! formulas, which directly shift geodetic coordinates but only account
+//! for the translation part of the parameters.
+//!
+//! Built-in parameters are the usual published "mean" values,
+//! valid over the whole area covered by each datum: expect meter level
+//! accuracy, for legacy data. Use local parameters for better results.
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+use crate::dms::{crosses_pole, fold_latitude, wrap_longitude};
+use crate::Ellipsoid;
+
+/// Number of radians in an arc second
+const ARC_SECOND: f64 = core::f64::consts::PI / 648_000.0;
+
+/// 7 parameter Helmert transformation, using the
+/// position vector convention (EPSG:9606)
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Helmert {
+    /// X translation, in meters
+    pub tx: f64,
+    /// Y translation, in meters
+    pub ty: f64,
+    /// Z translation, in meters
+    pub tz: f64,
+    /// Rotation around the X axis, in arc seconds
+    pub rx: f64,
+    /// Rotation around the Y axis, in arc seconds
+    pub ry: f64,
+    /// Rotation around the Z axis, in arc seconds
+    pub rz: f64,
+    /// Scale correction, in parts per million
+    pub scale: f64,
+}
+
+impl Helmert {
+    /// Builds a 3 parameter transformation (translations only)
+    pub const fn translation(tx: f64, ty: f64, tz: f64) -> Self {
+        Self::new(tx, ty, tz, 0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Builds a 7 parameter transformation, translations in meters,
+    /// rotations in arc seconds and scale correction in ppm
+    pub const fn new(tx: f64, ty: f64, tz: f64, rx: f64, ry: f64, rz: f64, scale: f64) -> Self {
+        Self {
+            tx,
+            ty,
+            tz,
+            rx,
+            ry,
+            rz,
+            scale,
+        }
+    }
+
+    /// Applies this transformation to given ECEF (x, y, z) coordinates, in meters
+    pub fn apply(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let (rx, ry, rz) = (
+            self.rx * ARC_SECOND,
+            self.ry * ARC_SECOND,
+            self.rz * ARC_SECOND,
+        );
+        let s = 1.0 + self.scale * 1.0E-6;
+        (
+            self.tx + s * (x - rz * y + ry * z),
+            self.ty + s * (rz * x + y - rx * z),
+            self.tz + s * (-ry * x + rx * y + z),
+        )
+    }
+
+    /// Applies the inverse of this transformation to given ECEF (x, y, z)
+    /// coordinates, in meters. The inverse is exact, not approximated
+    /// by negating the parameters.
+    pub fn apply_inverse(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let (rx, ry, rz) = (
+            self.rx * ARC_SECOND,
+            self.ry * ARC_SECOND,
+            self.rz * ARC_SECOND,
+        );
+        let s = 1.0 + self.scale * 1.0E-6;
+        let (x, y, z) = ((x - self.tx) / s, (y - self.ty) / s, (z - self.tz) / s);
+        // (I + [r]x)^-1 = (I - [r]x + r r^T) / (1 + |r|²)
+        let dot = rx * x + ry * y + rz * z;
+        let norm = 1.0 + rx * rx + ry * ry + rz * rz;
+        (
+            (x + rz * y - ry * z + rx * dot) / norm,
+            (-rz * x + y + rx * z + ry * dot) / norm,
+            (ry * x - rx * y + z + rz * dot) / norm,
+        )
+    }
+}
+
+/// Transformation method between two datums
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Transformation {
+    /// 7 parameter Helmert transformation, on ECEF coordinates
+    #[default]
+    Helmert,
+    /// Standard Molodensky transformation, on geodetic coordinates.
+    /// Rotations and scale correction are not taken into account.
+    Molodensky,
+}
+
+/// Geodetic datum: a reference ellipsoid, and its
+/// position with respect to WGS84
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Datum {
+    /// Reference ellipsoid
+    pub ellipsoid: Ellipsoid,
+    /// Transformation from this datum to WGS84
+    pub to_wgs84: Helmert,
+}
+
+impl Default for Datum {
+    /// Builds WGS84 datum
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
+impl Datum {
+    /// World Geodetic System 1984, used by GPS
+    pub const WGS84: Self = Self::new(Ellipsoid::WGS84, Helmert::translation(0.0, 0.0, 0.0));
+    /// World Geodetic System 1972 (EPSG:1238)
+    pub const WGS72: Self = Self::new(
+        Ellipsoid::WGS72,
+        Helmert::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554, 0.2263),
+    );
+    /// European Terrestrial Reference System 1989,
+    /// equivalent to WGS84 at the meter level
+    pub const ETRS89: Self = Self::new(Ellipsoid::GRS80, Helmert::translation(0.0, 0.0, 0.0));
+    /// North American Datum 1983, equivalent to WGS84 at the meter level
+    pub const NAD83: Self = Self::new(Ellipsoid::GRS80, Helmert::translation(0.0, 0.0, 0.0));
+    /// North American Datum 1927, mean for the conterminous United States
+    pub const NAD27: Self = Self::new(
+        Ellipsoid::CLARKE_1866,
+        Helmert::translation(-8.0, 160.0, 176.0),
+    );
+    /// European Datum 1950, mean for Western Europe
+    pub const ED50: Self = Self::new(
+        Ellipsoid::INTERNATIONAL_1924,
+        Helmert::translation(-87.0, -98.0, -121.0),
+    );
+    /// Ordnance Survey Great Britain 1936, as published by the Ordnance Survey
+    pub const OSGB36: Self = Self::new(
+        Ellipsoid::AIRY_1830,
+        Helmert::new(446.448, -125.157, 542.060, 0.1502, 0.2470, 0.8421, -20.4894),
+    );
+    /// Tokyo datum, mean for Japan
+    pub const TOKYO: Self = Self::new(
+        Ellipsoid::BESSEL_1841,
+        Helmert::translation(-148.0, 507.0, 685.0),
+    );
+    /// Australian Geodetic Datum 1966, mean for Australia
+    pub const AGD66: Self = Self::new(
+        Ellipsoid::AUSTRALIAN_NATIONAL,
+        Helmert::translation(-133.0, -48.0, 148.0),
+    );
+
+    /// Builds a datum from its ellipsoid and its transformation to WGS84
+    pub const fn new(ellipsoid: Ellipsoid, to_wgs84: Helmert) -> Self {
+        Self {
+            ellipsoid,
+            to_wgs84,
+        }
+    }
+
+    /// Transforms geodetic coordinates expressed in Self: (latitude, longitude)
+    /// in decimal degrees and height above the ellipsoid in meters,
+    /// to geodetic coordinates expressed in `target` datum
+    pub fn transform(
+        &self,
+        target: &Self,
+        coords: (f64, f64, f64),
+        method: Transformation,
+    ) -> (f64, f64, f64) {
+        match method {
+            Transformation::Helmert => {
+                let (x, y, z) = self
+                    .ellipsoid
+                    .geodetic_to_ecef(coords.0, coords.1, coords.2);
+                let (x, y, z) = self.to_wgs84.apply(x, y, z);
+                let (x, y, z) = target.to_wgs84.apply_inverse(x, y, z);
+                target.ellipsoid.ecef_to_geodetic(x, y, z)
+            }
+            Transformation::Molodensky => self.molodensky(target, coords),
+        }
+    }
+
+    /// Standard Molodensky formulas. Latitudes are folded back over the poles,
+    /// and longitudes wrapped into `]-180°, 180°]`
+    fn molodensky(&self, target: &Self, coords: (f64, f64, f64)) -> (f64, f64, f64) {
+        let (dx, dy, dz) = (
+            self.to_wgs84.tx - target.to_wgs84.tx,
+            self.to_wgs84.ty - target.to_wgs84.ty,
+            self.to_wgs84.tz - target.to_wgs84.tz,
+        );
+        let (a, f) = (self.ellipsoid.semi_major_axis, self.ellipsoid.flattening);
+        let da = target.ellipsoid.semi_major_axis - a;
+        let df = target.ellipsoid.flattening - f;
+        let b = self.ellipsoid.semi_minor_axis();
+        let e2 = self.ellipsoid.eccentricity_squared();
+        let (phi, lambda, h) = (coords.0.to_radians(), coords.1.to_radians(), coords.2);
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let w2 = 1.0 - e2 * sin_phi * sin_phi;
+        // radii of curvature, in the meridian and the prime vertical
+        let m = a * (1.0 - e2) / w2.powf(1.5);
+        let n = a / w2.sqrt();
+
+        let dphi = (-dx * sin_phi * cos_lambda - dy * sin_phi * sin_lambda
+            + dz * cos_phi
+            + da * n * e2 * sin_phi * cos_phi / a
+            + df * (m * a / b + n * b / a) * sin_phi * cos_phi)
+            / (m + h);
+        // longitude is undefined at the poles, where it is left untouched
+        let dlambda = if cos_phi.abs() < 1.0E-12 {
+            0.0
+        } else {
+            (-dx * sin_lambda + dy * cos_lambda) / ((n + h) * cos_phi)
+        };
+        let dh = dx * cos_phi * cos_lambda + dy * cos_phi * sin_lambda + dz * sin_phi - da * a / n
+            + df * b / a * n * sin_phi * sin_phi;
+        let (latitude, longitude) = ((phi + dphi).to_degrees(), (lambda + dlambda).to_degrees());
+        let longitude = if crosses_pole(latitude) {
+            longitude + 180.0
+        } else {
+            longitude
+        };
+        (fold_latitude(latitude), wrap_longitude(longitude), h + dh)
+    }
+}
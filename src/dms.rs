@@ -1,7 +1,7 @@
 //! Angle representation in D°M'S" (sexagesimal format).
 //! Supports arithmetics operation, up to double precision,
 //! for easy navigation calculations.
-use crate::{cardinal::Cardinal, Error};
+use crate::{cardinal::Cardinal, format::DelayedFormat, Error};
 
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
@@ -12,7 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 /// When a cardinal is associated to this angle,
 /// we consider this angle represents either a Latitude
 /// or a Longitude angle.
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DMS {
     /// Degrees D°
@@ -23,9 +23,25 @@ pub struct DMS {
     pub seconds: f64,
     /// Optionnal cardinal associated to this angle
     pub cardinal: Option<Cardinal>,
+    /// Sign of angles with no cardinal, ignored when a cardinal
+    /// is associated, refer to [DMS::is_negative]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub negative: bool,
 }
 
-#[derive(Debug, Copy, Clone)]
+impl PartialEq for DMS {
+    /// The sign is only compared for angles with no cardinal,
+    /// others being signed by their cardinal
+    fn eq(&self, rhs: &Self) -> bool {
+        self.degrees == rhs.degrees
+            && self.minutes == rhs.minutes
+            && self.seconds == rhs.seconds
+            && self.cardinal == rhs.cardinal
+            && self.is_negative() == rhs.is_negative()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Scale {
     /// Countries scale is 1°0'0"
@@ -48,6 +64,122 @@ pub enum Scale {
     PreciseSurveying,
 }
 
+/// Policy applied to angles falling out of their valid range:
+/// `[-90°, 90°]` for latitudes, `[-180°, 180°]` for longitudes
+/// and `[-360°, 360°]` for angles with no cardinal.
+/// Angles within range are left untouched, except a full turn which
+/// gets wrapped to 0° when the angle has no cardinal.
+/// Policies return an error for NaN and infinite angles.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Normalization {
+    /// Wraps angles modulo the width of their range:
+    /// 95°N becomes 85°S, 190°E becomes 170°W and -370° becomes -10°
+    Wrap,
+    /// Folds latitudes back over the poles, 95°N becomes 85°N,
+    /// other angles are wrapped. When applied to 3D coordinates,
+    /// the longitude moves to the opposite meridian.
+    /// This is the policy used by constructors and arithmetic operators
+    #[default]
+    Fold,
+    /// Clamps angles to the closest bound: 95°N becomes 90°N, 190°E becomes 180°E
+    Clamp,
+    /// Rejects out of range angles with an error
+    Reject,
+}
+
+/// Amount of seconds in a full turn
+const TURN_SECONDS: f64 = 360.0 * 3600.0;
+
+/// Folds given latitude, in decimal degrees, over the poles
+pub(crate) fn fold_latitude(angle: f64) -> f64 {
+    if (-90.0..=90.0).contains(&angle) {
+        return angle;
+    }
+    let angle = angle.rem_euclid(360.0);
+    if angle <= 90.0 {
+        angle
+    } else if angle < 270.0 {
+        180.0 - angle
+    } else {
+        angle - 360.0
+    }
+}
+
+/// Returns true if folding given latitude, in decimal degrees,
+/// goes over an odd number of poles
+pub(crate) fn crosses_pole(angle: f64) -> bool {
+    let angle = angle.rem_euclid(360.0);
+    angle > 90.0 && angle < 270.0
+}
+
+/// Wraps given longitude, in decimal degrees, across the antimeridian
+/// into `]-180°, 180°]`
+pub(crate) fn wrap_longitude(angle: f64) -> f64 {
+    if (-180.0..=180.0).contains(&angle) {
+        return angle;
+    }
+    180.0 - (180.0 - angle).rem_euclid(360.0)
+}
+
+/// Wraps given angle into `]-turn, turn[`, keeping its sign
+fn wrap_turn(angle: f64, turn: f64) -> f64 {
+    angle % turn
+}
+
+impl Normalization {
+    /// Normalizes given latitude, expressed in decimal degrees
+    pub fn latitude(&self, angle: f64) -> Result<f64, Error> {
+        if (-90.0..=90.0).contains(&angle) {
+            return Ok(angle);
+        }
+        match self {
+            Self::Wrap if angle.is_finite() => Ok((angle + 90.0).rem_euclid(180.0) - 90.0),
+            Self::Fold if angle.is_finite() => Ok(fold_latitude(angle)),
+            Self::Clamp if angle.is_finite() => Ok(angle.clamp(-90.0, 90.0)),
+            _ => Err(Error::InvalidLatitude),
+        }
+    }
+
+    /// Normalizes given longitude, expressed in decimal degrees
+    pub fn longitude(&self, angle: f64) -> Result<f64, Error> {
+        if (-180.0..=180.0).contains(&angle) {
+            return Ok(angle);
+        }
+        match self {
+            Self::Wrap | Self::Fold if angle.is_finite() => Ok(wrap_longitude(angle)),
+            Self::Clamp if angle.is_finite() => Ok(angle.clamp(-180.0, 180.0)),
+            _ => Err(Error::InvalidLongitude),
+        }
+    }
+
+    /// Normalizes given angle with no cardinal, expressed in decimal degrees
+    pub fn angle(&self, angle: f64) -> Result<f64, Error> {
+        self.turn(angle, 360.0)
+    }
+
+    /// Normalizes a pair of (latitude, longitude) angles, expressed in decimal degrees.
+    /// Folding a latitude over a pole moves the longitude to the opposite meridian
+    pub fn coordinates(&self, latitude: f64, longitude: f64) -> Result<(f64, f64), Error> {
+        let longitude = if *self == Self::Fold && crosses_pole(latitude) {
+            longitude + 180.0
+        } else {
+            longitude
+        };
+        Ok((self.latitude(latitude)?, self.longitude(longitude)?))
+    }
+
+    /// Normalizes given angle with no cardinal, `turn` being a full turn in its unit
+    fn turn(&self, angle: f64, turn: f64) -> Result<f64, Error> {
+        match self {
+            Self::Wrap | Self::Fold if angle.is_finite() => Ok(wrap_turn(angle, turn)),
+            _ if (-turn..=turn).contains(&angle) => Ok(angle),
+            Self::Clamp if angle.is_finite() => Ok(angle.clamp(-turn, turn)),
+            _ => Err(Error::InvalidAngle),
+        }
+    }
+}
+
 impl core::fmt::Display for DMS {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(cardinal) = self.cardinal {
@@ -57,11 +189,213 @@ impl core::fmt::Display for DMS {
                 self.degrees, self.minutes, self.seconds, cardinal,
             )
         } else {
+            if self.is_negative() {
+                write!(f, "-")?;
+            }
             write!(f, "{}°{}'{:.4}\"", self.degrees, self.minutes, self.seconds)
         }
     }
 }
 
+/// Errors returned when parsing a D°M'S" angle from a string
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Input string is empty
+    EmptyString,
+    /// Degrees field is missing, not a number,
+    /// or out of range for the associated cardinal
+    InvalidDegrees,
+    /// Minutes field is not a number, or not within 0 <= M' < 60
+    InvalidMinutes,
+    /// Seconds field is not a number, or not within 0 <= S" < 60
+    InvalidSeconds,
+    /// Unknown cardinal, or cardinal given more than once
+    InvalidCardinal,
+    /// Both a sign and a cardinal were given
+    SignAndCardinal,
+    /// Input does not match any known sexagesimal notation
+    FormatNotRecognized,
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            ParseError::EmptyString => write!(f, "empty string"),
+            ParseError::InvalidDegrees => write!(f, "invalid degrees"),
+            ParseError::InvalidMinutes => write!(f, "invalid minutes"),
+            ParseError::InvalidSeconds => write!(f, "invalid seconds"),
+            ParseError::InvalidCardinal => write!(f, "invalid cardinal"),
+            ParseError::SignAndCardinal => write!(f, "both sign and cardinal specified"),
+            ParseError::FormatNotRecognized => write!(f, "format not recognized"),
+        }
+    }
+}
+
+impl core::str::FromStr for DMS {
+    type Err = ParseError;
+    /// Parses a D°M'S" angle from any of the common sexagesimal notations:
+    ///   - `40°43'50.196"N` (`Display` output), `40°43′50.196″N`
+    ///   - `40 43 50.196 N`, `40:43:50.196n`
+    ///   - `N40°43.8366'` (degrees and decimal minutes)
+    ///   - `40.730610`, `-40.730610` (decimal degrees)
+    ///
+    /// Cardinal may either prefix or suffix the angle, and is case insensitive.
+    /// Angles with no cardinal associated to them may be signed, and must not
+    /// exceed 360°, latitudes 90° and longitudes 180°.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseError::EmptyString);
+        }
+        // D°, M', S" fields, as found in the input string
+        let mut fields: [Option<&str>; 3] = [None; 3];
+        let mut next: usize = 0;
+        let mut negative: Option<bool> = None;
+        let mut cardinal: Option<Cardinal> = None;
+        let mut trailing_cardinal = false;
+
+        let mut chars = s.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c.is_whitespace() || c == ':' {
+                continue;
+            }
+            if trailing_cardinal {
+                // nothing should follow a trailing cardinal
+                return Err(ParseError::FormatNotRecognized);
+            }
+            if c == '+' || c == '-' {
+                if negative.is_some() || next > 0 {
+                    return Err(ParseError::FormatNotRecognized);
+                }
+                negative = Some(c == '-');
+            } else if c.is_ascii_digit() || c == '.' {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_ascii_digit() || c == '.' {
+                        end = i + c.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let index = match chars.peek() {
+                    Some(&(_, '°')) | Some(&(_, 'º')) | Some(&(_, '˚')) => {
+                        chars.next();
+                        0
+                    }
+                    Some(&(_, '"')) | Some(&(_, '″')) | Some(&(_, '”')) => {
+                        chars.next();
+                        2
+                    }
+                    Some(&(_, '\'')) | Some(&(_, '′')) | Some(&(_, '’')) | Some(&(_, '´')) => {
+                        chars.next();
+                        // two single quotes stand for seconds
+                        match chars.peek() {
+                            Some(&(_, '\'')) | Some(&(_, '′')) | Some(&(_, '’')) => {
+                                chars.next();
+                                2
+                            }
+                            _ => 1,
+                        }
+                    }
+                    _ => next,
+                };
+                if index < next || index > 2 {
+                    return Err(match index {
+                        0 => ParseError::InvalidDegrees,
+                        1 => ParseError::InvalidMinutes,
+                        2 => ParseError::InvalidSeconds,
+                        _ => ParseError::FormatNotRecognized,
+                    });
+                }
+                fields[index] = Some(&s[start..end]);
+                next = index + 1;
+            } else if c.is_alphabetic() {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_alphabetic() {
+                        end = i + c.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if cardinal.is_some() {
+                    return Err(ParseError::InvalidCardinal);
+                }
+                cardinal = Some(s[start..end].parse::<Cardinal>()?);
+                trailing_cardinal = next > 0;
+            } else {
+                return Err(ParseError::FormatNotRecognized);
+            }
+        }
+
+        if cardinal.is_some() && negative.is_some() {
+            return Err(ParseError::SignAndCardinal);
+        }
+
+        // only the last field may have a fractionnal part
+        let last = match fields.iter().rposition(|f| f.is_some()) {
+            Some(last) => last,
+            None => return Err(ParseError::InvalidDegrees),
+        };
+        let mut values = [0.0_f64; 3];
+        for (i, field) in fields.iter().enumerate().take(last + 1) {
+            let err = match i {
+                0 => ParseError::InvalidDegrees,
+                1 => ParseError::InvalidMinutes,
+                _ => ParseError::InvalidSeconds,
+            };
+            let field = field.ok_or(err)?;
+            if i < last && field.contains('.') {
+                return Err(err);
+            }
+            values[i] = field.parse::<f64>().map_err(|_| err)?;
+        }
+        let (degrees, minutes, seconds) = (values[0], values[1], values[2]);
+        if minutes >= 60.0 {
+            return Err(ParseError::InvalidMinutes);
+        }
+        if seconds >= 60.0 {
+            return Err(ParseError::InvalidSeconds);
+        }
+
+        // range is checked on the raw value, before any wrapping
+        let max_degrees = match cardinal {
+            Some(c) if c.is_latitude() => 90.0,
+            Some(c) if c.is_longitude() => 180.0,
+            _ => 360.0,
+        };
+        if degrees + minutes / 60.0 + seconds / 3600.0 > max_degrees {
+            return Err(ParseError::InvalidDegrees);
+        }
+
+        let dms = match last {
+            0 => Self::from_ddeg_angle(degrees),
+            1 => Self {
+                degrees: degrees as u16,
+                minutes: minutes.floor() as u8,
+                seconds: minutes.fract() * 60.0,
+                cardinal: None,
+                negative: false,
+            },
+            _ => Self {
+                degrees: degrees as u16,
+                minutes: minutes as u8,
+                seconds,
+                cardinal: None,
+                negative: false,
+            },
+        };
+
+        match (cardinal, negative) {
+            (Some(cardinal), _) => Ok(dms.with_cardinal(cardinal)),
+            (None, Some(true)) => Ok(-dms),
+            _ => Ok(dms),
+        }
+    }
+}
+
 impl Default for DMS {
     /// Builds null angle with no Cardinal associated to it
     fn default() -> Self {
@@ -70,6 +404,7 @@ impl Default for DMS {
             minutes: 0,
             seconds: 0.0_f64,
             cardinal: None,
+            negative: false,
         }
     }
 }
@@ -135,12 +470,12 @@ impl core::ops::Add<DMS> for DMS {
                 }
             } else {
                 Ok(Self::from_seconds(
-                    self.total_seconds() + rhs.total_seconds(),
+                    self.signed_seconds() + rhs.signed_seconds(),
                 ))
             }
         } else {
             Ok(Self::from_seconds(
-                self.total_seconds() + rhs.total_seconds(),
+                self.signed_seconds() + rhs.signed_seconds(),
             ))
         }
     }
@@ -157,10 +492,58 @@ impl core::ops::AddAssign<DMS> for DMS {
                     *self = Self::from_ddeg_longitude(a)
                 }
             } else {
-                *self = Self::from_seconds(self.total_seconds() + rhs.total_seconds())
+                *self = Self::from_seconds(self.signed_seconds() + rhs.signed_seconds())
             }
         } else {
-            *self = Self::from_seconds(self.total_seconds() + rhs.total_seconds())
+            *self = Self::from_seconds(self.signed_seconds() + rhs.signed_seconds())
+        }
+    }
+}
+
+impl core::ops::Neg for DMS {
+    type Output = Self;
+    /// Returns the opposite angle: cardinal is reversed (N/S, E/W)
+    /// when one is associated, the angle changes sign otherwise
+    fn neg(self) -> Self {
+        match self.cardinal {
+            Some(cardinal) => self.with_cardinal(cardinal + 180),
+            None => Self {
+                negative: !self.negative && self.total_seconds() > 0.0,
+                ..self
+            },
+        }
+    }
+}
+
+impl core::ops::Sub<DMS> for DMS {
+    type Output = Result<Self, Error>;
+    /// Substracts `rhs` from Self, following the same rules as (+):
+    /// latitudes and longitudes can only be combined with one another,
+    /// angles with no cardinal may turn negative
+    fn sub(self, rhs: Self) -> Result<Self, Error> {
+        match (self.cardinal, rhs.cardinal) {
+            (Some(c0), Some(c1)) => {
+                let a = self.to_ddeg_angle() - rhs.to_ddeg_angle();
+                if c0.is_latitude() && c1.is_latitude() {
+                    Ok(Self::from_ddeg_latitude(a))
+                } else if c0.is_longitude() && c1.is_longitude() {
+                    Ok(Self::from_ddeg_longitude(a))
+                } else {
+                    Err(Error::IncompatibleCardinals)
+                }
+            }
+            _ => Ok(Self::from_seconds(
+                self.signed_seconds() - rhs.signed_seconds(),
+            )),
+        }
+    }
+}
+
+impl core::ops::SubAssign<DMS> for DMS {
+    /// Self is left untouched when cardinals are not compatible
+    fn sub_assign(&mut self, rhs: Self) {
+        if let Ok(dms) = *self - rhs {
+            *self = dms
         }
     }
 }
@@ -175,7 +558,7 @@ impl core::ops::AddAssign<f64> for DMS {
                 *self = Self::from_ddeg_longitude(a)
             }
         } else {
-            *self = Self::from_seconds(self.total_seconds() + rhs)
+            *self = Self::from_seconds(self.signed_seconds() + rhs)
         }
     }
 }
@@ -191,7 +574,7 @@ impl core::ops::Add<f64> for DMS {
                 Self::from_ddeg_longitude(a)
             }
         } else {
-            Self::from_seconds(self.total_seconds() + rhs)
+            Self::from_seconds(self.signed_seconds() + rhs)
         }
     }
 }
@@ -207,7 +590,7 @@ impl core::ops::Sub<f64> for DMS {
                 Self::from_ddeg_longitude(a)
             }
         } else {
-            Self::from_seconds(self.total_seconds() - rhs)
+            Self::from_seconds(self.signed_seconds() - rhs)
         }
     }
 }
@@ -222,7 +605,7 @@ impl core::ops::SubAssign<f64> for DMS {
                 *self = Self::from_ddeg_longitude(a)
             }
         } else {
-            *self = Self::from_seconds(self.total_seconds() - rhs)
+            *self = Self::from_seconds(self.signed_seconds() - rhs)
         }
     }
 }
@@ -238,7 +621,7 @@ impl core::ops::Mul<f64> for DMS {
                 Self::from_ddeg_longitude(a)
             }
         } else {
-            Self::from_seconds(self.total_seconds() * rhs)
+            Self::from_seconds(self.signed_seconds() * rhs)
         }
     }
 }
@@ -254,7 +637,7 @@ impl core::ops::Div<f64> for DMS {
                 Self::from_ddeg_longitude(a)
             }
         } else {
-            Self::from_seconds(self.total_seconds() / rhs)
+            Self::from_seconds(self.signed_seconds() / rhs)
         }
     }
 }
@@ -269,7 +652,7 @@ impl core::ops::MulAssign<f64> for DMS {
                 *self = Self::from_ddeg_longitude(a)
             }
         } else {
-            *self = Self::from_seconds(self.total_seconds() * rhs)
+            *self = Self::from_seconds(self.signed_seconds() * rhs)
         }
     }
 }
@@ -284,34 +667,85 @@ impl core::ops::DivAssign<f64> for DMS {
                 *self = Self::from_ddeg_longitude(a)
             }
         } else {
-            *self = Self::from_seconds(self.total_seconds() / rhs)
+            *self = Self::from_seconds(self.signed_seconds() / rhs)
         }
     }
 }
 
 impl DMS {
     /// Builds `D°M'S"` angle, from given D°, M', S" values.
-    /// This method allows overflow, it will wrapp values to correct range
-    /// itself.
+    /// This method allows overflow: M' and S" carry over to the upper units,
+    /// and negative amounts reverse the cardinal. Angles out of range are
+    /// normalized with the default [Normalization] policy, like [DMS::from_ddeg_latitude]
+    /// and [DMS::from_ddeg_longitude] do.
+    /// Folding a latitude does not move any longitude: use
+    /// [DMS3d::from_ddeg_angles](crate::DMS3d::from_ddeg_angles) for positions
+    /// that go over a pole.
     pub fn new(degrees: u16, minutes: u8, seconds: f64, cardinal: Option<Cardinal>) -> DMS {
-        let d = Self::from_seconds(degrees as f64 * 3600.0 + minutes as f64 * 60.0 + seconds);
-        if let Some(cardinal) = cardinal {
-            d.with_cardinal(cardinal)
-        } else {
-            d
+        let dms = Self::from_fields(degrees, minutes, seconds, cardinal);
+        dms.normalize(Normalization::default()).unwrap_or(dms)
+    }
+
+    /// Builds `D°M'S"` angle, from given D°, M', S" values,
+    /// normalized with given policy
+    pub fn new_with(
+        degrees: u16,
+        minutes: u8,
+        seconds: f64,
+        cardinal: Option<Cardinal>,
+        normalization: Normalization,
+    ) -> Result<DMS, Error> {
+        Self::from_fields(degrees, minutes, seconds, cardinal).normalize(normalization)
+    }
+
+    /// Builds `D°M'S"` angle from given fields, carrying M' and S" over,
+    /// but not normalized
+    fn from_fields(degrees: u16, minutes: u8, seconds: f64, cardinal: Option<Cardinal>) -> Self {
+        if minutes < 60 && (0.0..60.0).contains(&seconds) {
+            // fields are kept as is
+            return Self {
+                degrees,
+                minutes,
+                seconds,
+                cardinal,
+                negative: false,
+            };
+        }
+        let total = degrees as f64 * 3600.0 + minutes as f64 * 60.0 + seconds;
+        match cardinal {
+            Some(c) if total < 0.0 => Self::split_seconds(-total).with_cardinal(c + 180),
+            Some(c) => Self::split_seconds(total).with_cardinal(c),
+            None => Self::split_seconds(total),
         }
     }
 
-    /// Builds `D°M'S"` angle from total amount of seconds
+    /// Builds `D°M'S"` angle from total amount of seconds,
+    /// wrapped to `|D°| < 360`, negative amounts giving negative angles
     pub fn from_seconds(seconds: f64) -> Self {
+        Self::split_seconds(wrap_turn(seconds, TURN_SECONDS))
+    }
+
+    /// Builds `D°M'S"` angle from total amount of seconds,
+    /// normalized with given policy
+    pub fn from_seconds_with(seconds: f64, normalization: Normalization) -> Result<Self, Error> {
+        Ok(Self::split_seconds(
+            normalization.turn(seconds, TURN_SECONDS)?,
+        ))
+    }
+
+    /// Splits given amount of seconds, within range, into D°, M', S" fields,
+    /// negative amounts giving negative angles
+    fn split_seconds(total: f64) -> Self {
+        let seconds = total.abs();
         let degrees = (seconds / 3600.0).floor();
         let minutes = ((seconds - degrees * 3600.0) / 60.0).floor();
         let integer = ((seconds - degrees * 3600.0 - minutes * 60.0).floor() as u8) % 60;
         Self {
-            degrees: (degrees as u16) % 360,
+            degrees: degrees as u16,
             minutes: minutes as u8,
             seconds: integer as f64 + seconds.fract(),
             cardinal: None,
+            negative: total < 0.0 && seconds > 0.0,
         }
     }
 
@@ -324,63 +758,119 @@ impl DMS {
             minutes: self.minutes,
             seconds: self.seconds,
             cardinal: Some(cardinal),
+            negative: false,
         }
     }
 
     /// Builds D°M'S" angle from given angle expressed in
-    /// decimal degrees, with no cardinal associated to returned value
+    /// decimal degrees, with no cardinal associated to returned value.
+    /// The angle is wrapped to `|D°| < 360`, and keeps its sign
     pub fn from_ddeg_angle(angle: f64) -> Self {
-        let degrees = angle.abs().floor();
-        let minutes = ((angle.abs() - degrees) * 60.0).floor();
-        let seconds = (angle.abs() - degrees - minutes / 60.0_f64) * 3600.0_f64;
+        let negative = angle < 0.0;
+        let angle = wrap_turn(angle.abs(), 360.0);
+        let degrees = angle.floor();
+        let minutes = ((angle - degrees) * 60.0).floor();
+        let seconds = (angle - degrees - minutes / 60.0_f64) * 3600.0_f64;
         Self {
             degrees: degrees as u16,
             minutes: minutes as u8,
             seconds,
             cardinal: None,
+            negative: negative && angle > 0.0,
         }
     }
 
     /// Builds Latitude angle, expressed in D°M'S", from
-    /// given angle expressed in decimal degrees
+    /// given angle expressed in decimal degrees,
+    /// folded over the poles when out of range (see [Normalization::Fold])
     pub fn from_ddeg_latitude(angle: f64) -> Self {
-        let degrees = angle.abs().floor();
-        let minutes = ((angle.abs() - degrees) * 60.0).floor();
-        let seconds = (angle.abs() - degrees - minutes / 60.0_f64) * 3600.0_f64;
+        Self::latitude(fold_latitude(angle))
+    }
+
+    /// Builds Latitude angle, expressed in D°M'S", from given angle
+    /// expressed in decimal degrees, normalized with given policy
+    pub fn from_ddeg_latitude_with(
+        angle: f64,
+        normalization: Normalization,
+    ) -> Result<Self, Error> {
+        Ok(Self::latitude(normalization.latitude(angle)?))
+    }
+
+    /// Builds Longitude angle, expressed in D°M'S",
+    /// from given angle expressed in decimal degrees,
+    /// wrapped across the antimeridian when out of range
+    pub fn from_ddeg_longitude(angle: f64) -> Self {
+        Self::longitude(wrap_longitude(angle))
+    }
+
+    /// Builds Longitude angle, expressed in D°M'S", from given angle
+    /// expressed in decimal degrees, normalized with given policy
+    pub fn from_ddeg_longitude_with(
+        angle: f64,
+        normalization: Normalization,
+    ) -> Result<Self, Error> {
+        Ok(Self::longitude(normalization.longitude(angle)?))
+    }
+
+    /// Builds Latitude angle from given angle within range
+    fn latitude(angle: f64) -> Self {
         let cardinal = if angle < 0.0 {
             Cardinal::South
         } else {
             Cardinal::North
         };
-        Self {
-            degrees: (degrees as u16) % 90,
-            minutes: minutes as u8,
-            seconds,
-            cardinal: Some(cardinal),
-        }
+        Self::from_ddeg_angle(angle).with_cardinal(cardinal)
     }
 
-    /// Builds Longitude angle, expressed in D°M'S",
-    /// from given angle expressed in decimal degrees
-    pub fn from_ddeg_longitude(angle: f64) -> Self {
-        let degrees = angle.abs().floor();
-        let minutes = ((angle.abs() - degrees) * 60.0).floor();
-        let seconds = (angle.abs() - degrees - minutes / 60.0_f64) * 3600.0_f64;
+    /// Builds Longitude angle from given angle within range
+    fn longitude(angle: f64) -> Self {
         let cardinal = if angle < 0.0 {
             Cardinal::West
         } else {
             Cardinal::East
         };
-        Self {
-            degrees: (degrees as u16) % 180,
-            minutes: minutes as u8,
-            seconds,
-            cardinal: Some(cardinal),
+        Self::from_ddeg_angle(angle).with_cardinal(cardinal)
+    }
+
+    /// Returns a copy of Self normalized with given policy,
+    /// according to its cardinal. Angles within range and with
+    /// valid M', S" fields are returned untouched
+    pub fn normalize(&self, normalization: Normalization) -> Result<Self, Error> {
+        if self.is_normalized() {
+            return Ok(*self);
+        }
+        match self.cardinal {
+            Some(c) if c.is_latitude() => {
+                Self::from_ddeg_latitude_with(self.to_ddeg_angle(), normalization)
+            }
+            Some(c) if c.is_longitude() => {
+                Self::from_ddeg_longitude_with(self.to_ddeg_angle(), normalization)
+            }
+            cardinal => {
+                let dms = Self::from_seconds_with(self.signed_seconds(), normalization)?;
+                Ok(match cardinal {
+                    Some(c) => dms.with_cardinal(c),
+                    None => dms,
+                })
+            }
         }
     }
 
+    /// Returns true if M' and S" are within range,
+    /// and Self within the range of its cardinal
+    fn is_normalized(&self) -> bool {
+        let total = self.total_seconds();
+        let in_range = match self.cardinal {
+            Some(c) if c.is_latitude() => total <= 90.0 * 3600.0,
+            Some(c) if c.is_longitude() => total <= 180.0 * 3600.0,
+            _ => total < TURN_SECONDS,
+        };
+        self.minutes < 60 && (0.0..60.0).contains(&self.seconds) && in_range
+    }
+
     /// Returns Self expressed in decimal degrees
-    /// If no cardinal is associated, returned angle strictly > 0.
+    /// If no cardinal is associated, returned angle is only negative
+    /// for negative angles.
     pub fn to_ddeg_angle(&self) -> f64 {
         let d = self.degrees as f64 + self.minutes as f64 / 60.0_f64 + self.seconds / 3600.0_f64;
         match self.cardinal {
@@ -391,6 +881,7 @@ impl DMS {
                     d
                 }
             }
+            None if self.is_negative() => -d,
             None => d,
         }
     }
@@ -405,100 +896,57 @@ impl DMS {
         Self::from_ddeg_angle(self.to_ddeg_angle() + angle)
     }
 
-    /// Returns total of seconds (base unit) contained in Self
+    /// Returns total of seconds (base unit) contained in Self,
+    /// regardless of its sign
     pub fn total_seconds(&self) -> f64 {
         self.degrees as f64 * 3600.0 + self.minutes as f64 * 60.0 + self.seconds
     }
 
-    /// Converts self to radians
-    pub fn to_radians(&self) -> f64 {
-        self.to_ddeg_angle() / 180.0 * core::f64::consts::PI
+    /// Returns true for southern and western angles,
+    /// and for negative angles with no cardinal
+    pub fn is_negative(&self) -> bool {
+        match self.cardinal {
+            Some(c) => c.is_southern() || c.is_western(),
+            None => self.negative,
+        }
     }
-    /*
-        /// Descriptor must follow standard formats:
-        ///     +DDD.D  : sign + 3 digit "." + 1digit
-        ///     Degrees specified, minutes = 0, seconds = 0
-        ///     +DDDMM.M : sign + 3 digit D° + 2 digit M' "." 1 digit M'
-        ///     Degrees + minutes specified
-        ///     +DDDMMSS.S : sign + 3 digit D° + 2 digit M' + fractionnal seconds
-        /// <!> Although standards says "+" is mandatory to describe positive D°,
-        /// this method tolerates a missing '+' and we interprate D° as positive value.
-        pub fn from_str (s: &str) -> Result<Self, ParseError> {
-            let lon_positive_d = Regex::new(r"^+\d{3}.\d{1}$")
-                .unwrap();
-            let lon_negative_d = Regex::new(r"^-\d{3}.\d{1}$")
-                .unwrap();
-            let lon_positive_dm = Regex::new(r"^+\d{3}d{2}.\d{1}$")
-                .unwrap();
-            let lon_negative_dm = Regex::new(r"^-\d{d}d{2}.\d{1}$")
-                .unwrap();
-            let lon_positive_dms = Regex::new(r"^+\d{3}d{2}d{2}.\d{1}$")
-                .unwrap();
-            let lon_negative_dms = Regex::new(r"^-\d{3}d{2}d{2}.\d{1}$")
-                .unwrap();
-            if lon_positive_d.is_match(s) {
-                let degrees = u16::from_str_radix(&s[0..3], 10)?; //attention au '+'
-                Ok(DMS {
-                    degrees,
-                    minutes: 0,
-                    seconds: 0.0,
-                    cardinal: Some(Cardinal::East),
-                })
-            } else if lon_negative_d.is_match(s) {
-                let degrees = u16::from_str_radix(&s[0..3], 10)?; //attention au '+'
-                Ok(DMS {
-                    degrees,
-                    minutes: 0,
-                    seconds: 0.0,
-                    cardinal: Some(Cardinal::West),
-                })
-
-            } else if lon_positive_dm.is_match(s) {
-                let degrees = u16::from_str_radix(&s[0..3], 10)?; //attention au '+'
-                Ok(DMS {
-                    degrees,
-                    minutes: 0,
-                    seconds: 0.0,
-                    cardinal: Some(Cardinal::East),
-                })
-
-            } else if lon_negative_dm.is_match(s) {
-                let degrees = u16::from_str_radix(&s[0..3], 10)?; //attention au '+'
-                Ok(DMS {
-                    degrees,
-                    minutes: 0,
-                    seconds: 0.0,
-                    cardinal: Some(Cardinal::West),
-                })
 
-            } else if lon_positive_dms.is_match(s) {
-                let degrees = u16::from_str_radix(&s[0..3], 10)?; //attention au '+'
-                Ok(DMS {
-                    degrees,
-                    minutes: 0,
-                    seconds: 0.0,
-                    cardinal: Some(Cardinal::East),
-                })
+    /// Returns total of seconds contained in Self,
+    /// negative for negative angles with no cardinal
+    fn signed_seconds(&self) -> f64 {
+        if self.cardinal.is_none() && self.negative {
+            -self.total_seconds()
+        } else {
+            self.total_seconds()
+        }
+    }
 
-            } else if lon_negative_dms.is_match(s) {
-                let degrees = u16::from_str_radix(&s[0..3], 10)?; //attention au '+'
-                Ok(DMS {
-                    degrees,
-                    minutes: 0,
-                    seconds: 0.0,
-                    cardinal: Some(Cardinal::West),
-                })
+    /// Returns an object that displays Self according to given pattern,
+    /// similarly to `chrono::format`. Refer to [crate::format] for
+    /// supported specifiers.
+    /// ```
+    /// use dms_coordinates::{Cardinal, DMS};
+    /// let dms = DMS::new(40, 43, 50.196, Some(Cardinal::North));
+    /// assert_eq!(dms.format("%D°%M'%.2S\"%C").to_string(), "40°43'50.20\"N");
+    /// assert_eq!(dms.format("%C%0D°%.3m'").to_string(), "N40°43.837'");
+    /// ```
+    pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+        DelayedFormat::angle(*self, pattern)
+    }
 
-            } else {
-                Err(ParseError::FormatNotRecognized)
-            }
-        }
-    */
+    /// Converts self to radians
+    pub fn to_radians(&self) -> f64 {
+        self.to_ddeg_angle() / 180.0 * core::f64::consts::PI
+    }
 
-    /// Returns D°M'S" angle copy with
-    /// WGS84 to EU50 conversion applied.
+    /// Returns D°M'S" angle copy with a fixed WGS84 to EU50 offset applied
+    /// (3.6"N or 2.4"E), only valid in a small area.
     /// For conversion to be applied, we need a cardinal to be associated,
     /// otherwise this simply returns a copy
+    #[deprecated(
+        since = "2.0.0",
+        note = "a datum shift depends on both coordinates, use DMS3d::to_europe50 or DMS3d::transform"
+    )]
     pub fn to_europe50(&self) -> Result<DMS, Error> {
         if let Some(cardinal) = self.cardinal {
             if cardinal.is_latitude() {
@@ -511,3 +959,320 @@ impl DMS {
         }
     }
 }
+
+/// Angle expressed as `D°M.MMM'`, in Degrees D° and
+/// fractionnal Minutes M' (double precision) with an optionnal Cardinal.
+/// This is the representation used by marine GPS units and NMEA.
+/// Like [DMS], when a cardinal is associated to this angle,
+/// we consider this angle represents either a Latitude or a Longitude angle.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DDM {
+    /// Degrees D°
+    pub degrees: u16,
+    /// Minutes with fractionnal part M'
+    pub minutes: f64,
+    /// Optionnal cardinal associated to this angle
+    pub cardinal: Option<Cardinal>,
+    /// Sign of angles with no cardinal, ignored when a cardinal
+    /// is associated, refer to [DDM::is_negative]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub negative: bool,
+}
+
+impl PartialEq for DDM {
+    /// The sign is only compared for angles with no cardinal,
+    /// others being signed by their cardinal
+    fn eq(&self, rhs: &Self) -> bool {
+        self.degrees == rhs.degrees
+            && self.minutes == rhs.minutes
+            && self.cardinal == rhs.cardinal
+            && self.is_negative() == rhs.is_negative()
+    }
+}
+
+impl core::fmt::Display for DDM {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(cardinal) = self.cardinal {
+            write!(f, "{}°{:.4}'{}", self.degrees, self.minutes, cardinal)
+        } else {
+            if self.is_negative() {
+                write!(f, "-")?;
+            }
+            write!(f, "{}°{:.4}'", self.degrees, self.minutes)
+        }
+    }
+}
+
+impl core::str::FromStr for DDM {
+    type Err = ParseError;
+    /// Parses a `D°M.MMM'` angle, any notation supported by [DMS] is accepted
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<DMS>()?.into())
+    }
+}
+
+impl Default for DDM {
+    /// Builds null angle with no Cardinal associated to it
+    fn default() -> Self {
+        Self {
+            degrees: 0,
+            minutes: 0.0_f64,
+            cardinal: None,
+            negative: false,
+        }
+    }
+}
+
+impl From<DMS> for DDM {
+    /// Converts `D°M'S"` to `D°M.MMM'`
+    fn from(dms: DMS) -> Self {
+        Self {
+            degrees: dms.degrees,
+            minutes: dms.minutes as f64 + dms.seconds / 60.0_f64,
+            cardinal: dms.cardinal,
+            negative: dms.cardinal.is_none() && dms.negative,
+        }
+    }
+}
+
+impl From<DDM> for DMS {
+    /// Converts `D°M.MMM'` to `D°M'S"`
+    fn from(ddm: DDM) -> Self {
+        let minutes = ddm.minutes.floor();
+        Self {
+            degrees: ddm.degrees,
+            minutes: minutes as u8,
+            seconds: (ddm.minutes - minutes) * 60.0_f64,
+            cardinal: ddm.cardinal,
+            negative: ddm.cardinal.is_none() && ddm.negative,
+        }
+    }
+}
+
+impl From<DDM> for f64 {
+    /// Converts Self to decimal degrees
+    fn from(val: DDM) -> Self {
+        val.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Add<DDM> for DDM {
+    type Output = Result<Self, Error>;
+    fn add(self, rhs: Self) -> Result<Self, Error> {
+        (DMS::from(self) + DMS::from(rhs)).map(Self::from)
+    }
+}
+
+impl core::ops::AddAssign<DDM> for DDM {
+    fn add_assign(&mut self, rhs: Self) {
+        let mut dms = DMS::from(*self);
+        dms += DMS::from(rhs);
+        *self = dms.into()
+    }
+}
+
+impl core::ops::Neg for DDM {
+    type Output = Self;
+    /// Returns the opposite angle, refer to [DMS] (-)
+    fn neg(self) -> Self {
+        (-DMS::from(self)).into()
+    }
+}
+
+impl core::ops::Sub<DDM> for DDM {
+    type Output = Result<Self, Error>;
+    fn sub(self, rhs: Self) -> Result<Self, Error> {
+        (DMS::from(self) - DMS::from(rhs)).map(Self::from)
+    }
+}
+
+impl core::ops::SubAssign<DDM> for DDM {
+    /// Self is left untouched when cardinals are not compatible
+    fn sub_assign(&mut self, rhs: Self) {
+        let mut dms = DMS::from(*self);
+        dms -= DMS::from(rhs);
+        *self = dms.into()
+    }
+}
+
+impl core::ops::Add<f64> for DDM {
+    type Output = Self;
+    fn add(self, rhs: f64) -> Self {
+        (DMS::from(self) + rhs).into()
+    }
+}
+
+impl core::ops::AddAssign<f64> for DDM {
+    fn add_assign(&mut self, rhs: f64) {
+        *self = *self + rhs
+    }
+}
+
+impl core::ops::Sub<f64> for DDM {
+    type Output = Self;
+    fn sub(self, rhs: f64) -> Self {
+        (DMS::from(self) - rhs).into()
+    }
+}
+
+impl core::ops::SubAssign<f64> for DDM {
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = *self - rhs
+    }
+}
+
+impl core::ops::Mul<f64> for DDM {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        (DMS::from(self) * rhs).into()
+    }
+}
+
+impl core::ops::MulAssign<f64> for DDM {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs
+    }
+}
+
+impl core::ops::Div<f64> for DDM {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        (DMS::from(self) / rhs).into()
+    }
+}
+
+impl core::ops::DivAssign<f64> for DDM {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs
+    }
+}
+
+impl DDM {
+    /// Builds `D°M.MMM'` angle, from given D° and M' values.
+    /// This method allows overflow, and negative amounts reverse the cardinal.
+    /// Angles out of range are normalized with the default [Normalization] policy,
+    /// refer to [DMS::new].
+    pub fn new(degrees: u16, minutes: f64, cardinal: Option<Cardinal>) -> DDM {
+        let ddm = Self::from_fields(degrees, minutes, cardinal);
+        ddm.normalize(Normalization::default()).unwrap_or(ddm)
+    }
+
+    /// Builds `D°M.MMM'` angle, from given D° and M' values,
+    /// normalized with given policy
+    pub fn new_with(
+        degrees: u16,
+        minutes: f64,
+        cardinal: Option<Cardinal>,
+        normalization: Normalization,
+    ) -> Result<DDM, Error> {
+        Self::from_fields(degrees, minutes, cardinal).normalize(normalization)
+    }
+
+    /// Builds `D°M.MMM'` angle from given fields, carrying M' over,
+    /// but not normalized
+    fn from_fields(degrees: u16, minutes: f64, cardinal: Option<Cardinal>) -> Self {
+        if (0.0..60.0).contains(&minutes) {
+            // fields are kept as is
+            return Self {
+                degrees,
+                minutes,
+                cardinal,
+                negative: false,
+            };
+        }
+        let total = degrees as f64 * 60.0 + minutes;
+        match cardinal {
+            Some(c) if total < 0.0 => Self::split_minutes(-total).with_cardinal(c + 180),
+            Some(c) => Self::split_minutes(total).with_cardinal(c),
+            None => Self::split_minutes(total),
+        }
+    }
+
+    /// Builds `D°M.MMM'` angle from total amount of minutes,
+    /// wrapped to `|D°| < 360`, negative amounts giving negative angles
+    pub fn from_minutes(minutes: f64) -> Self {
+        Self::split_minutes(minutes % (360.0 * 60.0))
+    }
+
+    /// Splits given amount of minutes into D° and M' fields,
+    /// negative amounts giving negative angles
+    fn split_minutes(total: f64) -> Self {
+        let minutes = total.abs();
+        let degrees = (minutes / 60.0).floor();
+        Self {
+            degrees: degrees as u16,
+            minutes: minutes - degrees * 60.0,
+            cardinal: None,
+            negative: total < 0.0 && minutes > 0.0,
+        }
+    }
+
+    /// Normalizes Self with given policy, angles within range
+    /// being returned as is. Refer to [DMS::normalize].
+    pub fn normalize(&self, normalization: Normalization) -> Result<Self, Error> {
+        let total = self.total_minutes();
+        let in_range = match self.cardinal {
+            Some(c) if c.is_latitude() => total <= 90.0 * 60.0,
+            Some(c) if c.is_longitude() => total <= 180.0 * 60.0,
+            _ => total < 360.0 * 60.0,
+        };
+        if (0.0..60.0).contains(&self.minutes) && in_range {
+            return Ok(*self);
+        }
+        DMS::from(*self).normalize(normalization).map(Self::from)
+    }
+
+    /// Returns same `D°M.MMM'` angle but attaches a cardinal to it.
+    /// Latitude and longitude angles being signed by their cardinal,
+    /// the sign of the angle is dropped.
+    pub fn with_cardinal(&self, cardinal: Cardinal) -> Self {
+        Self {
+            degrees: self.degrees,
+            minutes: self.minutes,
+            cardinal: Some(cardinal),
+            negative: false,
+        }
+    }
+
+    /// Returns true for southern and western angles,
+    /// and for negative angles with no cardinal
+    pub fn is_negative(&self) -> bool {
+        DMS::from(*self).is_negative()
+    }
+
+    /// Builds `D°M.MMM'` angle from given angle expressed in
+    /// decimal degrees, with no cardinal associated to returned value
+    pub fn from_ddeg_angle(angle: f64) -> Self {
+        DMS::from_ddeg_angle(angle).into()
+    }
+
+    /// Builds Latitude angle, expressed in `D°M.MMM'`, from
+    /// given angle expressed in decimal degrees
+    pub fn from_ddeg_latitude(angle: f64) -> Self {
+        DMS::from_ddeg_latitude(angle).into()
+    }
+
+    /// Builds Longitude angle, expressed in `D°M.MMM'`, from
+    /// given angle expressed in decimal degrees
+    pub fn from_ddeg_longitude(angle: f64) -> Self {
+        DMS::from_ddeg_longitude(angle).into()
+    }
+
+    /// Returns Self expressed in decimal degrees
+    /// If no cardinal is associated, returned angle is only negative
+    /// for negative angles.
+    pub fn to_ddeg_angle(&self) -> f64 {
+        DMS::from(*self).to_ddeg_angle()
+    }
+
+    /// Returns total of minutes contained in Self, regardless of its sign
+    pub fn total_minutes(&self) -> f64 {
+        self.degrees as f64 * 60.0 + self.minutes
+    }
+
+    /// Converts self to radians
+    pub fn to_radians(&self) -> f64 {
+        self.to_ddeg_angle().to_radians()
+    }
+}
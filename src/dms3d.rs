@@ -1,7 +1,20 @@
 //! 3D D°M'S" coordinates
+use crate::datum::{Datum, Transformation};
+use crate::dms::{crosses_pole, Normalization, ParseError, Scale};
+use crate::format::DelayedFormat;
+use crate::geodesic::{self, Inverse, Karney, Method};
+use crate::geohash::Geohash;
+use crate::iso6709::{DelayedIso6709, Location, Style};
+use crate::latlon::{Latitude, Longitude};
+use crate::maidenhead::Maidenhead;
+use crate::mgrs::MGRS;
+use crate::nmea::{DelayedSentence, SentenceKind};
+use crate::plus_code::PlusCode;
+use crate::rhumb::{self, Rhumb};
+use crate::utm::UTM;
 use crate::Error;
-use crate::EARTH_RADIUS;
-use crate::{projected_distance, Cardinal, DMS};
+use crate::{Cardinal, Ellipsoid, DMS};
+use core::convert::TryFrom;
 
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
@@ -54,6 +67,13 @@ impl From<DMS3d> for (f64, f64) {
     }
 }
 
+impl From<(Latitude, Longitude)> for DMS3d {
+    /// Builds 2D coordinates, with no altitude
+    fn from((latitude, longitude): (Latitude, Longitude)) -> Self {
+        Self::from_lat_lon(latitude, longitude, None)
+    }
+}
+
 impl From<rust_3d::Point3D> for DMS3d {
     /// Builds 3D D°M'S" coordinates from cartesian (ECEF) coordinates
     fn from(item: rust_3d::Point3D) -> Self {
@@ -63,32 +83,242 @@ impl From<rust_3d::Point3D> for DMS3d {
 
 impl core::ops::Add<DMS3d> for DMS3d {
     type Output = Result<DMS3d, Error>;
+    /// Adds angles together, going over a pole
+    /// moves the longitude to the opposite meridian
     fn add(self, rhs: Self) -> Result<Self, Error> {
-        if let Some(a0) = self.altitude {
-            if let Some(a1) = rhs.altitude {
-                Ok(DMS3d {
-                    latitude: (self.latitude + rhs.latitude)?,
-                    longitude: (self.longitude + rhs.longitude)?,
-                    altitude: Some(a1),
-                })
-            } else {
-                Ok(DMS3d {
-                    latitude: (self.latitude + rhs.latitude)?,
-                    longitude: (self.longitude + rhs.longitude)?,
-                    altitude: Some(a0),
-                })
+        let latitude = self.latitude.to_ddeg_angle() + rhs.latitude.to_ddeg_angle();
+        let mut longitude = (self.longitude + rhs.longitude)?;
+        if self.latitude.cardinal.is_some()
+            && rhs.latitude.cardinal.is_some()
+            && crosses_pole(latitude)
+        {
+            longitude += 180.0;
+        }
+        Ok(DMS3d {
+            latitude: (self.latitude + rhs.latitude)?,
+            longitude,
+            altitude: self.altitude.map(|a0| rhs.altitude.unwrap_or(a0)),
+        })
+    }
+}
+
+/// Converts given quantity in `feet` to `meters`
+fn feet_to_meters(feet: f64) -> f64 {
+    feet / 3.28084
+}
+
+/// Parsed angle, with the optionnal sign (true: negative) that came with it
+type SignedAngle = (DMS, Option<bool>);
+
+/// Parses a single angle, returning the optionnal sign that came with it,
+/// so latitude / longitude can be resolved once both angles are known.
+fn parse_angle(s: &str) -> Result<SignedAngle, ParseError> {
+    let s = s.trim();
+    let (s, negative) = if let Some(s) = s.strip_prefix('-') {
+        (s, Some(true))
+    } else if let Some(s) = s.strip_prefix('+') {
+        (s, Some(false))
+    } else {
+        (s, None)
+    };
+    let dms = s.parse::<DMS>()?;
+    if dms.cardinal.is_some() && negative.is_some() {
+        return Err(ParseError::SignAndCardinal);
+    }
+    Ok((dms, negative))
+}
+
+/// Parses an altitude, either in `meters` or `feet`,
+/// returned in `meters`
+fn parse_altitude(s: &str) -> Result<f64, Error> {
+    let s = s.trim();
+    let s = s.trim_matches('"').trim();
+    let bytes = s.as_bytes();
+    let (value, feet) = if bytes.len() > 2 && bytes[bytes.len() - 2..].eq_ignore_ascii_case(b"ft") {
+        (&s[..s.len() - 2], true)
+    } else if bytes.len() > 1 && bytes[bytes.len() - 1].eq_ignore_ascii_case(&b'm') {
+        (&s[..s.len() - 1], false)
+    } else {
+        (s, false)
+    };
+    let value = value
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| Error::InvalidAltitude)?;
+    if feet {
+        Ok(feet_to_meters(value))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Splits an explicit altitude (`10m`, `32 ft`) from the end of given string
+fn split_altitude(s: &str) -> Result<(&str, Option<f64>), Error> {
+    let bytes = s.as_bytes();
+    let mut end = bytes.len();
+    if end > 2 && bytes[end - 2..].eq_ignore_ascii_case(b"ft") {
+        end -= 2;
+    } else if end > 1 && bytes[end - 1].eq_ignore_ascii_case(&b'm') {
+        end -= 1;
+    } else {
+        return Ok((s, None));
+    }
+    let mut start = s[..end].trim_end().len();
+    while start > 0 && matches!(bytes[start - 1], b'0'..=b'9' | b'.' | b'-' | b'+') {
+        start -= 1;
+    }
+    if start == 0 || !matches!(bytes[start - 1], b' ' | b'\t' | b',') {
+        return Err(Error::InvalidAltitude);
+    }
+    let altitude = parse_altitude(&s[start..])?;
+    Ok((
+        s[..start].trim_end().trim_end_matches(',').trim_end(),
+        Some(altitude),
+    ))
+}
+
+/// Returns value associated to given key, in a "key: value" description
+fn keyed_value<'a>(s: &'a str, key: &str) -> Option<&'a str> {
+    let keys = ["lat:", "lon:", "alt:"];
+    let find = |key: &str| {
+        s.as_bytes()
+            .windows(key.len())
+            .position(|w| w.eq_ignore_ascii_case(key.as_bytes()))
+    };
+    let start = find(key)? + key.len();
+    let end = keys
+        .iter()
+        .filter_map(|k| find(k))
+        .filter(|&i| i >= start)
+        .min()
+        .unwrap_or(s.len());
+    let value = s[start..end].trim().trim_end_matches(',').trim();
+    // strip enclosing quotes, as emitted by Display
+    let value = match (value.strip_prefix('"'), value.len() > 1) {
+        (Some(inner), true) => inner.strip_suffix('"').unwrap_or(inner),
+        _ => value,
+    };
+    Some(value.trim())
+}
+
+impl core::str::FromStr for DMS3d {
+    type Err = Error;
+    /// Parses 3D D°M'S" coordinates, from either:
+    ///   - two angles, in any notation supported by `DMS::from_str`,
+    ///     separated by whitespaces or a comma: `40°43'50.196"N 73°56'6.871"W`
+    ///     or `40.730610, -73.935242`
+    ///   - the `Display` output of this structure: `lat: ".." lon: ".." alt: ".."`
+    ///   - an ISO 6709 string, terminated by "/": `+404350.196-0735606.871/`
+    ///
+    /// When cardinals are specified, latitude and longitude may come in any order,
+    /// otherwise latitude is expected first.
+    /// An optionnal altitude may follow, in meters (`10m`) or feet (`32ft`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseError::EmptyString.into());
+        }
+        if s.ends_with('/') {
+            return Self::from_iso6709(s);
+        }
+        if let Some(lat) = keyed_value(s, "lat:") {
+            let lon = keyed_value(s, "lon:").ok_or(Error::MissingLongitude)?;
+            let altitude = match keyed_value(s, "alt:") {
+                Some(alt) => Some(parse_altitude(alt)?),
+                None => None,
+            };
+            return Self::from_angles(parse_angle(lat)?, parse_angle(lon)?, altitude);
+        }
+
+        let (s, altitude) = split_altitude(s)?;
+        if s.contains(',') {
+            let mut items = s.split(',');
+            let a = items.next().unwrap_or("");
+            let b = items.next().ok_or(Error::MissingLongitude)?;
+            let altitude = match (items.next(), altitude) {
+                (Some(_), Some(_)) => return Err(Error::InvalidAltitude),
+                (Some(alt), None) => Some(parse_altitude(alt)?),
+                (None, altitude) => altitude,
+            };
+            if items.next().is_some() {
+                return Err(ParseError::FormatNotRecognized.into());
             }
+            return Self::from_angles(parse_angle(a)?, parse_angle(b)?, altitude);
+        }
+
+        // whitespace separated: we try every possible split between both angles
+        let mut tokens = [(0_usize, 0_usize); 16];
+        let mut ntokens = 0;
+        let mut start: Option<usize> = None;
+        for (i, c) in s.char_indices().chain(core::iter::once((s.len(), ' '))) {
+            if c.is_whitespace() {
+                if let Some(st) = start.take() {
+                    if ntokens == tokens.len() {
+                        return Err(ParseError::FormatNotRecognized.into());
+                    }
+                    tokens[ntokens] = (st, i);
+                    ntokens += 1;
+                }
+            } else if start.is_none() {
+                start = Some(i);
+            }
+        }
+
+        let mut error: Option<ParseError> = None;
+        // trailing plain number may be an altitude with no unit
+        let attempts = if altitude.is_none() && ntokens > 2 {
+            2
         } else {
-            Ok(DMS3d {
-                latitude: (self.latitude + rhs.latitude)?,
-                longitude: (self.longitude + rhs.longitude)?,
-                altitude: None,
-            })
+            1
+        };
+        for attempt in 0..attempts {
+            let n = ntokens - attempt;
+            let altitude = if attempt == 1 {
+                let (st, end) = tokens[n];
+                match s[st..end].parse::<f64>() {
+                    Ok(alt) => Some(alt),
+                    Err(_) => break,
+                }
+            } else {
+                altitude
+            };
+            let mut found: Option<(SignedAngle, SignedAngle)> = None;
+            for k in 1..n {
+                let a = parse_angle(&s[tokens[0].0..tokens[k - 1].1]);
+                let b = parse_angle(&s[tokens[k].0..tokens[n - 1].1]);
+                match (a, b) {
+                    (Ok(a), Ok(b)) => {
+                        if found.is_some() {
+                            return Err(ParseError::FormatNotRecognized.into());
+                        }
+                        found = Some((a, b));
+                    }
+                    (Err(e), _) | (_, Err(e)) => {
+                        error.get_or_insert(e);
+                    }
+                }
+            }
+            if let Some((a, b)) = found {
+                return Self::from_angles(a, b, altitude);
+            }
+        }
+
+        // a single angle is a coordinate missing one of its components
+        match parse_angle(s) {
+            Ok((dms, _)) => match dms.cardinal {
+                Some(c) if c.is_longitude() => Err(Error::MissingLatitude),
+                _ => Err(Error::MissingLongitude),
+            },
+            Err(e) => Err(error.unwrap_or(e).into()),
         }
     }
 }
+
 impl DMS3d {
-    /// Builds `3D D°M'S"` coordinates
+    /// Builds `3D D°M'S"` coordinates, angles out of range
+    /// being normalized with [Normalization::Fold].
+    /// Angles built with [DMS::new] are already normalized on their own:
+    /// use [DMS3d::from_ddeg_angles] to move the longitude when going over a pole.
     pub fn new(latitude: DMS, longitude: DMS, altitude: Option<f64>) -> Result<DMS3d, Error> {
         let cardlat = latitude.cardinal.ok_or(Error::MissingLatitude)?;
         if !cardlat.is_latitude() {
@@ -98,12 +328,69 @@ impl DMS3d {
         if !cardlon.is_longitude() {
             return Err(Error::InvalidLongitude);
         }
-        Ok(DMS3d {
+        DMS3d {
             latitude,
             longitude,
             altitude,
-        })
+        }
+        .normalize(Normalization::Fold)
     }
+
+    /// Builds `3D D°M'S"` coordinates from validated angles
+    pub fn from_lat_lon(latitude: Latitude, longitude: Longitude, altitude: Option<f64>) -> DMS3d {
+        DMS3d {
+            latitude: latitude.into(),
+            longitude: longitude.into(),
+            altitude,
+        }
+    }
+
+    /// Returns validated latitude and longitude angles
+    pub fn lat_lon(&self) -> Result<(Latitude, Longitude), Error> {
+        Ok((
+            Latitude::try_from(self.latitude)?,
+            Longitude::try_from(self.longitude)?,
+        ))
+    }
+
+    /// Builds `3D D°M'S"` coordinates from two parsed angles and their optionnal sign.
+    /// Angles are swapped when cardinals tell us longitude came first.
+    fn from_angles(a: SignedAngle, b: SignedAngle, altitude: Option<f64>) -> Result<DMS3d, Error> {
+        let swap = match (a.0.cardinal, b.0.cardinal) {
+            (Some(c), _) => c.is_longitude(),
+            (None, Some(c)) => c.is_latitude(),
+            (None, None) => false,
+        };
+        let ((latitude, lat_sign), (longitude, lon_sign)) = if swap { (b, a) } else { (a, b) };
+        let latitude = match latitude.cardinal {
+            Some(_) => latitude,
+            None => {
+                if latitude.to_ddeg_angle() > 90.0 {
+                    return Err(Error::InvalidLatitude);
+                }
+                if lat_sign == Some(true) {
+                    latitude.with_cardinal(Cardinal::South)
+                } else {
+                    latitude.with_cardinal(Cardinal::North)
+                }
+            }
+        };
+        let longitude = match longitude.cardinal {
+            Some(_) => longitude,
+            None => {
+                if longitude.to_ddeg_angle() > 180.0 {
+                    return Err(Error::InvalidLongitude);
+                }
+                if lon_sign == Some(true) {
+                    longitude.with_cardinal(Cardinal::West)
+                } else {
+                    longitude.with_cardinal(Cardinal::East)
+                }
+            }
+        };
+        Self::new(latitude, longitude, altitude)
+    }
+
     /// Builds 3D DMS copy with given altitude attribute in `meters`,
     /// if altitude data was already present, it gets overwritten
     pub fn with_altitude(&self, altitude: f64) -> DMS3d {
@@ -116,7 +403,7 @@ impl DMS3d {
 
     /// Same as [with_altitude] but quantity is expressed in `feet`
     pub fn with_altitude_feet(&self, altitude: f64) -> DMS3d {
-        self.with_altitude(altitude / 3.28084)
+        self.with_altitude(feet_to_meters(altitude))
     }
 
     /// Adds given altitude quantity to self,
@@ -131,101 +418,478 @@ impl DMS3d {
 
     /// Same as [add_altitude] but quantity is expressed in `feet`
     pub fn add_altitude_feet(&mut self, altitude: f64) {
-        self.add_altitude(altitude / 3.28084)
+        self.add_altitude(feet_to_meters(altitude))
     }
 
     /// Builds `3D D°M'S"` coordinates from given angles, expressed
     /// in decimal degrees, and an optionnal altitude.
+    /// Latitudes out of range are folded over the poles (see [Normalization::Fold])
     pub fn from_ddeg_angles(latitude: f64, longitude: f64, altitude: Option<f64>) -> DMS3d {
+        let (latitude, longitude) = Normalization::Fold
+            .coordinates(latitude, longitude)
+            .unwrap_or((latitude, longitude));
         DMS3d {
-            latitude: {
-                let dms = DMS::from_ddeg_angle(latitude);
-                if latitude < 0.0 {
-                    dms.with_cardinal(Cardinal::South)
-                } else {
-                    dms.with_cardinal(Cardinal::North)
-                }
-            },
-            longitude: {
-                let dms = DMS::from_ddeg_angle(longitude);
-                if longitude < 0.0 {
-                    dms.with_cardinal(Cardinal::West)
-                } else {
-                    dms.with_cardinal(Cardinal::East)
-                }
-            },
+            latitude: DMS::from_ddeg_latitude(latitude),
+            longitude: DMS::from_ddeg_longitude(longitude),
             altitude,
         }
     }
 
-    /// Builds 3D D°M'S" coordinates from given Cartesian coordinates
-    pub fn from_cartesian(xyz: rust_3d::Point3D) -> DMS3d {
-        DMS3d {
-            latitude: DMS::from_ddeg_latitude(map_3d::rad2deg((xyz.z / EARTH_RADIUS).asin())),
-            longitude: DMS::from_ddeg_longitude(map_3d::rad2deg(xyz.y.atan2(xyz.x))),
-            altitude: Some(xyz.z),
+    /// Builds `3D D°M'S"` coordinates from given angles, expressed
+    /// in decimal degrees, normalized with given policy
+    pub fn from_ddeg_angles_with(
+        latitude: f64,
+        longitude: f64,
+        altitude: Option<f64>,
+        normalization: Normalization,
+    ) -> Result<DMS3d, Error> {
+        let (latitude, longitude) = normalization.coordinates(latitude, longitude)?;
+        Ok(DMS3d {
+            latitude: DMS::from_ddeg_latitude(latitude),
+            longitude: DMS::from_ddeg_longitude(longitude),
+            altitude,
+        })
+    }
+
+    /// Returns a copy of Self normalized with given policy.
+    /// Coordinates within range are returned untouched
+    pub fn normalize(&self, normalization: Normalization) -> Result<DMS3d, Error> {
+        if self.latitude.total_seconds() <= 90.0 * 3600.0 {
+            Ok(DMS3d {
+                latitude: self.latitude.normalize(normalization)?,
+                longitude: self.longitude.normalize(normalization)?,
+                altitude: self.altitude,
+            })
+        } else {
+            Self::from_ddeg_angles_with(
+                self.latitude.to_ddeg_angle(),
+                self.longitude.to_ddeg_angle(),
+                self.altitude,
+                normalization,
+            )
         }
     }
 
-    /// Returns distance in meters, between Self and given 3D D°M'S" coordinates
+    /// Builds 3D D°M'S" coordinates from given Cartesian (ECEF) coordinates,
+    /// on the WGS84 ellipsoid. Altitude is the height above the ellipsoid.
+    pub fn from_cartesian(xyz: rust_3d::Point3D) -> DMS3d {
+        Self::from_ecef(xyz, Ellipsoid::WGS84)
+    }
+
+    /// Builds 3D D°M'S" coordinates from given ECEF coordinates, on given ellipsoid.
+    /// Altitude is the height above the ellipsoid.
+    pub fn from_ecef(xyz: rust_3d::Point3D, ellipsoid: Ellipsoid) -> DMS3d {
+        let (latitude, longitude, altitude) = ellipsoid.ecef_to_geodetic(xyz.x, xyz.y, xyz.z);
+        Self::from_ddeg_angles(latitude, longitude, Some(altitude))
+    }
+
+    /// Returns geodesic distance in meters, between Self and given 3D D°M'S" coordinates,
+    /// on the WGS84 ellipsoid (Karney's method). Altitudes are not taken into account.
+    /// Refer to [DMS3d::geodesic_inverse] to select the method
     pub fn distance(&self, other: DMS3d) -> f64 {
-        projected_distance(
+        self.karney_inverse(other).distance
+    }
+
+    /// Returns azimuth angle ɑ, where 0 <= ɑ < 360,
+    /// between Self & other 3D D°M'S" coordinates, on the WGS84 ellipsoid.
+    /// ɑ, being the angle between North Pole & `rhs` coordinates
+    pub fn azimuth(&self, rhs: Self) -> f64 {
+        self.karney_inverse(rhs).forward_azimuth
+    }
+
+    /// Solves the inverse geodesic problem on the WGS84 ellipsoid,
+    /// with Karney's method which always converges
+    fn karney_inverse(&self, rhs: Self) -> Inverse {
+        Karney::new(Ellipsoid::WGS84).inverse(
             (
                 self.latitude.to_ddeg_angle(),
                 self.longitude.to_ddeg_angle(),
             ),
+            (rhs.latitude.to_ddeg_angle(), rhs.longitude.to_ddeg_angle()),
+        )
+    }
+
+    /// Solves the inverse geodesic problem between Self and `rhs`, on the WGS84 ellipsoid:
+    /// returns the geodesic distance, forward and reverse azimuths,
+    /// computed with given [Method]. Altitudes are not taken into account.
+    /// ```
+    /// use dms_coordinates::{DMS3d, geodesic::Method};
+    /// let jfk = DMS3d::from_ddeg_angles(40.6, -73.8, None);
+    /// let cdg = DMS3d::from_ddeg_angles(49.016667, 2.55, None);
+    /// let inverse = jfk.geodesic_inverse(cdg, Method::Karney).unwrap();
+    /// assert!((inverse.distance - 5853226.0).abs() < 50.0);
+    /// assert!((inverse.forward_azimuth - 53.47).abs() < 0.01);
+    /// ```
+    pub fn geodesic_inverse(&self, rhs: Self, method: Method) -> Result<Inverse, Error> {
+        self.geodesic_inverse_on(rhs, method, Ellipsoid::WGS84)
+    }
+
+    /// Solves the inverse geodesic problem between Self and `rhs`, on given ellipsoid.
+    /// Refer to [DMS3d::geodesic_inverse]
+    pub fn geodesic_inverse_on(
+        &self,
+        rhs: Self,
+        method: Method,
+        ellipsoid: Ellipsoid,
+    ) -> Result<Inverse, Error> {
+        geodesic::inverse(
+            ellipsoid,
             (
-                other.latitude.to_ddeg_angle(),
-                other.longitude.to_ddeg_angle(),
+                self.latitude.to_ddeg_angle(),
+                self.longitude.to_ddeg_angle(),
             ),
+            (rhs.latitude.to_ddeg_angle(), rhs.longitude.to_ddeg_angle()),
+            method,
         )
     }
 
-    /// Returns azimuth angle ɑ, where 0 <= ɑ < 360,
-    /// between Self & other 3D D°M'S" coordinates.
-    /// ɑ, being the angle between North Pole & `rhs` coordinates
-    pub fn azimuth(&self, rhs: Self) -> f64 {
-        let (phi1, phi2) = (
-            map_3d::deg2rad(self.latitude.to_ddeg_angle()),
-            map_3d::deg2rad(rhs.latitude.to_ddeg_angle()),
-        );
-        let (lambda1, lambda2) = (
-            map_3d::deg2rad(self.longitude.to_ddeg_angle()),
-            map_3d::deg2rad(rhs.longitude.to_ddeg_angle()),
-        );
-        let dlambda = lambda2 - lambda1;
-        let y = dlambda.sin() * phi2.cos();
-        let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * dlambda.cos();
-        map_3d::rad2deg(y.atan2(x))
+    /// Returns coordinates reached after travelling `distance` meters from Self,
+    /// with initial `azimuth` in decimal degrees (0 being North), on the WGS84 ellipsoid.
+    /// [Method::Haversine] travels along a great circle of the ellipsoid mean radius.
+    /// Altitude is preserved.
+    /// ```
+    /// use dms_coordinates::{DMS3d, Cardinal, geodesic::Method};
+    /// let jfk = DMS3d::from_ddeg_angles(40.6, -73.8, Some(10.0));
+    /// let dest = jfk.destination(53.470218, 5853226.256, Method::Karney).unwrap();
+    /// assert!((dest.latitude.to_ddeg_angle() - 49.016667).abs() < 1E-6);
+    /// assert!((dest.longitude.to_ddeg_angle() - 2.55).abs() < 1E-6);
+    /// assert_eq!(dest.longitude.cardinal, Some(Cardinal::East));
+    /// assert_eq!(dest.altitude, Some(10.0));
+    /// ```
+    pub fn destination(&self, azimuth: f64, distance: f64, method: Method) -> Result<Self, Error> {
+        self.destination_on(azimuth, distance, method, Ellipsoid::WGS84)
+    }
+
+    /// Returns coordinates reached after travelling `distance` meters from Self,
+    /// with initial `azimuth`, on given ellipsoid. Refer to [DMS3d::destination]
+    pub fn destination_on(
+        &self,
+        azimuth: f64,
+        distance: f64,
+        method: Method,
+        ellipsoid: Ellipsoid,
+    ) -> Result<Self, Error> {
+        let direct = geodesic::direct(
+            ellipsoid,
+            (
+                self.latitude.to_ddeg_angle(),
+                self.longitude.to_ddeg_angle(),
+            ),
+            azimuth,
+            distance,
+            method,
+        )?;
+        Ok(DMS3d {
+            latitude: DMS::from_ddeg_latitude(direct.latitude),
+            longitude: DMS::from_ddeg_longitude(direct.longitude),
+            altitude: self.altitude,
+        })
+    }
+
+    /// Returns the rhumb line (constant bearing) from Self to `rhs`,
+    /// on the WGS84 ellipsoid. Altitudes are not taken into account.
+    /// ```
+    /// use dms_coordinates::{DMS3d, Cardinal};
+    /// let plymouth = DMS3d::from_ddeg_angles(50.3639, -4.1569, None);
+    /// let boston = DMS3d::from_ddeg_angles(42.3511, -71.0408, None);
+    /// let rhumb = plymouth.rhumb_line(boston);
+    /// assert!((rhumb.distance - 5_210_756.5).abs() < 0.1);
+    /// assert_eq!(rhumb.course().degrees, 260);
+    /// assert_eq!(rhumb.cardinal(), Cardinal::West);
+    /// ```
+    pub fn rhumb_line(&self, rhs: Self) -> Rhumb {
+        self.rhumb_line_on(rhs, Ellipsoid::WGS84)
+    }
+
+    /// Returns the rhumb line from Self to `rhs`, on given ellipsoid
+    pub fn rhumb_line_on(&self, rhs: Self, ellipsoid: Ellipsoid) -> Rhumb {
+        rhumb::inverse(
+            ellipsoid,
+            (
+                self.latitude.to_ddeg_angle(),
+                self.longitude.to_ddeg_angle(),
+            ),
+            (rhs.latitude.to_ddeg_angle(), rhs.longitude.to_ddeg_angle()),
+        )
+    }
+
+    /// Returns coordinates reached after travelling `distance` meters from Self,
+    /// on constant `bearing` in decimal degrees (0 being North), on the WGS84 ellipsoid.
+    /// Altitude is preserved. Error::InvalidLatitude is returned if a pole is passed.
+    pub fn rhumb_destination(&self, bearing: f64, distance: f64) -> Result<Self, Error> {
+        self.rhumb_destination_on(bearing, distance, Ellipsoid::WGS84)
+    }
+
+    /// Returns coordinates reached after travelling `distance` meters from Self,
+    /// on constant `bearing`, on given ellipsoid. Refer to [DMS3d::rhumb_destination]
+    pub fn rhumb_destination_on(
+        &self,
+        bearing: f64,
+        distance: f64,
+        ellipsoid: Ellipsoid,
+    ) -> Result<Self, Error> {
+        let (latitude, longitude) = rhumb::direct(
+            ellipsoid,
+            (
+                self.latitude.to_ddeg_angle(),
+                self.longitude.to_ddeg_angle(),
+            ),
+            bearing,
+            distance,
+        )?;
+        Ok(DMS3d {
+            latitude: DMS::from_ddeg_latitude(latitude),
+            longitude: DMS::from_ddeg_longitude(longitude),
+            altitude: self.altitude,
+        })
+    }
+
+    /// Projects Self to UTM grid coordinates, on the WGS84 ellipsoid,
+    /// in the standard zone (Norway and Svalbard exceptions included).
+    /// Error::InvalidLatitude is returned outside 80°S..84°N.
+    /// ```
+    /// use dms_coordinates::DMS3d;
+    /// let eiffel = DMS3d::from_ddeg_angles(48.8582, 2.2945, None);
+    /// let utm = eiffel.to_utm().unwrap();
+    /// assert_eq!(utm.zone, 31);
+    /// assert_eq!(utm.band, 'U');
+    /// assert_eq!(utm.to_string(), "31U 448251.795 5411932.678");
+    /// ```
+    pub fn to_utm(&self) -> Result<UTM, Error> {
+        UTM::project(
+            Ellipsoid::WGS84,
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            None,
+        )
+    }
+
+    /// Projects Self to UTM grid coordinates, on the WGS84 ellipsoid,
+    /// in given zone, like a neighbouring zone to work across a zone boundary
+    pub fn to_utm_zone(&self, zone: u8) -> Result<UTM, Error> {
+        UTM::project(
+            Ellipsoid::WGS84,
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            Some(zone),
+        )
+    }
+
+    /// Builds 3D D°M'S" coordinates from UTM grid coordinates,
+    /// on the WGS84 ellipsoid. Altitude is not defined.
+    pub fn from_utm(utm: UTM) -> DMS3d {
+        let (latitude, longitude) = utm.unproject(Ellipsoid::WGS84);
+        DMS3d {
+            latitude: DMS::from_ddeg_latitude(latitude),
+            longitude: DMS::from_ddeg_longitude(longitude),
+            altitude: None,
+        }
+    }
+
+    /// Returns the MGRS / USNG grid reference of the square containing Self,
+    /// with given number of digits per axis, from 0 (100 km) to 5 (1 m).
+    /// ```
+    /// use dms_coordinates::DMS3d;
+    /// let greenwich = DMS3d::from_ddeg_angles(51.477881, -0.001469, None);
+    /// let mgrs = greenwich.to_mgrs(5).unwrap();
+    /// assert_eq!(mgrs.to_string(), "30UYC0821507233");
+    /// assert_eq!(format!("{:#}", mgrs), "30U YC 08215 07233");
+    /// ```
+    pub fn to_mgrs(&self, precision: u8) -> Result<MGRS, Error> {
+        MGRS::from_geodetic(
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            precision,
+        )
+    }
+
+    /// Builds 3D D°M'S" coordinates at the centre of the square identified
+    /// by given MGRS / USNG grid reference, returned along with the Scale
+    /// matching the size of the square. Altitude is not defined.
+    pub fn from_mgrs(mgrs: MGRS) -> Result<(DMS3d, Scale), Error> {
+        let (latitude, longitude) = mgrs.to_geodetic()?;
+        let coords = DMS3d {
+            latitude: DMS::from_ddeg_latitude(latitude),
+            longitude: DMS::from_ddeg_longitude(longitude),
+            altitude: None,
+        };
+        Ok((coords, mgrs.scale()))
+    }
+
+    /// Returns the Maidenhead locator of the square containing Self,
+    /// with given number of characters: 2, 4, 6, 8 or 10.
+    /// The locator decodes to the bounds and the centre of the square.
+    /// ```
+    /// use dms_coordinates::DMS3d;
+    /// let w1aw = DMS3d::from_ddeg_angles(41.714775, -72.727260, None);
+    /// let locator = w1aw.to_maidenhead(6).unwrap();
+    /// assert_eq!(locator.to_string(), "FN31pr");
+    /// ```
+    pub fn to_maidenhead(&self, precision: usize) -> Result<Maidenhead, Error> {
+        Maidenhead::from_ddeg_angles(
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            precision,
+        )
+    }
+
+    /// Returns the geohash of the cell containing Self,
+    /// with given number of characters, 1 to 12.
+    /// The geohash decodes to the bounds and the centre of the cell.
+    /// ```
+    /// use dms_coordinates::DMS3d;
+    /// let coords = DMS3d::from_ddeg_angles(57.64911, 10.40744, None);
+    /// let geohash = coords.to_geohash(11).unwrap();
+    /// assert_eq!(geohash.to_string(), "u4pruydqqvj");
+    /// ```
+    pub fn to_geohash(&self, length: usize) -> Result<Geohash, Error> {
+        Geohash::from_ddeg_angles(
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            length,
+        )
     }
 
-    /// Converts Self to Cartesian Coordinates (x, y, z).
+    /// Returns the full Plus Code (Open Location Code) of the area containing
+    /// Self, with given number of digits: 2, 4, 6, 8, or 10 to 15.
+    /// ```
+    /// use dms_coordinates::DMS3d;
+    /// let coords = DMS3d::from_ddeg_angles(47.365590, 8.524997, None);
+    /// let code = coords.to_plus_code(10).unwrap();
+    /// assert_eq!(code.to_string(), "8FVC9G8F+6X");
+    /// ```
+    pub fn to_plus_code(&self, length: usize) -> Result<PlusCode, Error> {
+        PlusCode::from_ddeg_angles(
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            length,
+        )
+    }
+
+    /// Parses an ISO 6709 string, like `+40.7306-073.9352+10.0CRSWGS_84/`,
+    /// altitude being expressed in meters. Refer to [Location::parse]
+    /// to retrieve the angle notation and the coordinate reference system.
+    /// ```
+    /// use dms_coordinates::{DMS3d, Cardinal};
+    /// let coords = DMS3d::from_iso6709("+404350.196-0735606.871/").unwrap();
+    /// assert_eq!(coords.latitude.seconds, 50.196);
+    /// assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+    /// ```
+    pub fn from_iso6709(s: &str) -> Result<Self, Error> {
+        Location::parse(s).map(|location| location.coords)
+    }
+
+    /// Returns the ISO 6709 representation of Self, in given angle notation.
+    /// The shortest representation preserving each angle is used,
+    /// unless a precision is given.
+    /// ```
+    /// use dms_coordinates::{DMS3d, iso6709::Style};
+    /// let coords = DMS3d::from_ddeg_angles(40.730610, -73.935242, Some(10.0));
+    /// assert_eq!(
+    ///     coords.to_iso6709(Style::Degrees).with_precision(4).with_crs("WGS_84").to_string(),
+    ///     "+40.7306-073.9352+10.0CRSWGS_84/"
+    /// );
+    /// ```
+    pub fn to_iso6709<'a>(&self, style: Style) -> DelayedIso6709<'a> {
+        DelayedIso6709::new(*self, style)
+    }
+
+    /// Converts Self to Cartesian Coordinates (x, y, z), on the WGS84 ellipsoid.
     /// (x = 0, y = 0, z = 0) being Earth center, in Cartesian coordinates.
+    /// Altitude is considered as the height above the ellipsoid, 0 if not defined.
     pub fn to_cartesian(&self) -> rust_3d::Point3D {
-        let (lat, lon) = (
-            map_3d::deg2rad(self.latitude.to_ddeg_angle()),
-            map_3d::deg2rad(self.longitude.to_ddeg_angle()),
+        self.to_ecef(Ellipsoid::WGS84)
+    }
+
+    /// Converts Self to ECEF coordinates (x, y, z), on given ellipsoid.
+    /// Altitude is considered as the height above the ellipsoid, 0 if not defined.
+    pub fn to_ecef(&self, ellipsoid: Ellipsoid) -> rust_3d::Point3D {
+        let (x, y, z) = ellipsoid.geodetic_to_ecef(
+            self.latitude.to_ddeg_angle(),
+            self.longitude.to_ddeg_angle(),
+            self.altitude.unwrap_or(0.0_f64),
+        );
+        rust_3d::Point3D { x, y, z }
+    }
+    /// Returns an object that displays Self according to given pattern,
+    /// similarly to `chrono::format`. Refer to [crate::format] for
+    /// supported specifiers.
+    /// ```
+    /// use dms_coordinates::DMS3d;
+    /// let coords = DMS3d::from_ddeg_angles(40.730610, -73.935242, Some(10.0));
+    /// assert_eq!(
+    ///     coords.format("%D°%M'%.1S\"%C %(lon)%D°%M'%.1S\"%C %Am").to_string(),
+    ///     "40°43'50.2\"N 73°56'6.9\"W 10.0m"
+    /// );
+    /// ```
+    pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+        DelayedFormat::coordinates(*self, pattern)
+    }
+
+    /// Returns an object that displays Self as an NMEA 0183 sentence of given
+    /// kind (GGA, RMC, GLL or WPL), checksum included. Time, fix quality,
+    /// speed and course are attached to the returned object.
+    /// ```
+    /// use dms_coordinates::{nmea::{SentenceKind, Time}, DMS3d};
+    /// let coords = DMS3d::from_ddeg_angles(48.1173, 11.516666667, Some(545.4));
+    /// let time = Time {
+    ///     hours: 12,
+    ///     minutes: 35,
+    ///     seconds: 19.0,
+    /// };
+    /// let gga = coords.to_nmea(SentenceKind::GGA).with_time(time);
+    /// assert_eq!(
+    ///     gga.to_string(),
+    ///     "$GPGGA,123519.00,4807.0380,N,01131.0000,E,1,,,545.4,M,,,,*1E"
+    /// );
+    /// ```
+    pub fn to_nmea<'a>(&self, kind: SentenceKind) -> DelayedSentence<'a> {
+        DelayedSentence::new(*self, kind)
+    }
+
+    /// Transforms Self, expressed in `from` datum, to `to` datum, using given method.
+    /// Altitude is considered as the height above the ellipsoid, 0 if not defined,
+    /// in which case the returned altitude is not defined either.
+    /// ```
+    /// use dms_coordinates::{DMS3d, Datum, datum::Transformation};
+    /// // Greenwich Airy transit circle
+    /// let wgs84 = DMS3d::from_ddeg_angles(51.477811, -0.001475, Some(46.0));
+    /// let osgb36 = wgs84.transform(Datum::WGS84, Datum::OSGB36, Transformation::Helmert);
+    /// assert!((osgb36.latitude.to_ddeg_angle() - 51.4773).abs() < 5E-5);
+    /// assert!((osgb36.longitude.to_ddeg_angle() - 0.0001).abs() < 5E-5);
+    /// // back to WGS84
+    /// let coords = osgb36.transform(Datum::OSGB36, Datum::WGS84, Transformation::Helmert);
+    /// assert!((coords.latitude.to_ddeg_angle() - 51.477811).abs() < 1E-9);
+    /// ```
+    pub fn transform(&self, from: Datum, to: Datum, method: Transformation) -> DMS3d {
+        let (latitude, longitude, altitude) = from.transform(
+            &to,
+            (
+                self.latitude.to_ddeg_angle(),
+                self.longitude.to_ddeg_angle(),
+                self.altitude.unwrap_or(0.0_f64),
+            ),
+            method,
         );
-        rust_3d::Point3D {
-            x: EARTH_RADIUS * lat.cos() * lon.cos(),
-            y: EARTH_RADIUS * lat.cos() * lon.sin(),
-            z: EARTH_RADIUS * lat.sin(),
+        DMS3d {
+            latitude: DMS::from_ddeg_latitude(latitude),
+            longitude: DMS::from_ddeg_longitude(longitude),
+            altitude: self.altitude.map(|_| altitude),
         }
     }
-    /// Converts Self from WGS84 to EU50 Data
+
+    /// Converts Self from WGS84 to ED50 (European Datum 1950),
+    /// with the Helmert transformation
     pub fn to_europe50(&self) -> Result<DMS3d, Error> {
-        Ok(DMS3d {
-            latitude: self.latitude.to_europe50()?,
-            longitude: self.longitude.to_europe50()?,
-            altitude: self.altitude,
-        })
+        Ok(self.transform(Datum::WGS84, Datum::ED50, Transformation::Helmert))
     }
 }
 
 #[cfg(feature = "gpx")]
 impl From<Waypoint> for DMS3d {
+    /// Converts a GPX waypoint, its elevation being the altitude
     fn from(wpt: Waypoint) -> Self {
-        Self::from_ddeg_angles(wpt.point().x(), wpt.point().y(), wpt.elevation)
+        Self::from_ddeg_angles(wpt.point().y(), wpt.point().x(), wpt.elevation)
     }
 }
 
@@ -0,0 +1,174 @@
+//! Reference ellipsoids, used to model the Earth surface
+//! and convert geodetic coordinates to / from ECEF coordinates.
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Reference ellipsoid of revolution, defined by its
+/// semi major axis and its flattening
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ellipsoid {
+    /// Semi major axis (equatorial radius) `a`, in meters
+    pub semi_major_axis: f64,
+    /// Flattening `f = (a - b) / a`
+    pub flattening: f64,
+}
+
+impl Default for Ellipsoid {
+    /// Builds WGS84 ellipsoid
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
+impl Ellipsoid {
+    /// World Geodetic System 1984, used by GPS
+    pub const WGS84: Self = Self::new(6_378_137.0, 298.257_223_563);
+    /// World Geodetic System 1972
+    pub const WGS72: Self = Self::new(6_378_135.0, 298.26);
+    /// Geodetic Reference System 1980, used by NAD83, ETRS89, GDA94..
+    pub const GRS80: Self = Self::new(6_378_137.0, 298.257_222_101);
+    /// Geodetic Reference System 1967
+    pub const GRS67: Self = Self::new(6_378_160.0, 298.247_167_427);
+    /// International 1924 (Hayford), used by ED50
+    pub const INTERNATIONAL_1924: Self = Self::new(6_378_388.0, 297.0);
+    /// Clarke 1866, used by NAD27
+    pub const CLARKE_1866: Self = Self::new(6_378_206.4, 294.978_698_2);
+    /// Clarke 1880 (RGS)
+    pub const CLARKE_1880: Self = Self::new(6_378_249.145, 293.465);
+    /// Airy 1830, used by OSGB36
+    pub const AIRY_1830: Self = Self::new(6_377_563.396, 299.324_964_6);
+    /// Modified Airy, used by Ireland 1965
+    pub const AIRY_MODIFIED: Self = Self::new(6_377_340.189, 299.324_964_6);
+    /// Bessel 1841, used by Tokyo and DHDN datums
+    pub const BESSEL_1841: Self = Self::new(6_377_397.155, 299.152_812_8);
+    /// Krassovsky 1940, used by Pulkovo 1942
+    pub const KRASSOVSKY_1940: Self = Self::new(6_378_245.0, 298.3);
+    /// Australian National Spheroid, used by AGD66 / AGD84
+    pub const AUSTRALIAN_NATIONAL: Self = Self::new(6_378_160.0, 298.25);
+
+    /// Builds an ellipsoid from its semi major axis (m)
+    /// and its inverse flattening `1/f`
+    pub const fn new(semi_major_axis: f64, inverse_flattening: f64) -> Self {
+        Self {
+            semi_major_axis,
+            flattening: 1.0 / inverse_flattening,
+        }
+    }
+
+    /// Builds a sphere of given radius (m)
+    pub const fn sphere(radius: f64) -> Self {
+        Self {
+            semi_major_axis: radius,
+            flattening: 0.0,
+        }
+    }
+
+    /// Returns semi minor axis (polar radius) `b`, in meters
+    pub fn semi_minor_axis(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.flattening)
+    }
+
+    /// Returns first eccentricity squared `e² = f (2 - f)`
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// Returns second eccentricity squared `e'² = e² / (1 - e²)`
+    pub fn second_eccentricity_squared(&self) -> f64 {
+        let e2 = self.eccentricity_squared();
+        e2 / (1.0 - e2)
+    }
+
+    /// Returns third flattening `n = f / (2 - f)`
+    pub fn third_flattening(&self) -> f64 {
+        self.flattening / (2.0 - self.flattening)
+    }
+
+    /// Returns mean radius `(2a + b) / 3`, in meters
+    pub fn mean_radius(&self) -> f64 {
+        (2.0 * self.semi_major_axis + self.semi_minor_axis()) / 3.0
+    }
+
+    /// Returns prime vertical radius of curvature `N`, in meters,
+    /// at given latitude expressed in radians
+    pub fn prime_vertical_radius(&self, latitude: f64) -> f64 {
+        self.semi_major_axis / (1.0 - self.eccentricity_squared() * latitude.sin().powi(2)).sqrt()
+    }
+
+    /// Returns meridian arc length from the equator to given latitude,
+    /// expressed in radians, in meters (Helmert's series, to the 4th order in n)
+    pub fn meridian_arc(&self, latitude: f64) -> f64 {
+        let n = self.third_flattening();
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        self.semi_major_axis / (1.0 + n)
+            * ((1.0 + n2 / 4.0 + n4 / 64.0) * latitude
+                - 1.5 * (n - n3 / 8.0) * (2.0 * latitude).sin()
+                + 15.0 / 16.0 * (n2 - n4 / 4.0) * (4.0 * latitude).sin()
+                - 35.0 / 48.0 * n3 * (6.0 * latitude).sin()
+                + 315.0 / 512.0 * n4 * (8.0 * latitude).sin())
+    }
+
+    /// Returns latitude, in radians, at which the meridian arc
+    /// from the equator has given length in meters. Inverse of [Ellipsoid::meridian_arc]
+    pub fn meridian_arc_latitude(&self, arc: f64) -> f64 {
+        let n = self.third_flattening();
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        // rectifying latitude
+        let mu = arc * (1.0 + n) / (self.semi_major_axis * (1.0 + n2 / 4.0 + n4 / 64.0));
+        mu + (1.5 * n - 27.0 / 32.0 * n3) * (2.0 * mu).sin()
+            + (21.0 / 16.0 * n2 - 55.0 / 32.0 * n4) * (4.0 * mu).sin()
+            + 151.0 / 96.0 * n3 * (6.0 * mu).sin()
+            + 1097.0 / 512.0 * n4 * (8.0 * mu).sin()
+    }
+
+    /// Returns isometric latitude ψ, at given latitude expressed in radians.
+    /// ψ is the Mercator projection ordinate, infinite at the poles
+    pub fn isometric_latitude(&self, latitude: f64) -> f64 {
+        if latitude.abs() >= core::f64::consts::FRAC_PI_2 {
+            return f64::INFINITY.copysign(latitude);
+        }
+        let e = self.eccentricity_squared().sqrt();
+        latitude.tan().asinh() - e * (e * latitude.sin()).atanh()
+    }
+
+    /// Converts geodetic coordinates: latitude and longitude in decimal degrees,
+    /// height above ellipsoid in meters, to ECEF (x, y, z) coordinates in meters
+    pub fn geodetic_to_ecef(&self, latitude: f64, longitude: f64, height: f64) -> (f64, f64, f64) {
+        let (lat, lon) = (latitude.to_radians(), longitude.to_radians());
+        let n = self.prime_vertical_radius(lat);
+        let e2 = self.eccentricity_squared();
+        (
+            (n + height) * lat.cos() * lon.cos(),
+            (n + height) * lat.cos() * lon.sin(),
+            (n * (1.0 - e2) + height) * lat.sin(),
+        )
+    }
+
+    /// Converts ECEF (x, y, z) coordinates in meters to geodetic coordinates:
+    /// (latitude, longitude) in decimal degrees and height above ellipsoid in meters.
+    /// This uses Vermeille's exact closed form solution (J. Geodesy 2002),
+    /// valid anywhere but in the immediate vicinity of the Earth center.
+    pub fn ecef_to_geodetic(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let a = self.semi_major_axis;
+        let e2 = self.eccentricity_squared();
+        let e4 = e2 * e2;
+        let rho2 = x * x + y * y;
+        let p = rho2 / (a * a);
+        let q = (1.0 - e2) * z * z / (a * a);
+        let r = (p + q - e4) / 6.0;
+        let s = e4 * p * q / (4.0 * r * r * r);
+        let t = (1.0 + s + (s * (2.0 + s)).sqrt()).cbrt();
+        let u = r * (1.0 + t + 1.0 / t);
+        let v = (u * u + e4 * q).sqrt();
+        let w = e2 * (u + v - q) / (2.0 * v);
+        let k = (u + v + w * w).sqrt() - w;
+        let d = k * rho2.sqrt() / (k + e2);
+        let dz = (d * d + z * z).sqrt();
+        let latitude = 2.0 * z.atan2(d + dz);
+        let longitude = y.atan2(x);
+        let height = (k + e2 - 1.0) / k * dz;
+        (latitude.to_degrees(), longitude.to_degrees(), height)
+    }
+}
@@ -0,0 +1,311 @@
+//! `chrono::format` like formatting of D°M'S" angles and 3D coordinates.
+//!
+//! Supported specifiers:
+//!   - `%D`: integer degrees D°
+//!   - `%M`: integer minutes M'
+//!   - `%S`: fractionnal seconds S", 4 decimals by default
+//!   - `%d`: decimal degrees (unsigned), 6 decimals by default
+//!   - `%m`: decimal minutes (M' + S"/60), 4 decimals by default
+//!   - `%C`: cardinal letter, nothing when no cardinal is associated
+//!   - `%+`: sign deduced from cardinal, or from the sign of angles with no cardinal,
+//!     always printed ("+" or "-")
+//!   - `%-`: same sign, only printed when negative
+//!   - `%A`: altitude in meters, 1 decimal by default (3D coordinates only)
+//!   - `%(lat)`, `%(lon)`: following specifiers apply to latitude / longitude
+//!     (3D coordinates only, latitude being the default)
+//!   - `%%`: a literal "%"
+//!
+//! Precision is given with `.N`, like `%.3S` or `%.5d`,
+//! and zero padding with `0`, like `%0D` or `%0.2S`: degrees are then padded to
+//! 2 digits for latitudes, 3 digits otherwise, minutes and seconds to 2 digits.
+//! Rounding to the requested precision carries over to the upper units,
+//! so 59.99999" printed with `%.2S` becomes 1' more and 0.00".
+//! Each angle is rounded to the precision of its first `%S`, or else `%m`, specifier.
+//!
+//! Displaying an invalid pattern returns a [core::fmt::Error], which makes
+//! `to_string()` panic: patterns can be checked beforehand with [is_valid].
+use crate::{DMS3d, DMS};
+
+/// Default number of decimals for `%S`
+const SECONDS_PRECISION: usize = 4;
+/// Default number of decimals for `%d`
+const DDEG_PRECISION: usize = 6;
+/// Default number of decimals for `%m`
+const MINUTES_PRECISION: usize = 4;
+/// Default number of decimals for `%A`
+const ALTITUDE_PRECISION: usize = 1;
+
+/// Formatting item, parsed from the pattern
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Item<'a> {
+    /// Literal text
+    Literal(&'a str),
+    /// Specifier, with padding flag and optionnal precision
+    Spec(char, bool, Option<usize>),
+    /// Switch to latitude (true) or longitude (false)
+    Axis(bool),
+    /// Pattern is not valid
+    Error,
+}
+
+/// Iterates over the items of a format pattern
+#[derive(Debug, Clone)]
+struct Items<'a> {
+    remainder: &'a str,
+}
+
+impl<'a> Iterator for Items<'a> {
+    type Item = Item<'a>;
+    fn next(&mut self) -> Option<Item<'a>> {
+        if self.remainder.is_empty() {
+            return None;
+        }
+        let s = self.remainder;
+        if let Some(spec) = s.strip_prefix('%') {
+            if let Some(rem) = spec.strip_prefix('%') {
+                self.remainder = rem;
+                return Some(Item::Literal("%"));
+            }
+            if let Some(rem) = spec.strip_prefix("(lat)") {
+                self.remainder = rem;
+                return Some(Item::Axis(true));
+            }
+            if let Some(rem) = spec.strip_prefix("(lon)") {
+                self.remainder = rem;
+                return Some(Item::Axis(false));
+            }
+            let (pad, spec) = match spec.strip_prefix('0') {
+                Some(spec) => (true, spec),
+                None => (false, spec),
+            };
+            let (precision, spec) = match spec.strip_prefix('.') {
+                Some(spec) => {
+                    let digits = spec.bytes().take_while(|b| b.is_ascii_digit()).count();
+                    match spec[..digits].parse::<usize>() {
+                        Ok(p) => (Some(p), &spec[digits..]),
+                        Err(_) => {
+                            self.remainder = "";
+                            return Some(Item::Error);
+                        }
+                    }
+                }
+                None => (None, spec),
+            };
+            let mut chars = spec.chars();
+            match chars.next() {
+                Some(c) if "DMSdmCA+-".contains(c) => {
+                    self.remainder = chars.as_str();
+                    Some(Item::Spec(c, pad, precision))
+                }
+                _ => {
+                    self.remainder = "";
+                    Some(Item::Error)
+                }
+            }
+        } else {
+            let end = s.find('%').unwrap_or(s.len());
+            self.remainder = &s[end..];
+            Some(Item::Literal(&s[..end]))
+        }
+    }
+}
+
+/// Angle components, rounded to the precision requested
+/// by the pattern for this angle
+struct Components {
+    degrees: u32,
+    minutes: u32,
+    seconds: f64,
+}
+
+impl Components {
+    /// Builds components of either the latitude (or single angle),
+    /// or the longitude, only considering the specifiers applying to it
+    fn new(angle: &DMS, pattern: &str, latitude: bool) -> Self {
+        let total = angle.total_seconds();
+        let mut seconds_precision: Option<usize> = None;
+        let mut minutes_precision: Option<usize> = None;
+        let mut on_latitude = true;
+        for item in (Items { remainder: pattern }) {
+            match item {
+                Item::Axis(lat) => on_latitude = lat,
+                _ if on_latitude != latitude => {}
+                Item::Spec('S', _, p) => {
+                    seconds_precision.get_or_insert(p.unwrap_or(SECONDS_PRECISION));
+                }
+                Item::Spec('m', _, p) => {
+                    minutes_precision.get_or_insert(p.unwrap_or(MINUTES_PRECISION));
+                }
+                _ => {}
+            }
+        }
+        let total = if let Some(p) = seconds_precision {
+            let scale = 10.0_f64.powi(p as i32);
+            (total * scale).round() / scale
+        } else if let Some(p) = minutes_precision {
+            let scale = 10.0_f64.powi(p as i32);
+            (total / 60.0 * scale).round() / scale * 60.0
+        } else {
+            total
+        };
+        // guards against floating point residues, like 3599.9999999
+        let degrees = ((total + 1.0E-9) / 3600.0).floor();
+        let minutes = ((total - degrees * 3600.0 + 1.0E-9) / 60.0).floor();
+        let seconds = (total - degrees * 3600.0 - minutes * 60.0).max(0.0);
+        Self {
+            degrees: degrees as u32,
+            minutes: minutes as u32,
+            seconds,
+        }
+    }
+}
+
+/// Formatted D°M'S" angle or 3D coordinates, returned by
+/// [DMS::format] and [DMS3d::format]. Formatting happens when
+/// this object is displayed, without any allocation.
+#[derive(Debug, Clone)]
+pub struct DelayedFormat<'a> {
+    latitude: DMS,
+    longitude: Option<DMS>,
+    altitude: Option<f64>,
+    pattern: &'a str,
+}
+
+impl<'a> DelayedFormat<'a> {
+    /// Formats a single angle
+    pub(crate) fn angle(angle: DMS, pattern: &'a str) -> Self {
+        Self {
+            latitude: angle,
+            longitude: None,
+            altitude: None,
+            pattern,
+        }
+    }
+    /// Formats 3D coordinates
+    pub(crate) fn coordinates(coords: DMS3d, pattern: &'a str) -> Self {
+        Self {
+            latitude: coords.latitude,
+            longitude: Some(coords.longitude),
+            altitude: coords.altitude,
+            pattern,
+        }
+    }
+}
+
+/// Writes a float with given padded width & precision
+fn write_padded(
+    f: &mut core::fmt::Formatter,
+    value: f64,
+    integer_digits: usize,
+    pad: bool,
+    precision: usize,
+) -> core::fmt::Result {
+    if pad {
+        let width = if precision > 0 {
+            integer_digits + 1 + precision
+        } else {
+            integer_digits
+        };
+        write!(
+            f,
+            "{:0width$.precision$}",
+            value,
+            width = width,
+            precision = precision
+        )
+    } else {
+        write!(f, "{:.precision$}", value, precision = precision)
+    }
+}
+
+impl<'a> core::fmt::Display for DelayedFormat<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let latitude = Components::new(&self.latitude, self.pattern, true);
+        let longitude = self
+            .longitude
+            .map(|lon| Components::new(&lon, self.pattern, false));
+        let mut on_latitude = true;
+        for item in (Items {
+            remainder: self.pattern,
+        }) {
+            let (angle, components) = match (on_latitude, &self.longitude, &longitude) {
+                (false, Some(angle), Some(components)) => (angle, components),
+                _ => (&self.latitude, &latitude),
+            };
+            let degrees_digits = match angle.cardinal {
+                Some(c) if c.is_latitude() => 2,
+                _ => 3,
+            };
+            let negative = angle.is_negative();
+            match item {
+                Item::Literal(s) => f.write_str(s)?,
+                Item::Axis(lat) => {
+                    if self.longitude.is_none() {
+                        return Err(core::fmt::Error);
+                    }
+                    on_latitude = lat;
+                }
+                Item::Spec('D', pad, _) => {
+                    let width = if pad { degrees_digits } else { 0 };
+                    write!(f, "{:0width$}", components.degrees, width = width)?
+                }
+                Item::Spec('M', pad, _) => {
+                    let width = if pad { 2 } else { 0 };
+                    write!(f, "{:0width$}", components.minutes, width = width)?
+                }
+                Item::Spec('S', pad, p) => write_padded(
+                    f,
+                    components.seconds,
+                    2,
+                    pad,
+                    p.unwrap_or(SECONDS_PRECISION),
+                )?,
+                Item::Spec('m', pad, p) => write_padded(
+                    f,
+                    components.minutes as f64 + components.seconds / 60.0,
+                    2,
+                    pad,
+                    p.unwrap_or(MINUTES_PRECISION),
+                )?,
+                Item::Spec('d', pad, p) => write_padded(
+                    f,
+                    angle.to_ddeg_angle().abs(),
+                    degrees_digits,
+                    pad,
+                    p.unwrap_or(DDEG_PRECISION),
+                )?,
+                Item::Spec('C', _, _) => {
+                    if let Some(cardinal) = angle.cardinal {
+                        write!(f, "{}", cardinal)?
+                    }
+                }
+                Item::Spec('+', _, _) => f.write_str(if negative { "-" } else { "+" })?,
+                Item::Spec('-', _, _) => {
+                    if negative {
+                        f.write_str("-")?
+                    }
+                }
+                Item::Spec('A', _, p) => {
+                    if self.longitude.is_none() {
+                        return Err(core::fmt::Error);
+                    }
+                    write!(
+                        f,
+                        "{:.precision$}",
+                        self.altitude.unwrap_or(0.0_f64),
+                        precision = p.unwrap_or(ALTITUDE_PRECISION)
+                    )?
+                }
+                _ => return Err(core::fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if given pattern is a valid format pattern.
+/// Displaying an invalid pattern returns an error, so does a valid pattern
+/// using `%A`, `%(lat)` or `%(lon)` on a single angle
+pub fn is_valid(pattern: &str) -> bool {
+    !(Items { remainder: pattern }).any(|item| item == Item::Error)
+}
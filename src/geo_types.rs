@@ -0,0 +1,137 @@
+//! Conversions between [DMS3d] coordinates and `geo_types` geometries.
+//!
+//! `geo_types` coordinates are 2D, `x` being the longitude and `y` the latitude,
+//! both in decimal degrees. Altitudes are dropped when converting to `geo_types`,
+//! and are left undefined when converting back: `Path::altitudes` and
+//! `Path::with_altitudes` carry them over explicitly.
+use crate::{DMS3d, Error};
+use core::convert::TryFrom;
+
+impl From<DMS3d> for ::geo_types::Coord<f64> {
+    /// Converts to `(x: longitude, y: latitude)`, altitude being dropped
+    fn from(coords: DMS3d) -> Self {
+        Self {
+            x: coords.longitude.to_ddeg_angle(),
+            y: coords.latitude.to_ddeg_angle(),
+        }
+    }
+}
+
+impl From<DMS3d> for ::geo_types::Point<f64> {
+    /// Converts to `(x: longitude, y: latitude)`, altitude being dropped
+    fn from(coords: DMS3d) -> Self {
+        Self(::geo_types::Coord::from(coords))
+    }
+}
+
+impl TryFrom<::geo_types::Coord<f64>> for DMS3d {
+    type Error = Error;
+    /// Converts `(x: longitude, y: latitude)`, with no altitude
+    fn try_from(coord: ::geo_types::Coord<f64>) -> Result<Self, Error> {
+        if !(-90.0..=90.0).contains(&coord.y) {
+            return Err(Error::InvalidLatitude);
+        }
+        if !(-180.0..=180.0).contains(&coord.x) {
+            return Err(Error::InvalidLongitude);
+        }
+        Ok(DMS3d::from_ddeg_angles(coord.y, coord.x, None))
+    }
+}
+
+impl TryFrom<::geo_types::Point<f64>> for DMS3d {
+    type Error = Error;
+    /// Converts `(x: longitude, y: latitude)`, with no altitude
+    fn try_from(point: ::geo_types::Point<f64>) -> Result<Self, Error> {
+        Self::try_from(point.0)
+    }
+}
+
+/// Path going through a list of coordinates, converted to and from a `LineString`
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Path(pub Vec<DMS3d>);
+
+/// Polygon made of [DMS3d] rings, converted to and from a `geo_types::Polygon`
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polygon {
+    /// Exterior ring
+    pub exterior: Path,
+    /// Holes
+    pub interiors: Vec<Path>,
+}
+
+#[cfg(feature = "std")]
+impl Path {
+    /// Returns the altitude of each position, to be restored
+    /// with [Path::with_altitudes] after a round trip through `geo_types`
+    pub fn altitudes(&self) -> Vec<Option<f64>> {
+        self.0.iter().map(|coords| coords.altitude).collect()
+    }
+
+    /// Builds a copy of Self with given altitudes, position by position.
+    /// Positions past the end of `altitudes` are left untouched
+    pub fn with_altitudes(&self, altitudes: &[Option<f64>]) -> Self {
+        let mut path = self.clone();
+        for (coords, altitude) in path.0.iter_mut().zip(altitudes) {
+            coords.altitude = *altitude;
+        }
+        path
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Vec<DMS3d>> for Path {
+    fn from(coords: Vec<DMS3d>) -> Self {
+        Self(coords)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Path> for ::geo_types::LineString<f64> {
+    /// Converts each position, altitudes being dropped
+    fn from(path: Path) -> Self {
+        path.0.into_iter().map(::geo_types::Coord::from).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&::geo_types::LineString<f64>> for Path {
+    type Error = Error;
+    /// Converts each position, with no altitude
+    fn try_from(line: &::geo_types::LineString<f64>) -> Result<Self, Error> {
+        Ok(Self(
+            line.coords()
+                .map(|coord| DMS3d::try_from(*coord))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Polygon> for ::geo_types::Polygon<f64> {
+    /// Converts each ring, altitudes being dropped.
+    /// Rings get closed if they were not already
+    fn from(polygon: Polygon) -> Self {
+        Self::new(
+            polygon.exterior.into(),
+            polygon.interiors.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&::geo_types::Polygon<f64>> for Polygon {
+    type Error = Error;
+    /// Converts each ring, with no altitude
+    fn try_from(polygon: &::geo_types::Polygon<f64>) -> Result<Self, Error> {
+        Ok(Self {
+            exterior: Path::try_from(polygon.exterior())?,
+            interiors: polygon
+                .interiors()
+                .iter()
+                .map(Path::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -0,0 +1,1099 @@
+//! Geodesic calculations on a reference ellipsoid.
+//!
+//! The inverse problem (distance and azimuths between two points)
+//! and the direct problem (destination reached from a point, an azimuth
+//! and a distance) can be solved with:
+//!   - the spherical haversine formula, on the ellipsoid mean radius: fast, 0.5% error
+//!   - Vincenty's iterative method: sub-millimeter accuracy, but fails to converge
+//!     for nearly antipodal points
+//!   - Karney's method (J. Geodesy 2013, as implemented by GeographicLib):
+//!     accurate to round-off and converging for any pair of points
+use crate::{Ellipsoid, Error};
+
+/// Algorithm used to solve geodesic problems
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Method {
+    /// Great circle on a sphere of the ellipsoid mean radius
+    Haversine,
+    /// Vincenty's iterative formulae (1975)
+    Vincenty,
+    /// Karney's algorithms (2013)
+    #[default]
+    Karney,
+}
+
+/// Solution of the inverse geodesic problem
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Inverse {
+    /// Geodesic distance between both points, in meters
+    pub distance: f64,
+    /// Azimuth at first point, towards second point,
+    /// in decimal degrees, 0 <= ɑ < 360, 0 being North
+    pub forward_azimuth: f64,
+    /// Azimuth at second point, back towards first point,
+    /// in decimal degrees, 0 <= ɑ < 360, 0 being North
+    pub reverse_azimuth: f64,
+}
+
+impl Inverse {
+    /// Returns azimuth of the geodesic at second point, in the direction
+    /// of travel (as opposed to [Inverse::reverse_azimuth])
+    pub fn final_azimuth(&self) -> f64 {
+        normalize_azimuth(self.reverse_azimuth + 180.0)
+    }
+}
+
+/// Solution of the direct geodesic problem
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Direct {
+    /// Latitude of the destination, in decimal degrees
+    pub latitude: f64,
+    /// Longitude of the destination, in decimal degrees, -180 < λ <= 180
+    pub longitude: f64,
+    /// Azimuth of the geodesic at destination, in the direction of travel,
+    /// in decimal degrees, 0 <= ɑ < 360, 0 being North
+    pub final_azimuth: f64,
+}
+
+/// Maximal number of iterations in Vincenty's method
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+
+/// Solves the inverse geodesic problem between point 1 and point 2,
+/// both expressed as (latitude, longitude) in decimal degrees, on given ellipsoid.
+/// Vincenty's method may fail to converge, for nearly antipodal points.
+pub fn inverse(
+    ellipsoid: Ellipsoid,
+    p1: (f64, f64),
+    p2: (f64, f64),
+    method: Method,
+) -> Result<Inverse, Error> {
+    match method {
+        Method::Haversine => Ok(haversine(ellipsoid.mean_radius(), p1, p2)),
+        Method::Vincenty => vincenty(ellipsoid, p1, p2),
+        Method::Karney => Ok(Karney::new(ellipsoid).inverse(p1, p2)),
+    }
+}
+
+/// Solves the direct geodesic problem: travels `distance` meters from point 1,
+/// expressed as (latitude, longitude) in decimal degrees, with initial `azimuth`
+/// in decimal degrees (0 being North), on given ellipsoid.
+/// Negative distances travel backwards.
+pub fn direct(
+    ellipsoid: Ellipsoid,
+    p1: (f64, f64),
+    azimuth: f64,
+    distance: f64,
+    method: Method,
+) -> Result<Direct, Error> {
+    match method {
+        Method::Haversine => Ok(great_circle_direct(
+            ellipsoid.mean_radius(),
+            p1,
+            azimuth,
+            distance,
+        )),
+        Method::Vincenty => vincenty_direct(ellipsoid, p1, azimuth, distance),
+        Method::Karney => Ok(Karney::new(ellipsoid).direct(p1, azimuth, distance)),
+    }
+}
+
+/// Wraps an azimuth, in decimal degrees, to 0 <= ɑ < 360
+pub(crate) fn normalize_azimuth(azimuth: f64) -> f64 {
+    let azimuth = azimuth.rem_euclid(360.0);
+    if azimuth >= 360.0 {
+        0.0
+    } else {
+        azimuth
+    }
+}
+
+/// Great circle azimuth from point 1 to point 2, in decimal degrees
+fn great_circle_azimuth(p1: (f64, f64), p2: (f64, f64)) -> f64 {
+    let (phi1, phi2) = (p1.0.to_radians(), p2.0.to_radians());
+    let dlambda = (p2.1 - p1.1).to_radians();
+    let y = dlambda.sin() * phi2.cos();
+    let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * dlambda.cos();
+    normalize_azimuth(y.atan2(x).to_degrees())
+}
+
+fn haversine(radius: f64, p1: (f64, f64), p2: (f64, f64)) -> Inverse {
+    let dphi = (p2.0 - p1.0).to_radians();
+    let dlambda = (p2.1 - p1.1).to_radians();
+    let a = (dphi / 2.0).sin().powi(2)
+        + p1.0.to_radians().cos() * p2.0.to_radians().cos() * (dlambda / 2.0).sin().powi(2);
+    let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+    Inverse {
+        distance: radius * c,
+        forward_azimuth: great_circle_azimuth(p1, p2),
+        reverse_azimuth: great_circle_azimuth(p2, p1),
+    }
+}
+
+fn great_circle_direct(radius: f64, p1: (f64, f64), azimuth: f64, distance: f64) -> Direct {
+    let delta = distance / radius;
+    let (sin_phi1, cos_phi1) = p1.0.to_radians().sin_cos();
+    let (sin_theta, cos_theta) = azimuth.to_radians().sin_cos();
+    let (sin_delta, cos_delta) = delta.sin_cos();
+    let sin_phi2 = (sin_phi1 * cos_delta + cos_phi1 * sin_delta * cos_theta).clamp(-1.0, 1.0);
+    let phi2 = sin_phi2.asin();
+    let dlambda = (sin_theta * sin_delta * cos_phi1).atan2(cos_delta - sin_phi1 * sin_phi2);
+    let alpha2 =
+        (cos_phi1 * sin_theta).atan2(cos_phi1 * cos_theta * cos_delta - sin_phi1 * sin_delta);
+    Direct {
+        latitude: phi2.to_degrees(),
+        longitude: ang_normalize(p1.1 + dlambda.to_degrees()),
+        final_azimuth: normalize_azimuth(alpha2.to_degrees()),
+    }
+}
+
+fn vincenty_direct(
+    ellipsoid: Ellipsoid,
+    p1: (f64, f64),
+    azimuth: f64,
+    distance: f64,
+) -> Result<Direct, Error> {
+    let (a, f) = (ellipsoid.semi_major_axis, ellipsoid.flattening);
+    let b = ellipsoid.semi_minor_axis();
+    let (sin_alpha1, cos_alpha1) = azimuth.to_radians().sin_cos();
+    let tan_u1 = (1.0 - f) * p1.0.to_radians().tan();
+    let cos_u1 = 1.0 / (1.0 + tan_u1 * tan_u1).sqrt();
+    let sin_u1 = tan_u1 * cos_u1;
+    let sigma1 = tan_u1.atan2(cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+
+    let mut sigma = distance / (b * big_a);
+    let mut iterations = 0;
+    let (sin_sigma, cos_sigma, cos_2sigma_m) = loop {
+        let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos_2sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                        - big_b / 6.0
+                            * cos_2sigma_m
+                            * (-3.0 + 4.0 * sin_sigma.powi(2))
+                            * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+        let previous = sigma;
+        sigma = distance / (b * big_a) + delta_sigma;
+        iterations += 1;
+        if iterations > VINCENTY_MAX_ITERATIONS {
+            return Err(Error::NoConvergence);
+        }
+        if (sigma - previous).abs() < 1.0E-12 {
+            let (sin_sigma, cos_sigma) = sigma.sin_cos();
+            break (sin_sigma, cos_sigma, (2.0 * sigma1 + sigma).cos());
+        }
+    };
+
+    let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let phi2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+        .atan2((1.0 - f) * (sin_alpha * sin_alpha + x * x).sqrt());
+    let lambda =
+        (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+    let l = lambda
+        - (1.0 - c)
+            * f
+            * sin_alpha
+            * (sigma
+                + c * sin_sigma
+                    * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+    let alpha2 = sin_alpha.atan2(-x);
+    Ok(Direct {
+        latitude: phi2.to_degrees(),
+        longitude: ang_normalize(p1.1 + l.to_degrees()),
+        final_azimuth: normalize_azimuth(alpha2.to_degrees()),
+    })
+}
+
+fn vincenty(ellipsoid: Ellipsoid, p1: (f64, f64), p2: (f64, f64)) -> Result<Inverse, Error> {
+    let (a, f) = (ellipsoid.semi_major_axis, ellipsoid.flattening);
+    let b = ellipsoid.semi_minor_axis();
+    let l = ang_normalize(p2.1 - p1.1).to_radians();
+    let u1 = ((1.0 - f) * p1.0.to_radians().tan()).atan();
+    let u2 = ((1.0 - f) * p2.0.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    let mut iterations = 0;
+    let (sin_sigma, cos_sigma, sigma, cos_sq_alpha, cos_2sigma_m, sin_lambda, cos_lambda) = loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            // coincident points
+            return Ok(Inverse {
+                distance: 0.0,
+                forward_azimuth: 0.0,
+                reverse_azimuth: 0.0,
+            });
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        let cos_2sigma_m = if cos_sq_alpha != 0.0 {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        } else {
+            // equatorial line
+            0.0
+        };
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let previous = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+        iterations += 1;
+        if lambda.abs() > core::f64::consts::PI || iterations > VINCENTY_MAX_ITERATIONS {
+            return Err(Error::NoConvergence);
+        }
+        if (lambda - previous).abs() < 1.0E-12 {
+            break (
+                sin_sigma,
+                cos_sigma,
+                sigma,
+                cos_sq_alpha,
+                cos_2sigma_m,
+                sin_lambda,
+                cos_lambda,
+            );
+        }
+    };
+
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    let delta_sigma = big_b
+        * sin_sigma
+        * (cos_2sigma_m
+            + big_b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                    - big_b / 6.0
+                        * cos_2sigma_m
+                        * (-3.0 + 4.0 * sin_sigma.powi(2))
+                        * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+    let alpha1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+    let alpha2 = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+    Ok(Inverse {
+        distance: b * big_a * (sigma - delta_sigma),
+        forward_azimuth: normalize_azimuth(alpha1.to_degrees()),
+        reverse_azimuth: normalize_azimuth(alpha2.to_degrees() + 180.0),
+    })
+}
+
+/// Order of the series expansions used in Karney's method
+const ORDER: usize = 6;
+/// Number of C3 coefficients
+const NC3X: usize = 15;
+
+/// A3 coefficients, polynomials in n, from highest to lowest order in eps
+const COEFF_A3: [f64; 18] = [
+    -3.0, 128.0, -2.0, -3.0, 64.0, -1.0, -3.0, -1.0, 16.0, 3.0, -1.0, -2.0, 8.0, 1.0, -1.0, 2.0,
+    1.0, 1.0,
+];
+
+/// C3 coefficients, polynomials in n
+const COEFF_C3: [f64; 45] = [
+    3.0, 128.0, 2.0, 5.0, 128.0, -1.0, 3.0, 3.0, 64.0, -1.0, 0.0, 1.0, 8.0, -1.0, 1.0, 4.0, 5.0,
+    256.0, 1.0, 3.0, 128.0, -3.0, -2.0, 3.0, 64.0, 1.0, -3.0, 2.0, 32.0, 7.0, 512.0, -10.0, 9.0,
+    384.0, 5.0, -9.0, 5.0, 192.0, 7.0, 512.0, -14.0, 7.0, 512.0, 21.0, 2560.0,
+];
+
+/// C1 coefficients, polynomials in eps²
+const COEFF_C1: [f64; 18] = [
+    -1.0, 6.0, -16.0, 32.0, -9.0, 64.0, -128.0, 2048.0, 9.0, -16.0, 768.0, 3.0, -5.0, 512.0, -7.0,
+    1280.0, -7.0, 2048.0,
+];
+
+/// C1' coefficients, polynomials in eps²
+const COEFF_C1P: [f64; 18] = [
+    205.0, -432.0, 768.0, 1536.0, 4005.0, -4736.0, 3840.0, 12288.0, -225.0, 116.0, 384.0, -7173.0,
+    2695.0, 7680.0, 3467.0, 7680.0, 38081.0, 61440.0,
+];
+
+/// C2 coefficients, polynomials in eps²
+const COEFF_C2: [f64; 18] = [
+    1.0, 2.0, 16.0, 32.0, 35.0, 64.0, 384.0, 2048.0, 15.0, 80.0, 768.0, 7.0, 35.0, 512.0, 63.0,
+    1280.0, 77.0, 2048.0,
+];
+
+/// Evaluates polynomial of degree n, coefficients from highest order
+fn polyval(n: usize, p: &[f64], x: f64) -> f64 {
+    p[1..=n].iter().fold(p[0], |y, c| y * x + c)
+}
+
+/// Normalizes (x, y) vector
+fn norm(x: f64, y: f64) -> (f64, f64) {
+    let r = x.hypot(y);
+    (x / r, y / r)
+}
+
+/// Error free sum: returns (s, t) where s = u + v rounded, t the round-off error
+fn sum(u: f64, v: f64) -> (f64, f64) {
+    let s = u + v;
+    let up = s - v;
+    let vpp = s - up;
+    (s, -((up - u) + (vpp - v)))
+}
+
+/// Rounds tiny values so that 1/16 - x is exact, avoids underflows
+fn ang_round(x: f64) -> f64 {
+    let z = 1.0 / 16.0;
+    let y = x.abs();
+    let y = if y < z { z - (z - y) } else { y };
+    if x == 0.0 {
+        0.0
+    } else {
+        y.copysign(x)
+    }
+}
+
+/// Wraps angle in decimal degrees to -180 < x <= 180
+pub(crate) fn ang_normalize(x: f64) -> f64 {
+    let y = x % 360.0;
+    let y = if y <= -180.0 {
+        y + 360.0
+    } else if y > 180.0 {
+        y - 360.0
+    } else {
+        y
+    };
+    if y == -180.0 {
+        180.0
+    } else {
+        y
+    }
+}
+
+/// Exact difference y - x of two angles in decimal degrees,
+/// reduced to -180 <= d <= 180, and its round-off error
+fn ang_diff(x: f64, y: f64) -> (f64, f64) {
+    let (d, t) = sum(ang_normalize(-x), ang_normalize(y));
+    let d = ang_normalize(d);
+    if d == 180.0 && t > 0.0 {
+        sum(-180.0, t)
+    } else {
+        sum(d, t)
+    }
+}
+
+/// Sine and cosine of an angle in decimal degrees, exact for multiples of 90°
+fn sincosd(x: f64) -> (f64, f64) {
+    let r = x % 360.0;
+    let q = (r / 90.0).round();
+    let (s, c) = (r - 90.0 * q).to_radians().sin_cos();
+    let (s, c) = match (q as i32).rem_euclid(4) {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let s = if s == 0.0 { s.copysign(x) } else { s };
+    (s, c + 0.0)
+}
+
+/// atan2 returning decimal degrees, exact for multiples of 90°
+fn atan2d(y: f64, x: f64) -> f64 {
+    let (mut y, mut x) = (y, x);
+    let mut q = 0;
+    if y.abs() > x.abs() {
+        core::mem::swap(&mut x, &mut y);
+        q = 2;
+    }
+    if x < 0.0 {
+        q += 1;
+        x = -x;
+    }
+    let ang = y.atan2(x).to_degrees();
+    match q {
+        1 => {
+            if y >= 0.0 {
+                180.0 - ang
+            } else {
+                -180.0 - ang
+            }
+        }
+        2 => 90.0 - ang,
+        3 => -90.0 + ang,
+        _ => ang,
+    }
+}
+
+/// Evaluates sum(c[i] sin(2 i x)) (sinp) or sum(c[i] cos((2 i + 1) x)),
+/// using Clenshaw summation
+fn sin_cos_series(sinp: bool, sinx: f64, cosx: f64, c: &[f64]) -> f64 {
+    let mut k = c.len();
+    let mut n = k - if sinp { 1 } else { 0 };
+    let ar = 2.0 * (cosx - sinx) * (cosx + sinx);
+    let mut y1 = 0.0;
+    let mut y0 = if n & 1 != 0 {
+        k -= 1;
+        c[k]
+    } else {
+        0.0
+    };
+    n /= 2;
+    while n > 0 {
+        n -= 1;
+        k -= 1;
+        y1 = ar * y0 - y1 + c[k];
+        k -= 1;
+        y0 = ar * y1 - y0 + c[k];
+    }
+    if sinp {
+        2.0 * sinx * cosx * y0
+    } else {
+        cosx * (y0 - y1)
+    }
+}
+
+/// Solves the astroid problem k⁴ + 2k³ - (x² + y² - 1) k² - 2y² k - y² = 0,
+/// for the positive root k
+fn astroid(x: f64, y: f64) -> f64 {
+    let p = x * x;
+    let q = y * y;
+    let r = (p + q - 1.0) / 6.0;
+    if q == 0.0 && r <= 0.0 {
+        return 0.0;
+    }
+    let s = p * q / 4.0;
+    let r2 = r * r;
+    let r3 = r * r2;
+    let disc = s * (s + 2.0 * r3);
+    let mut u = r;
+    if disc >= 0.0 {
+        let mut t3 = s + r3;
+        t3 += if t3 < 0.0 { -disc.sqrt() } else { disc.sqrt() };
+        let t = t3.cbrt();
+        u += t + if t != 0.0 { r2 / t } else { 0.0 };
+    } else {
+        let ang = (-disc).sqrt().atan2(-(s + r3));
+        u += 2.0 * r * (ang / 3.0).cos();
+    }
+    let v = (u * u + q).sqrt();
+    let uv = if u < 0.0 { q / (v - u) } else { u + v };
+    let w = (uv - q) / (2.0 * v);
+    uv / ((uv + w * w).sqrt() + w)
+}
+
+/// A1 - 1 series
+fn a1m1f(eps: f64) -> f64 {
+    let coeff = [1.0, 4.0, 64.0, 0.0, 256.0];
+    let m = ORDER / 2;
+    let t = polyval(m, &coeff, eps * eps) / coeff[m + 1];
+    (t + eps) / (1.0 - eps)
+}
+
+/// A2 - 1 series
+fn a2m1f(eps: f64) -> f64 {
+    let coeff = [-11.0, -28.0, -192.0, 0.0, 256.0];
+    let m = ORDER / 2;
+    let t = polyval(m, &coeff, eps * eps) / coeff[m + 1];
+    (t - eps) / (1.0 + eps)
+}
+
+/// C1 or C2 series coefficients, c[0] being unused
+fn cxf(coeff: &[f64; 18], eps: f64) -> [f64; ORDER + 1] {
+    let mut c = [0.0; ORDER + 1];
+    let eps2 = eps * eps;
+    let mut d = eps;
+    let mut o = 0;
+    for (l, cl) in c.iter_mut().enumerate().skip(1) {
+        let m = (ORDER - l) / 2;
+        *cl = d * polyval(m, &coeff[o..], eps2) / coeff[o + m + 1];
+        o += m + 2;
+        d *= eps;
+    }
+    c
+}
+
+/// Karney's geodesic solver, for a given ellipsoid
+pub(crate) struct Karney {
+    a: f64,
+    f: f64,
+    f1: f64,
+    ep2: f64,
+    n: f64,
+    b: f64,
+    etol2: f64,
+    a3x: [f64; ORDER],
+    c3x: [f64; NC3X],
+    tiny: f64,
+    tol0: f64,
+    tol1: f64,
+    tolb: f64,
+    xthresh: f64,
+}
+
+/// Intermediate results of [Karney::lambda12]
+struct Lambda12 {
+    lam12: f64,
+    salp2: f64,
+    calp2: f64,
+    sig12: f64,
+    ssig1: f64,
+    csig1: f64,
+    ssig2: f64,
+    csig2: f64,
+    eps: f64,
+    dlam12: f64,
+}
+
+impl Karney {
+    const MAXIT1: usize = 20;
+    const MAXIT2: usize = Self::MAXIT1 + 53 + 10;
+
+    pub(crate) fn new(ellipsoid: Ellipsoid) -> Self {
+        let (a, f) = (ellipsoid.semi_major_axis, ellipsoid.flattening);
+        let tol0 = f64::EPSILON;
+        let tol2 = tol0.sqrt();
+        let f1 = 1.0 - f;
+        let e2 = f * (2.0 - f);
+        let n = f / (2.0 - f);
+
+        let mut a3x = [0.0; ORDER];
+        let mut o = 0;
+        for (k, j) in (0..ORDER).rev().enumerate() {
+            let m = j.min(ORDER - j - 1);
+            a3x[k] = polyval(m, &COEFF_A3[o..], n) / COEFF_A3[o + m + 1];
+            o += m + 2;
+        }
+        let mut c3x = [0.0; NC3X];
+        let (mut o, mut k) = (0, 0);
+        for l in 1..ORDER {
+            for j in (l..ORDER).rev() {
+                let m = j.min(ORDER - j - 1);
+                c3x[k] = polyval(m, &COEFF_C3[o..], n) / COEFF_C3[o + m + 1];
+                k += 1;
+                o += m + 2;
+            }
+        }
+        Self {
+            a,
+            f,
+            f1,
+            ep2: e2 / (f1 * f1),
+            n,
+            b: a * f1,
+            etol2: 0.1 * tol2 / (f.abs().max(0.001) * (1.0 - f / 2.0).min(1.0) / 2.0).sqrt(),
+            a3x,
+            c3x,
+            tiny: f64::MIN_POSITIVE.sqrt(),
+            tol0,
+            tol1: 200.0 * tol0,
+            tolb: tol0 * tol2,
+            xthresh: 1000.0 * tol2,
+        }
+    }
+
+    fn a3f(&self, eps: f64) -> f64 {
+        polyval(ORDER - 1, &self.a3x, eps)
+    }
+
+    fn c3f(&self, eps: f64) -> [f64; ORDER] {
+        let mut c = [0.0; ORDER];
+        let mut mult = 1.0;
+        let mut o = 0;
+        for (l, cl) in c.iter_mut().enumerate().skip(1) {
+            let m = ORDER - l - 1;
+            mult *= eps;
+            *cl = mult * polyval(m, &self.c3x[o..], eps);
+            o += m + 1;
+        }
+        c
+    }
+
+    /// Returns (s12b, m12b, m0): distance and reduced length
+    /// in units of b, and m0 coefficient
+    #[allow(clippy::too_many_arguments)]
+    fn lengths(
+        &self,
+        eps: f64,
+        sig12: f64,
+        ssig1: f64,
+        csig1: f64,
+        dn1: f64,
+        ssig2: f64,
+        csig2: f64,
+        dn2: f64,
+    ) -> (f64, f64, f64) {
+        let c1a = cxf(&COEFF_C1, eps);
+        let c2a = cxf(&COEFF_C2, eps);
+        let a1 = a1m1f(eps);
+        let a2 = a2m1f(eps);
+        let m0 = a1 - a2;
+        let (a1, a2) = (a1 + 1.0, a2 + 1.0);
+        let b1 =
+            sin_cos_series(true, ssig2, csig2, &c1a) - sin_cos_series(true, ssig1, csig1, &c1a);
+        let b2 =
+            sin_cos_series(true, ssig2, csig2, &c2a) - sin_cos_series(true, ssig1, csig1, &c2a);
+        let s12b = a1 * (sig12 + b1);
+        let j12 = m0 * sig12 + (a1 * b1 - a2 * b2);
+        let m12b = dn2 * (csig1 * ssig2) - dn1 * (ssig1 * csig2) - csig1 * csig2 * j12;
+        (s12b, m12b, m0)
+    }
+
+    /// Returns starting point for Newton's method, as
+    /// (sig12, salp1, calp1, salp2, calp2, dnm)
+    #[allow(clippy::too_many_arguments)]
+    fn inverse_start(
+        &self,
+        sbet1: f64,
+        cbet1: f64,
+        dn1: f64,
+        sbet2: f64,
+        cbet2: f64,
+        dn2: f64,
+        lam12: f64,
+        slam12: f64,
+        clam12: f64,
+    ) -> (f64, f64, f64, f64, f64, f64) {
+        let pi = core::f64::consts::PI;
+        let mut sig12 = -1.0;
+        let (mut salp2, mut calp2, mut dnm) = (f64::NAN, f64::NAN, f64::NAN);
+        let sbet12 = sbet2 * cbet1 - cbet2 * sbet1;
+        let cbet12 = cbet2 * cbet1 + sbet2 * sbet1;
+        let sbet12a = sbet2 * cbet1 + cbet2 * sbet1;
+        let shortline = cbet12 >= 0.0 && sbet12 < 0.5 && cbet2 * lam12 < 0.5;
+        let (mut somg12, mut comg12) = if shortline {
+            let sbetm2 = (sbet1 + sbet2).powi(2);
+            let sbetm2 = sbetm2 / (sbetm2 + (cbet1 + cbet2).powi(2));
+            dnm = (1.0 + self.ep2 * sbetm2).sqrt();
+            let omg12 = lam12 / (self.f1 * dnm);
+            omg12.sin_cos()
+        } else {
+            (slam12, clam12)
+        };
+
+        let mut salp1 = cbet2 * somg12;
+        let mut calp1 = if comg12 >= 0.0 {
+            sbet12 + cbet2 * sbet1 * somg12 * somg12 / (1.0 + comg12)
+        } else {
+            sbet12a - cbet2 * sbet1 * somg12 * somg12 / (1.0 - comg12)
+        };
+        let ssig12 = salp1.hypot(calp1);
+        let csig12 = sbet1 * sbet2 + cbet1 * cbet2 * comg12;
+
+        if shortline && ssig12 < self.etol2 {
+            // really short lines
+            let s = cbet1 * somg12;
+            let c = sbet12
+                - cbet1
+                    * sbet2
+                    * if comg12 >= 0.0 {
+                        somg12 * somg12 / (1.0 + comg12)
+                    } else {
+                        1.0 - comg12
+                    };
+            let (s, c) = norm(s, c);
+            salp2 = s;
+            calp2 = c;
+            sig12 = ssig12.atan2(csig12);
+        } else if self.n.abs() > 0.1
+            || csig12 >= 0.0
+            || ssig12 >= 6.0 * self.n.abs() * pi * cbet1 * cbet1
+        {
+            // nothing to do, zeroth order spherical approximation is OK
+        } else {
+            // nearly antipodal points: scale to an astroid problem
+            let lam12x = (-slam12).atan2(-clam12);
+            let (x, y, lamscale) = if self.f >= 0.0 {
+                let k2 = sbet1 * sbet1 * self.ep2;
+                let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+                let lamscale = self.f * cbet1 * self.a3f(eps) * pi;
+                let betscale = lamscale * cbet1;
+                (lam12x / lamscale, sbet12a / betscale, lamscale)
+            } else {
+                let cbet12a = cbet2 * cbet1 - sbet2 * sbet1;
+                let bet12a = sbet12a.atan2(cbet12a);
+                let (ssig1, csig1) = (sbet1, -cbet1);
+                let (_, m12b, m0) =
+                    self.lengths(self.n, pi + bet12a, ssig1, csig1, dn1, sbet2, cbet2, dn2);
+                let x = -1.0 + m12b / (cbet1 * cbet2 * m0 * pi);
+                let betscale = if x < -0.01 {
+                    sbet12a / x
+                } else {
+                    -self.f * cbet1 * cbet1 * pi
+                };
+                let lamscale = betscale / cbet1;
+                (x, lam12x / lamscale, lamscale)
+            };
+            if y > -self.tol1 && x > -1.0 - self.xthresh {
+                if self.f >= 0.0 {
+                    salp1 = (-x).min(1.0);
+                    calp1 = -(1.0 - salp1 * salp1).sqrt();
+                } else {
+                    calp1 = x.max(if x > -self.tol1 { 0.0 } else { -1.0 });
+                    salp1 = (1.0 - calp1 * calp1).sqrt();
+                }
+            } else {
+                let k = astroid(x, y);
+                let omg12a = lamscale
+                    * if self.f >= 0.0 {
+                        -x * k / (1.0 + k)
+                    } else {
+                        -y * (1.0 + k) / k
+                    };
+                somg12 = omg12a.sin();
+                comg12 = -omg12a.cos();
+                salp1 = cbet2 * somg12;
+                calp1 = sbet12a - cbet2 * sbet1 * somg12 * somg12 / (1.0 - comg12);
+            }
+        }
+        let (salp1, calp1) = if salp1 > 0.0 || salp1.is_nan() {
+            norm(salp1, calp1)
+        } else {
+            (1.0, 0.0)
+        };
+        (sig12, salp1, calp1, salp2, calp2, dnm)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn lambda12(
+        &self,
+        sbet1: f64,
+        cbet1: f64,
+        dn1: f64,
+        sbet2: f64,
+        cbet2: f64,
+        dn2: f64,
+        salp1: f64,
+        calp1: f64,
+        slam120: f64,
+        clam120: f64,
+        diffp: bool,
+    ) -> Lambda12 {
+        let calp1 = if sbet1 == 0.0 && calp1 == 0.0 {
+            // break degeneracy of equatorial line
+            -self.tiny
+        } else {
+            calp1
+        };
+        let salp0 = salp1 * cbet1;
+        let calp0 = calp1.hypot(salp1 * sbet1);
+
+        let somg1 = salp0 * sbet1;
+        let comg1 = calp1 * cbet1;
+        let (ssig1, csig1) = norm(sbet1, calp1 * cbet1);
+
+        let salp2 = if cbet2 != cbet1 { salp0 / cbet2 } else { salp1 };
+        let calp2 = if cbet2 != cbet1 || sbet2.abs() != -sbet1 {
+            ((calp1 * cbet1).powi(2)
+                + if cbet1 < -sbet1 {
+                    (cbet2 - cbet1) * (cbet1 + cbet2)
+                } else {
+                    (sbet1 - sbet2) * (sbet1 + sbet2)
+                })
+            .sqrt()
+                / cbet2
+        } else {
+            calp1.abs()
+        };
+        let somg2 = salp0 * sbet2;
+        let comg2 = calp2 * cbet2;
+        let (ssig2, csig2) = norm(sbet2, calp2 * cbet2);
+
+        let sig12 = (csig1 * ssig2 - ssig1 * csig2)
+            .max(0.0)
+            .atan2(csig1 * csig2 + ssig1 * ssig2);
+        let somg12 = (comg1 * somg2 - somg1 * comg2).max(0.0);
+        let comg12 = comg1 * comg2 + somg1 * somg2;
+        let eta = (somg12 * clam120 - comg12 * slam120).atan2(comg12 * clam120 + somg12 * slam120);
+
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+        let c3a = self.c3f(eps);
+        let b312 =
+            sin_cos_series(true, ssig2, csig2, &c3a) - sin_cos_series(true, ssig1, csig1, &c3a);
+        let domg12 = -self.f * self.a3f(eps) * salp0 * (sig12 + b312);
+        let lam12 = eta + domg12;
+
+        let dlam12 = if diffp {
+            if calp2 == 0.0 {
+                -2.0 * self.f1 * dn1 / sbet1
+            } else {
+                let (_, m12b, _) = self.lengths(eps, sig12, ssig1, csig1, dn1, ssig2, csig2, dn2);
+                m12b * self.f1 / (calp2 * cbet2)
+            }
+        } else {
+            f64::NAN
+        };
+        Lambda12 {
+            lam12,
+            salp2,
+            calp2,
+            sig12,
+            ssig1,
+            csig1,
+            ssig2,
+            csig2,
+            eps,
+            dlam12,
+        }
+    }
+
+    /// Solves the direct problem from (lat1, lon1), with initial azimuth
+    /// and distance in meters
+    pub(crate) fn direct(&self, p1: (f64, f64), azimuth: f64, distance: f64) -> Direct {
+        let (salp1, calp1) = sincosd(ang_round(ang_normalize(azimuth)));
+        let (sbet1, cbet1) = sincosd(ang_round(p1.0));
+        let (sbet1, cbet1) = norm(self.f1 * sbet1, cbet1);
+        let cbet1 = cbet1.max(self.tiny);
+        let salp0 = salp1 * cbet1;
+        let calp0 = calp1.hypot(salp1 * sbet1);
+        let somg1 = salp0 * sbet1;
+        let comg1 = if sbet1 != 0.0 || calp1 != 0.0 {
+            cbet1 * calp1
+        } else {
+            1.0
+        };
+        let (ssig1, csig1) = norm(sbet1, comg1);
+
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+        let a1 = a1m1f(eps) + 1.0;
+        let c1a = cxf(&COEFF_C1, eps);
+        let c1pa = cxf(&COEFF_C1P, eps);
+        let b11 = sin_cos_series(true, ssig1, csig1, &c1a);
+        let (s, c) = b11.sin_cos();
+        let stau1 = ssig1 * c + csig1 * s;
+        let ctau1 = csig1 * c - ssig1 * s;
+
+        // distance to arc length, on the auxiliary sphere
+        let tau12 = distance / (self.b * a1);
+        let (s, c) = tau12.sin_cos();
+        let b12 = -sin_cos_series(true, stau1 * c + ctau1 * s, ctau1 * c - stau1 * s, &c1pa);
+        let mut sig12 = tau12 - (b12 - b11);
+        let (mut ssig12, mut csig12) = sig12.sin_cos();
+        if self.f.abs() > 0.01 {
+            // one Newton step, to reach full accuracy for large flattening
+            let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+            let csig2 = csig1 * csig12 - ssig1 * ssig12;
+            let b12 = sin_cos_series(true, ssig2, csig2, &c1a);
+            let serr = a1 * (sig12 + (b12 - b11)) - distance / self.b;
+            sig12 -= serr / (1.0 + k2 * ssig2 * ssig2).sqrt();
+            let (s, c) = sig12.sin_cos();
+            ssig12 = s;
+            csig12 = c;
+        }
+        let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+        let mut csig2 = csig1 * csig12 - ssig1 * ssig12;
+        let sbet2 = calp0 * ssig2;
+        let mut cbet2 = salp0.hypot(calp0 * csig2);
+        if cbet2 == 0.0 {
+            // destination is a pole
+            cbet2 = self.tiny;
+            csig2 = self.tiny;
+        }
+        let salp2 = salp0;
+        let calp2 = calp0 * csig2;
+
+        let somg2 = salp0 * ssig2;
+        let comg2 = csig2;
+        let e = 1.0_f64.copysign(salp0);
+        let omg12 = e
+            * (sig12 - (ssig2.atan2(csig2) - ssig1.atan2(csig1))
+                + ((e * somg2).atan2(comg2) - (e * somg1).atan2(comg1)));
+        let c3a = self.c3f(eps);
+        let b31 = sin_cos_series(true, ssig1, csig1, &c3a);
+        let b32 = sin_cos_series(true, ssig2, csig2, &c3a);
+        let lam12 = omg12 - self.f * self.a3f(eps) * salp0 * (sig12 + (b32 - b31));
+        Direct {
+            latitude: atan2d(sbet2, self.f1 * cbet2),
+            longitude: ang_normalize(ang_normalize(p1.1) + ang_normalize(lam12.to_degrees())),
+            final_azimuth: normalize_azimuth(atan2d(salp2, calp2)),
+        }
+    }
+
+    /// Solves the inverse problem between (lat1, lon1) and (lat2, lon2)
+    pub(crate) fn inverse(&self, p1: (f64, f64), p2: (f64, f64)) -> Inverse {
+        let (s12, salp1, calp1, salp2, calp2) = self.gen_inverse(p1.0, p1.1, p2.0, p2.1);
+        Inverse {
+            distance: s12,
+            forward_azimuth: normalize_azimuth(atan2d(salp1, calp1)),
+            reverse_azimuth: normalize_azimuth(atan2d(salp2, calp2) + 180.0),
+        }
+    }
+
+    /// Returns (s12, salp1, calp1, salp2, calp2)
+    fn gen_inverse(&self, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> (f64, f64, f64, f64, f64) {
+        // compute longitude difference exactly, and reduce the problem
+        // to lat1 <= 0, lat1 <= lat2 <= -lat1, 0 <= lon12 <= 180
+        let (lon12, lon12s) = ang_diff(lon1, lon2);
+        let mut lonsign = if lon12 >= 0.0 { 1.0 } else { -1.0 };
+        let lon12 = lonsign * ang_round(lon12);
+        let lon12s = ang_round((180.0 - lon12) - lonsign * lon12s);
+        let lam12 = lon12.to_radians();
+        let (slam12, clam12) = if lon12 > 90.0 {
+            let (s, c) = sincosd(lon12s);
+            (s, -c)
+        } else {
+            sincosd(lon12)
+        };
+
+        let mut lat1 = ang_round(if lat1.abs() > 90.0 { f64::NAN } else { lat1 });
+        let mut lat2 = ang_round(if lat2.abs() > 90.0 { f64::NAN } else { lat2 });
+        let swapp = if lat1.abs() < lat2.abs() { -1.0 } else { 1.0 };
+        if swapp < 0.0 {
+            lonsign *= -1.0;
+            core::mem::swap(&mut lat1, &mut lat2);
+        }
+        let latsign = if lat1 < 0.0 { 1.0 } else { -1.0 };
+        lat1 *= latsign;
+        lat2 *= latsign;
+
+        let (s, c) = sincosd(lat1);
+        let (sbet1, cbet1) = norm(self.f1 * s, c);
+        let cbet1 = cbet1.max(self.tiny);
+        let (s, c) = sincosd(lat2);
+        let (mut sbet2, mut cbet2) = norm(self.f1 * s, c);
+        cbet2 = cbet2.max(self.tiny);
+
+        if cbet1 < -sbet1 {
+            if cbet2 == cbet1 {
+                sbet2 = if sbet2 < 0.0 { sbet1 } else { -sbet1 };
+            }
+        } else if sbet2.abs() == -sbet1 {
+            cbet2 = cbet1;
+        }
+
+        let dn1 = (1.0 + self.ep2 * sbet1 * sbet1).sqrt();
+        let dn2 = (1.0 + self.ep2 * sbet2 * sbet2).sqrt();
+
+        let mut meridian = lat1 == -90.0 || slam12 == 0.0;
+        let (mut salp1, mut calp1, mut salp2, mut calp2) = (0.0, 0.0, 0.0, 0.0);
+        let mut s12x = 0.0;
+
+        if meridian {
+            // along a meridian, or from a pole
+            calp1 = clam12;
+            salp1 = slam12;
+            calp2 = 1.0;
+            salp2 = 0.0;
+            let (ssig1, csig1) = (sbet1, calp1 * cbet1);
+            let (ssig2, csig2) = (sbet2, calp2 * cbet2);
+            let sig12 = (csig1 * ssig2 - ssig1 * csig2)
+                .max(0.0)
+                .atan2(csig1 * csig2 + ssig1 * ssig2);
+            let (s12b, m12b, _) = self.lengths(self.n, sig12, ssig1, csig1, dn1, ssig2, csig2, dn2);
+            s12x = s12b;
+            if sig12 < 1.0 || m12b >= 0.0 {
+                if sig12 < 3.0 * self.tiny {
+                    // coincident points
+                    s12x = 0.0;
+                }
+                s12x *= self.b;
+            } else {
+                // not a shortest path
+                meridian = false;
+            }
+        }
+
+        if !meridian && sbet1 == 0.0 && (self.f <= 0.0 || lon12s >= self.f * 180.0) {
+            // along the equator
+            calp1 = 0.0;
+            calp2 = 0.0;
+            salp1 = 1.0;
+            salp2 = 1.0;
+            s12x = self.a * lam12;
+        } else if !meridian {
+            let (sig12, s1, c1, s2, c2, dnm) =
+                self.inverse_start(sbet1, cbet1, dn1, sbet2, cbet2, dn2, lam12, slam12, clam12);
+            salp1 = s1;
+            calp1 = c1;
+            if sig12 >= 0.0 {
+                // short lines
+                salp2 = s2;
+                calp2 = c2;
+                s12x = sig12 * self.b * dnm;
+            } else {
+                // Newton's method, with bisection fallback
+                let mut tripn = false;
+                let mut tripb = false;
+                let (mut salp1a, mut calp1a) = (self.tiny, 1.0);
+                let (mut salp1b, mut calp1b) = (self.tiny, -1.0);
+                let mut res = self.lambda12(
+                    sbet1, cbet1, dn1, sbet2, cbet2, dn2, salp1, calp1, slam12, clam12, true,
+                );
+                for numit in 0..Self::MAXIT2 {
+                    if numit > 0 {
+                        res = self.lambda12(
+                            sbet1,
+                            cbet1,
+                            dn1,
+                            sbet2,
+                            cbet2,
+                            dn2,
+                            salp1,
+                            calp1,
+                            slam12,
+                            clam12,
+                            numit < Self::MAXIT1,
+                        );
+                    }
+                    let v = res.lam12;
+                    if tripb
+                        || v.abs() < if tripn { 8.0 } else { 1.0 } * self.tol0
+                        || v.abs().is_nan()
+                    {
+                        break;
+                    }
+                    if v > 0.0 && (numit > Self::MAXIT1 || calp1 / salp1 > calp1b / salp1b) {
+                        salp1b = salp1;
+                        calp1b = calp1;
+                    } else if v < 0.0 && (numit > Self::MAXIT1 || calp1 / salp1 < calp1a / salp1a) {
+                        salp1a = salp1;
+                        calp1a = calp1;
+                    }
+                    if numit < Self::MAXIT1 && res.dlam12 > 0.0 {
+                        let dalp1 = -v / res.dlam12;
+                        let (sdalp1, cdalp1) = dalp1.sin_cos();
+                        let nsalp1 = salp1 * cdalp1 + calp1 * sdalp1;
+                        if nsalp1 > 0.0 && dalp1.abs() < core::f64::consts::PI {
+                            let (s, c) = norm(nsalp1, calp1 * cdalp1 - salp1 * sdalp1);
+                            salp1 = s;
+                            calp1 = c;
+                            tripn = v.abs() <= 16.0 * self.tol0;
+                            continue;
+                        }
+                    }
+                    // bisection
+                    let (s, c) = norm((salp1a + salp1b) / 2.0, (calp1a + calp1b) / 2.0);
+                    salp1 = s;
+                    calp1 = c;
+                    tripn = false;
+                    tripb = (salp1a - salp1).abs() + (calp1a - calp1) < self.tolb
+                        || (salp1 - salp1b).abs() + (calp1 - calp1b) < self.tolb;
+                }
+                let (s12b, _, _) = self.lengths(
+                    res.eps, res.sig12, res.ssig1, res.csig1, dn1, res.ssig2, res.csig2, dn2,
+                );
+                salp2 = res.salp2;
+                calp2 = res.calp2;
+                s12x = s12b * self.b;
+            }
+        }
+
+        if swapp < 0.0 {
+            core::mem::swap(&mut salp1, &mut salp2);
+            core::mem::swap(&mut calp1, &mut calp2);
+        }
+        salp1 *= swapp * lonsign;
+        calp1 *= swapp * latsign;
+        salp2 *= swapp * lonsign;
+        calp2 *= swapp * latsign;
+        (0.0 + s12x, salp1, calp1, salp2, calp2)
+    }
+}
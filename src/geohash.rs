@@ -0,0 +1,240 @@
+//! Geohash encoding and decoding.
+//!
+//! A geohash like `u09tunq` interleaves longitude and latitude bits,
+//! longitude first, 5 bits per base 32 character: each character divides
+//! the cell into 32 smaller cells. Up to 12 characters are supported,
+//! that is cells of a few centimeters.
+use crate::{Cardinal, DMS3d, Error};
+
+/// Base 32 alphabet
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+/// Maximal number of characters
+const MAX_LENGTH: usize = 12;
+
+/// Geohash, from 1 to 12 characters
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Geohash {
+    /// Geohash characters
+    hash: [u8; MAX_LENGTH],
+    /// Number of characters
+    length: usize,
+}
+
+impl core::fmt::Display for Geohash {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl core::str::FromStr for Geohash {
+    type Err = Error;
+    /// Parses a geohash like "u09tunq", case insensitive
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let s = s.trim().as_bytes();
+        if s.is_empty() || s.len() > MAX_LENGTH {
+            return Err(Error::InvalidGeohash);
+        }
+        let mut hash = [0_u8; MAX_LENGTH];
+        for (i, c) in s.iter().enumerate() {
+            let c = c.to_ascii_lowercase();
+            if !ALPHABET.contains(&c) {
+                return Err(Error::InvalidGeohash);
+            }
+            hash[i] = c;
+        }
+        Ok(Self {
+            hash,
+            length: s.len(),
+        })
+    }
+}
+
+/// Returns the number of longitude and latitude bits, for given length
+fn bits(length: usize) -> (u32, u32) {
+    let bits = 5 * length as u32;
+    (bits - bits / 2, bits / 2)
+}
+
+impl Geohash {
+    /// Returns the geohash of the cell containing (latitude, longitude),
+    /// in decimal degrees, with given number of characters, 1 to 12
+    pub fn from_ddeg_angles(latitude: f64, longitude: f64, length: usize) -> Result<Self, Error> {
+        if length == 0 || length > MAX_LENGTH {
+            return Err(Error::InvalidGeohash);
+        }
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(Error::InvalidLatitude);
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(Error::InvalidLongitude);
+        }
+        let (lon_bits, lat_bits) = bits(length);
+        // the north pole and the antimeridian belong to the last cells
+        let index = |value: f64, bits: u32| {
+            let cells = (1_u64 << bits) as f64;
+            ((value * cells).floor() as u64).min((1_u64 << bits) - 1)
+        };
+        Ok(Self::from_indexes(
+            index((longitude + 180.0) / 360.0, lon_bits),
+            index((latitude + 90.0) / 180.0, lat_bits),
+            length,
+        ))
+    }
+
+    /// Builds a geohash from its longitude and latitude cell indexes
+    fn from_indexes(longitude: u64, latitude: u64, length: usize) -> Self {
+        let (mut lon_bits, mut lat_bits) = bits(length);
+        let mut value = 0_u64;
+        for bit in 0..5 * length {
+            value <<= 1;
+            if bit % 2 == 0 {
+                lon_bits -= 1;
+                value |= (longitude >> lon_bits) & 1;
+            } else {
+                lat_bits -= 1;
+                value |= (latitude >> lat_bits) & 1;
+            }
+        }
+        let mut hash = [0_u8; MAX_LENGTH];
+        for (i, c) in hash.iter_mut().enumerate().take(length) {
+            *c = ALPHABET[((value >> (5 * (length - 1 - i))) & 31) as usize];
+        }
+        Self { hash, length }
+    }
+
+    /// Returns the longitude and latitude cell indexes
+    fn indexes(&self) -> (u64, u64) {
+        let (mut longitude, mut latitude) = (0_u64, 0_u64);
+        let mut bit = 0;
+        for c in self.hash.iter().take(self.length) {
+            let value = ALPHABET.iter().position(|a| a == c).unwrap_or(0);
+            for shift in (0..5).rev() {
+                let value = ((value >> shift) & 1) as u64;
+                if bit % 2 == 0 {
+                    longitude = (longitude << 1) | value;
+                } else {
+                    latitude = (latitude << 1) | value;
+                }
+                bit += 1;
+            }
+        }
+        (longitude, latitude)
+    }
+
+    /// Returns geohash characters
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.hash[..self.length]).unwrap_or_default()
+    }
+
+    /// Returns the number of characters
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if Self has no characters, which is never the case
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the cell size, as (height, width) in decimal degrees
+    pub fn size(&self) -> (f64, f64) {
+        let (lon_bits, lat_bits) = bits(self.length);
+        (
+            180.0 / (1_u64 << lat_bits) as f64,
+            360.0 / (1_u64 << lon_bits) as f64,
+        )
+    }
+
+    /// Returns the south western and north eastern corners of the cell
+    pub fn bounds(&self) -> (DMS3d, DMS3d) {
+        let (longitude, latitude) = self.indexes();
+        let (height, width) = self.size();
+        let (south, west) = (
+            latitude as f64 * height - 90.0,
+            longitude as f64 * width - 180.0,
+        );
+        (
+            DMS3d::from_ddeg_angles(south, west, None),
+            DMS3d::from_ddeg_angles(south + height, west + width, None),
+        )
+    }
+
+    /// Returns the centre of the cell
+    pub fn centre(&self) -> DMS3d {
+        let (longitude, latitude) = self.indexes();
+        let (height, width) = self.size();
+        DMS3d::from_ddeg_angles(
+            (latitude as f64 + 0.5) * height - 90.0,
+            (longitude as f64 + 0.5) * width - 180.0,
+            None,
+        )
+    }
+
+    /// Returns the neighbouring cell of same size, in given direction.
+    /// Neighbours wrap across the antimeridian, there is none beyond the poles.
+    pub fn neighbour(&self, direction: Cardinal) -> Option<Self> {
+        let (longitude, latitude) = self.indexes();
+        let (lon_bits, lat_bits) = bits(self.length);
+        let latitude = if direction.is_northern() {
+            latitude.checked_add(1).filter(|l| *l < 1_u64 << lat_bits)?
+        } else if direction.is_southern() {
+            latitude.checked_sub(1)?
+        } else {
+            latitude
+        };
+        let mask = (1_u64 << lon_bits) - 1;
+        let longitude = if direction.is_eastern() {
+            longitude.wrapping_add(1) & mask
+        } else if direction.is_western() {
+            longitude.wrapping_sub(1) & mask
+        } else {
+            longitude
+        };
+        Some(Self::from_indexes(longitude, latitude, self.length))
+    }
+
+    /// Returns the 8 neighbouring cells, clockwise from North
+    pub fn neighbours(&self) -> [Option<Self>; 8] {
+        [
+            Cardinal::North,
+            Cardinal::NorthEast,
+            Cardinal::East,
+            Cardinal::SouthEast,
+            Cardinal::South,
+            Cardinal::SouthWest,
+            Cardinal::West,
+            Cardinal::NorthWest,
+        ]
+        .map(|direction| self.neighbour(direction))
+    }
+
+    /// Returns the cell containing Self, one character shorter,
+    /// None for single character geohashes
+    pub fn parent(&self) -> Option<Self> {
+        if self.length == 1 {
+            return None;
+        }
+        let mut hash = self.hash;
+        hash[self.length - 1] = 0;
+        Some(Self {
+            hash,
+            length: self.length - 1,
+        })
+    }
+
+    /// Returns the 32 cells contained in Self, one character longer,
+    /// None for 12 character geohashes
+    pub fn children(&self) -> Option<[Self; 32]> {
+        if self.length == MAX_LENGTH {
+            return None;
+        }
+        Some(core::array::from_fn(|i| {
+            let mut hash = self.hash;
+            hash[self.length] = ALPHABET[i];
+            Self {
+                hash,
+                length: self.length + 1,
+            }
+        }))
+    }
+}
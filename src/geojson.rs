@@ -0,0 +1,287 @@
+//! GeoJSON geometries and features, made of [DMS3d] coordinates.
+//!
+//! Positions are written `[longitude, latitude]` in decimal degrees,
+//! followed by the altitude in meters when known: `[lon, lat, alt]`.
+//! Geometries, features and collections serialize as GeoJSON with serde,
+//! and are displayed and parsed as GeoJSON strings.
+use crate::{DMS3d, Error};
+use core::convert::TryFrom;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+pub use ::geojson::{JsonObject, JsonValue};
+
+/// Supported GeoJSON geometries
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    /// Single position
+    Point(DMS3d),
+    /// Path going through two or more positions
+    LineString(Vec<DMS3d>),
+    /// Closed linear rings, the exterior ring coming first, followed by holes
+    Polygon(Vec<Vec<DMS3d>>),
+}
+
+/// Geometry with its properties
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Feature {
+    /// Geometry, None for unlocated features
+    pub geometry: Option<Geometry>,
+    /// Properties, empty when none were given
+    pub properties: JsonObject,
+}
+
+/// List of features
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FeatureCollection {
+    /// Features
+    pub features: Vec<Feature>,
+}
+
+/// Converts coordinates to a `[lon, lat, alt]` position
+fn position(coords: &DMS3d) -> ::geojson::Position {
+    let mut position = vec![
+        coords.longitude.to_ddeg_angle(),
+        coords.latitude.to_ddeg_angle(),
+    ];
+    if let Some(altitude) = coords.altitude {
+        position.push(altitude);
+    }
+    position
+}
+
+/// Converts a `[lon, lat]` or `[lon, lat, alt]` position to coordinates,
+/// extra elements being ignored
+fn coords(position: &[f64]) -> Result<DMS3d, Error> {
+    let (longitude, latitude) = match position {
+        [longitude, latitude, ..] => (*longitude, *latitude),
+        _ => return Err(Error::GeoJsonError),
+    };
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(Error::InvalidLatitude);
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(Error::InvalidLongitude);
+    }
+    Ok(DMS3d::from_ddeg_angles(
+        latitude,
+        longitude,
+        position.get(2).copied(),
+    ))
+}
+
+/// Converts a list of positions to coordinates
+fn path(positions: &[::geojson::Position]) -> Result<Vec<DMS3d>, Error> {
+    positions.iter().map(|p| coords(p)).collect()
+}
+
+impl From<DMS3d> for Geometry {
+    fn from(coords: DMS3d) -> Self {
+        Self::Point(coords)
+    }
+}
+
+impl From<Vec<DMS3d>> for Geometry {
+    fn from(coords: Vec<DMS3d>) -> Self {
+        Self::LineString(coords)
+    }
+}
+
+impl From<&Geometry> for ::geojson::Geometry {
+    fn from(geometry: &Geometry) -> Self {
+        let value = match geometry {
+            Geometry::Point(coords) => ::geojson::Value::Point(position(coords)),
+            Geometry::LineString(path) => {
+                ::geojson::Value::LineString(path.iter().map(position).collect())
+            }
+            Geometry::Polygon(rings) => ::geojson::Value::Polygon(
+                rings
+                    .iter()
+                    .map(|ring| ring.iter().map(position).collect())
+                    .collect(),
+            ),
+        };
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&::geojson::Geometry> for Geometry {
+    type Error = Error;
+    /// Converts a Point, LineString or Polygon, other geometries being unsupported
+    fn try_from(geometry: &::geojson::Geometry) -> Result<Self, Error> {
+        match &geometry.value {
+            ::geojson::Value::Point(position) => Ok(Self::Point(coords(position)?)),
+            ::geojson::Value::LineString(positions) => Ok(Self::LineString(path(positions)?)),
+            ::geojson::Value::Polygon(rings) => Ok(Self::Polygon(
+                rings
+                    .iter()
+                    .map(|ring| path(ring))
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => Err(Error::GeoJsonError),
+        }
+    }
+}
+
+impl From<&Feature> for ::geojson::Feature {
+    fn from(feature: &Feature) -> Self {
+        Self {
+            geometry: feature.geometry.as_ref().map(::geojson::Geometry::from),
+            properties: Some(feature.properties.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&::geojson::Feature> for Feature {
+    type Error = Error;
+    fn try_from(feature: &::geojson::Feature) -> Result<Self, Error> {
+        Ok(Self {
+            geometry: match &feature.geometry {
+                Some(geometry) => Some(Geometry::try_from(geometry)?),
+                None => None,
+            },
+            properties: feature.properties.clone().unwrap_or_default(),
+        })
+    }
+}
+
+impl From<&FeatureCollection> for ::geojson::FeatureCollection {
+    fn from(collection: &FeatureCollection) -> Self {
+        Self {
+            bbox: None,
+            features: collection
+                .features
+                .iter()
+                .map(::geojson::Feature::from)
+                .collect(),
+            foreign_members: None,
+        }
+    }
+}
+
+impl TryFrom<&::geojson::FeatureCollection> for FeatureCollection {
+    type Error = Error;
+    fn try_from(collection: &::geojson::FeatureCollection) -> Result<Self, Error> {
+        Ok(Self {
+            features: collection
+                .features
+                .iter()
+                .map(Feature::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&::geojson::GeoJson> for FeatureCollection {
+    type Error = Error;
+    /// Converts any GeoJSON object, a single feature or geometry
+    /// making a collection of one feature
+    fn try_from(geojson: &::geojson::GeoJson) -> Result<Self, Error> {
+        match geojson {
+            ::geojson::GeoJson::FeatureCollection(collection) => Self::try_from(collection),
+            ::geojson::GeoJson::Feature(feature) => Ok(Self {
+                features: vec![Feature::try_from(feature)?],
+            }),
+            ::geojson::GeoJson::Geometry(geometry) => Ok(Self {
+                features: vec![Feature {
+                    geometry: Some(Geometry::try_from(geometry)?),
+                    properties: JsonObject::new(),
+                }],
+            }),
+        }
+    }
+}
+
+impl core::fmt::Display for Geometry {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", ::geojson::Geometry::from(self))
+    }
+}
+
+impl core::fmt::Display for Feature {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", ::geojson::Feature::from(self))
+    }
+}
+
+impl core::fmt::Display for FeatureCollection {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", ::geojson::FeatureCollection::from(self))
+    }
+}
+
+impl core::str::FromStr for Geometry {
+    type Err = Error;
+    /// Parses a GeoJSON geometry, or the geometry of a GeoJSON feature
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Feature::from_str(s)?.geometry.ok_or(Error::GeoJsonError)
+    }
+}
+
+impl core::str::FromStr for Feature {
+    type Err = Error;
+    /// Parses a GeoJSON feature, or a bare geometry with no properties
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s.parse::<::geojson::GeoJson>() {
+            Ok(::geojson::GeoJson::Feature(feature)) => Self::try_from(&feature),
+            Ok(::geojson::GeoJson::Geometry(geometry)) => Ok(Self {
+                geometry: Some(Geometry::try_from(&geometry)?),
+                properties: JsonObject::new(),
+            }),
+            _ => Err(Error::GeoJsonError),
+        }
+    }
+}
+
+impl core::str::FromStr for FeatureCollection {
+    type Err = Error;
+    /// Parses a GeoJSON feature collection, feature or geometry
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let geojson = s
+            .parse::<::geojson::GeoJson>()
+            .map_err(|_| Error::GeoJsonError)?;
+        Self::try_from(&geojson)
+    }
+}
+
+/// Error reported by deserializers
+const DESERIALIZATION_ERROR: &str = "invalid position or unsupported GeoJSON geometry";
+
+impl Serialize for Geometry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ::geojson::Geometry::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Geometry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let geometry = ::geojson::Geometry::deserialize(deserializer)?;
+        Self::try_from(&geometry).map_err(|_| de::Error::custom(DESERIALIZATION_ERROR))
+    }
+}
+
+impl Serialize for Feature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ::geojson::Feature::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Feature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let feature = ::geojson::Feature::deserialize(deserializer)?;
+        Self::try_from(&feature).map_err(|_| de::Error::custom(DESERIALIZATION_ERROR))
+    }
+}
+
+impl Serialize for FeatureCollection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ::geojson::FeatureCollection::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureCollection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let collection = ::geojson::FeatureCollection::deserialize(deserializer)?;
+        Self::try_from(&collection).map_err(|_| de::Error::custom(DESERIALIZATION_ERROR))
+    }
+}
@@ -0,0 +1,172 @@
+//! GPX (GPS Exchange Format) files, as collections of [DMS3d] coordinates.
+//!
+//! Waypoints, routes and tracks are read from and written to GPX 1.1 files,
+//! keeping the name, timestamp and elevation of each point.
+use crate::{DMS3d, Error};
+use std::io::{Read, Write};
+
+pub use ::gpx::Time;
+
+/// Named and timestamped coordinates, altitude being the GPX elevation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    /// Coordinates
+    pub coords: DMS3d,
+    /// Name
+    pub name: Option<String>,
+    /// UTC timestamp
+    pub time: Option<Time>,
+}
+
+/// Ordered list of points leading to a destination
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Route {
+    /// Route name
+    pub name: Option<String>,
+    /// Route points
+    pub points: Vec<Point>,
+}
+
+/// Recorded path, made of continuous segments
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Track {
+    /// Track name
+    pub name: Option<String>,
+    /// Track segments, each one being a list of points
+    pub segments: Vec<Vec<Point>>,
+}
+
+/// Content of a GPX file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Document {
+    /// Standalone waypoints
+    pub waypoints: Vec<Point>,
+    /// Routes
+    pub routes: Vec<Route>,
+    /// Tracks
+    pub tracks: Vec<Track>,
+}
+
+impl From<DMS3d> for Point {
+    fn from(coords: DMS3d) -> Self {
+        Self {
+            coords,
+            name: None,
+            time: None,
+        }
+    }
+}
+
+impl From<&::gpx::Waypoint> for Point {
+    fn from(wpt: &::gpx::Waypoint) -> Self {
+        Self {
+            coords: DMS3d::from(wpt.clone()),
+            name: wpt.name.clone(),
+            time: wpt.time,
+        }
+    }
+}
+
+impl From<&Point> for ::gpx::Waypoint {
+    fn from(point: &Point) -> Self {
+        let mut wpt = ::gpx::Waypoint::new(geo_types::Point::new(
+            point.coords.longitude.to_ddeg_angle(),
+            point.coords.latitude.to_ddeg_angle(),
+        ));
+        wpt.elevation = point.coords.altitude;
+        wpt.name = point.name.clone();
+        wpt.time = point.time;
+        wpt
+    }
+}
+
+impl Route {
+    /// Builds a route going through given coordinates
+    pub fn new(name: Option<String>, coords: &[DMS3d]) -> Self {
+        Self {
+            name,
+            points: coords.iter().map(|c| Point::from(*c)).collect(),
+        }
+    }
+}
+
+impl Track {
+    /// Builds a single segment track, going through given coordinates
+    pub fn new(name: Option<String>, coords: &[DMS3d]) -> Self {
+        Self {
+            name,
+            segments: vec![coords.iter().map(|c| Point::from(*c)).collect()],
+        }
+    }
+}
+
+impl Document {
+    /// Reads a GPX file (version 1.0 or 1.1)
+    pub fn read<R: Read>(reader: R) -> Result<Self, Error> {
+        let gpx = ::gpx::read(reader).map_err(|_| Error::GpxParsingError)?;
+        let points = |wpts: &[::gpx::Waypoint]| wpts.iter().map(Point::from).collect();
+        Ok(Self {
+            waypoints: points(&gpx.waypoints),
+            routes: gpx
+                .routes
+                .iter()
+                .map(|route| Route {
+                    name: route.name.clone(),
+                    points: points(&route.points),
+                })
+                .collect(),
+            tracks: gpx
+                .tracks
+                .iter()
+                .map(|track| Track {
+                    name: track.name.clone(),
+                    segments: track
+                        .segments
+                        .iter()
+                        .map(|segment| points(&segment.points))
+                        .collect(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Writes Self as a GPX 1.1 file
+    pub fn write<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let waypoints = |points: &[Point]| points.iter().map(::gpx::Waypoint::from).collect();
+        let gpx = ::gpx::Gpx {
+            version: ::gpx::GpxVersion::Gpx11,
+            creator: Some(String::from("dms-coordinates")),
+            waypoints: waypoints(&self.waypoints),
+            routes: self
+                .routes
+                .iter()
+                .map(|route| {
+                    let mut rte = ::gpx::Route::new();
+                    rte.name = route.name.clone();
+                    rte.points = waypoints(&route.points);
+                    rte
+                })
+                .collect(),
+            tracks: self
+                .tracks
+                .iter()
+                .map(|track| {
+                    let mut trk = ::gpx::Track::new();
+                    trk.name = track.name.clone();
+                    trk.segments = track
+                        .segments
+                        .iter()
+                        .map(|points| {
+                            let mut segment = ::gpx::TrackSegment::new();
+                            segment.points = waypoints(points);
+                            segment
+                        })
+                        .collect();
+                    trk
+                })
+                .collect(),
+            ..Default::default()
+        };
+        ::gpx::write(&gpx, writer).map_err(|_| Error::GpxError)
+    }
+}
@@ -0,0 +1,276 @@
+//! ISO 6709 representation of geographic point locations.
+//!
+//! A location like `+40.7306-073.9352+10.0CRSWGS_84/` is made of a signed
+//! latitude (2 digit degrees), a signed longitude (3 digit degrees),
+//! an optionnal signed altitude in meters and an optionnal coordinate
+//! reference system identifier, terminated by "/".
+//! Angles are written in one of three [Style]s, a positive sign standing
+//! for North or East, a negative sign for South or West.
+use crate::{dms::ParseError, Cardinal, DMS3d, Error, DDM, DMS};
+use core::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Maximal number of decimals, when a precision is requested
+const MAX_PRECISION: usize = 12;
+
+/// Angle notation
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Style {
+    /// Decimal degrees `±DD.DDDD±DDD.DDDD`
+    Degrees,
+    /// Degrees and decimal minutes `±DDMM.MMM±DDDMM.MMM`
+    DDM,
+    /// Degrees, minutes and decimal seconds `±DDMMSS.SS±DDDMMSS.SS`
+    DMS,
+}
+
+/// Location parsed from an ISO 6709 string
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Location<'a> {
+    /// Coordinates, altitude being expressed in meters
+    pub coords: DMS3d,
+    /// Notation of the angles
+    pub style: Style,
+    /// Coordinate reference system identifier, like "WGS_84"
+    pub crs: Option<&'a str>,
+}
+
+/// Splits the leading signed component of given string
+fn split_component(s: &str) -> Result<(&str, &str), Error> {
+    if !s.starts_with(['+', '-']) {
+        return Err(ParseError::FormatNotRecognized.into());
+    }
+    let end = s[1..].find(['+', '-']).map_or(s.len(), |i| i + 1);
+    Ok(s.split_at(end))
+}
+
+/// Parses a signed angle, in any [Style], with no cardinal attached
+fn parse_angle(s: &str, latitude: bool) -> Result<(DMS, Style, bool), Error> {
+    let negative = s.starts_with('-');
+    let s = &s[1..];
+    let integer = s.find('.').map_or(s, |i| &s[..i]);
+    let fraction = &s[integer.len()..];
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !digits(integer) || !(fraction.is_empty() || digits(&fraction[1..])) {
+        return Err(ParseError::FormatNotRecognized.into());
+    }
+    let width = if latitude { 2 } else { 3 };
+    let degrees = integer
+        .get(..width)
+        .and_then(|d| d.parse::<u16>().ok())
+        .ok_or(ParseError::FormatNotRecognized)?;
+    let (angle, style) = match integer.len() - width {
+        0 => {
+            let degrees = s.parse::<f64>().map_err(|_| ParseError::InvalidDegrees)?;
+            (DMS::from_ddeg_angle(degrees), Style::Degrees)
+        }
+        2 => {
+            let minutes = s[width..]
+                .parse::<f64>()
+                .ok()
+                .filter(|m| *m < 60.0)
+                .ok_or(ParseError::InvalidMinutes)?;
+            let ddm = DDM {
+                degrees,
+                minutes,
+                cardinal: None,
+                negative: false,
+            };
+            (DMS::from(ddm), Style::DDM)
+        }
+        4 => {
+            let minutes = s[width..width + 2]
+                .parse::<u8>()
+                .ok()
+                .filter(|m| *m < 60)
+                .ok_or(ParseError::InvalidMinutes)?;
+            let seconds = s[width + 2..]
+                .parse::<f64>()
+                .ok()
+                .filter(|s| *s < 60.0)
+                .ok_or(ParseError::InvalidSeconds)?;
+            let dms = DMS {
+                degrees,
+                minutes,
+                seconds,
+                cardinal: None,
+                negative: false,
+            };
+            (dms, Style::DMS)
+        }
+        _ => return Err(ParseError::FormatNotRecognized.into()),
+    };
+    Ok((angle, style, negative))
+}
+
+impl<'a> Location<'a> {
+    /// Parses an ISO 6709 string, like `+40.7306-073.9352+10.0CRSWGS_84/`
+    /// or `+404350.196-0735606.871/`. Latitude and longitude must use the same [Style].
+    /// Angles written in DMS style are preserved exactly.
+    pub fn parse(s: &'a str) -> Result<Self, Error> {
+        let s = s
+            .trim()
+            .strip_suffix('/')
+            .ok_or(ParseError::FormatNotRecognized)?;
+        let (s, crs) = match s.find("CRS") {
+            Some(i) if i + 3 < s.len() => (&s[..i], Some(&s[i + 3..])),
+            Some(_) => return Err(ParseError::FormatNotRecognized.into()),
+            None => (s, None),
+        };
+        let (latitude, s) = split_component(s)?;
+        let (longitude, s) = split_component(s)?;
+        let altitude = if s.is_empty() {
+            None
+        } else {
+            let (altitude, s) = split_component(s)?;
+            if !s.is_empty() {
+                return Err(ParseError::FormatNotRecognized.into());
+            }
+            let altitude = altitude
+                .parse::<f64>()
+                .ok()
+                .filter(|a| a.is_finite())
+                .ok_or(Error::InvalidAltitude)?;
+            Some(altitude)
+        };
+        let (latitude, style, south) = parse_angle(latitude, true)?;
+        if latitude.to_ddeg_angle() > 90.0 {
+            return Err(Error::InvalidLatitude);
+        }
+        let (longitude, lon_style, west) = parse_angle(longitude, false)?;
+        if longitude.to_ddeg_angle() > 180.0 {
+            return Err(Error::InvalidLongitude);
+        }
+        if style != lon_style {
+            return Err(ParseError::FormatNotRecognized.into());
+        }
+        let coords = DMS3d {
+            latitude: latitude.with_cardinal(if south {
+                Cardinal::South
+            } else {
+                Cardinal::North
+            }),
+            longitude: longitude.with_cardinal(if west { Cardinal::West } else { Cardinal::East }),
+            altitude,
+        };
+        Ok(Self { coords, style, crs })
+    }
+}
+
+/// ISO 6709 representation of [DMS3d] coordinates, returned by [DMS3d::to_iso6709].
+/// It is generated when this object is displayed, without any allocation.
+#[derive(Debug, Copy, Clone)]
+pub struct DelayedIso6709<'a> {
+    coords: DMS3d,
+    style: Style,
+    precision: Option<usize>,
+    crs: &'a str,
+}
+
+/// Writes a positive value, its integer part being padded to given number of digits.
+/// The shortest representation that parses back to the same value is used.
+fn write_shortest(f: &mut core::fmt::Formatter, value: f64, digits: u32) -> core::fmt::Result {
+    let integer_digits = (value as u64).checked_ilog10().unwrap_or(0) + 1;
+    for _ in integer_digits..digits {
+        f.write_char('0')?;
+    }
+    write!(f, "{}", value)
+}
+
+impl<'a> DelayedIso6709<'a> {
+    /// Builds the representation of given coordinates, in given [Style]
+    pub(crate) fn new(coords: DMS3d, style: Style) -> Self {
+        Self {
+            coords,
+            style,
+            precision: None,
+            crs: "",
+        }
+    }
+    /// Returns same representation with the last field of each angle rounded
+    /// to given number of decimals (up to 12), carrying over to the upper fields.
+    /// By default, the shortest representation preserving each angle is used.
+    pub fn with_precision(&self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision.min(MAX_PRECISION)),
+            ..*self
+        }
+    }
+    /// Returns same representation with given coordinate reference system
+    /// identifier, like "WGS_84", written as a `CRS` suffix
+    pub fn with_crs(&self, crs: &'a str) -> Self {
+        Self { crs, ..*self }
+    }
+    /// Writes given angle, with its sign
+    fn write_angle(
+        &self,
+        f: &mut core::fmt::Formatter,
+        angle: &DMS,
+        width: u32,
+    ) -> core::fmt::Result {
+        let negative = angle.is_negative();
+        f.write_char(if negative { '-' } else { '+' })?;
+        let precision = match self.precision {
+            Some(precision) => precision,
+            None => {
+                return match self.style {
+                    Style::Degrees => write_shortest(f, angle.to_ddeg_angle().abs(), width),
+                    Style::DDM => {
+                        write!(f, "{:0w$}", angle.degrees, w = width as usize)?;
+                        let minutes = angle.minutes as f64 + angle.seconds / 60.0;
+                        write_shortest(f, minutes, 2)
+                    }
+                    Style::DMS => {
+                        write!(
+                            f,
+                            "{:0w$}{:02}",
+                            angle.degrees,
+                            angle.minutes,
+                            w = width as usize
+                        )?;
+                        write_shortest(f, angle.seconds, 2)
+                    }
+                };
+            }
+        };
+        let scale = 10_u64.pow(precision as u32);
+        let (per_degree, seconds_per_unit) = match self.style {
+            Style::Degrees => (1, 3600.0),
+            Style::DDM => (60, 60.0),
+            Style::DMS => (3600, 1.0),
+        };
+        let units = (angle.total_seconds() / seconds_per_unit * scale as f64).round() as u64;
+        let (degrees, units) = (units / (per_degree * scale), units % (per_degree * scale));
+        write!(f, "{:0w$}", degrees, w = width as usize)?;
+        match self.style {
+            Style::Degrees => {}
+            Style::DDM => write!(f, "{:02}", units / scale)?,
+            Style::DMS => write!(f, "{:02}{:02}", units / scale / 60, units / scale % 60)?,
+        }
+        if precision > 0 {
+            write!(f, ".{:0p$}", units % scale, p = precision)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> core::fmt::Display for DelayedIso6709<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.write_angle(f, &self.coords.latitude, 2)?;
+        self.write_angle(f, &self.coords.longitude, 3)?;
+        if let Some(altitude) = self.coords.altitude {
+            if altitude.fract() == 0.0 {
+                write!(f, "{:+.1}", altitude)?;
+            } else {
+                write!(f, "{:+}", altitude)?;
+            }
+        }
+        if !self.crs.is_empty() {
+            write!(f, "CRS{}", self.crs)?;
+        }
+        f.write_char('/')
+    }
+}
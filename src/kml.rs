@@ -0,0 +1,370 @@
+//! KML (Keyhole Markup Language) documents, as used by Google Earth.
+//!
+//! Placemarks holding a `Point` or a `LineString` are read from and written to
+//! KML 2.2 documents, organized in folders. Coordinates are `lon,lat[,alt]` tuples,
+//! in decimal degrees and meters, the altitude being interpreted
+//! according to the [AltitudeMode] of the geometry.
+use crate::{DMS3d, Error};
+use std::io::{Read, Write};
+use xml::reader::{EventReader, XmlEvent};
+use xml::writer::{EmitterConfig, EventWriter, XmlEvent as WriterEvent};
+
+/// KML 2.2 namespace
+const NAMESPACE: &str = "http://www.opengis.net/kml/2.2";
+
+/// Interpretation of the altitude of the coordinates
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum AltitudeMode {
+    /// Altitude is ignored, geometry lies on the ground
+    #[default]
+    ClampToGround,
+    /// Altitude is relative to the ground
+    RelativeToGround,
+    /// Altitude is relative to the sea level
+    Absolute,
+}
+
+impl AltitudeMode {
+    /// Returns KML `altitudeMode` value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClampToGround => "clampToGround",
+            Self::RelativeToGround => "relativeToGround",
+            Self::Absolute => "absolute",
+        }
+    }
+}
+
+impl core::str::FromStr for AltitudeMode {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s.trim() {
+            "clampToGround" => Ok(Self::ClampToGround),
+            "relativeToGround" => Ok(Self::RelativeToGround),
+            "absolute" => Ok(Self::Absolute),
+            _ => Err(Error::KmlError),
+        }
+    }
+}
+
+/// Supported placemark geometries
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    /// Single position
+    Point(DMS3d),
+    /// Path going through two or more positions
+    LineString(Vec<DMS3d>),
+}
+
+/// Named geometry
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placemark {
+    /// Name
+    pub name: Option<String>,
+    /// Description
+    pub description: Option<String>,
+    /// Geometry
+    pub geometry: Geometry,
+    /// Altitude interpretation
+    pub altitude_mode: AltitudeMode,
+}
+
+/// Folder of placemarks and sub folders
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Folder {
+    /// Folder name
+    pub name: Option<String>,
+    /// Placemarks
+    pub placemarks: Vec<Placemark>,
+    /// Sub folders
+    pub folders: Vec<Folder>,
+}
+
+/// KML document
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Document {
+    /// Document name
+    pub name: Option<String>,
+    /// Top level placemarks
+    pub placemarks: Vec<Placemark>,
+    /// Top level folders
+    pub folders: Vec<Folder>,
+}
+
+impl Placemark {
+    /// Builds an unnamed placemark. Altitude is absolute when all coordinates
+    /// have one, geometry is clamped to the ground otherwise.
+    pub fn new(geometry: Geometry) -> Self {
+        let has_altitude = match &geometry {
+            Geometry::Point(coords) => coords.altitude.is_some(),
+            Geometry::LineString(path) => {
+                !path.is_empty() && path.iter().all(|c| c.altitude.is_some())
+            }
+        };
+        Self {
+            name: None,
+            description: None,
+            geometry,
+            altitude_mode: if has_altitude {
+                AltitudeMode::Absolute
+            } else {
+                AltitudeMode::ClampToGround
+            },
+        }
+    }
+    /// Returns same placemark with given name
+    pub fn with_name(&self, name: &str) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            ..self.clone()
+        }
+    }
+}
+
+impl From<DMS3d> for Placemark {
+    fn from(coords: DMS3d) -> Self {
+        Self::new(Geometry::Point(coords))
+    }
+}
+
+impl From<Vec<DMS3d>> for Placemark {
+    fn from(path: Vec<DMS3d>) -> Self {
+        Self::new(Geometry::LineString(path))
+    }
+}
+
+/// Parses whitespace separated `lon,lat[,alt]` tuples
+fn parse_coordinates(s: &str) -> Result<Vec<DMS3d>, Error> {
+    s.split_whitespace()
+        .map(|tuple| {
+            let mut values = tuple.split(',').map(|v| v.parse::<f64>());
+            let (longitude, latitude, altitude) =
+                match (values.next(), values.next(), values.next(), values.next()) {
+                    (Some(Ok(lon)), Some(Ok(lat)), None, None) => (lon, lat, None),
+                    (Some(Ok(lon)), Some(Ok(lat)), Some(Ok(alt)), None) => (lon, lat, Some(alt)),
+                    _ => return Err(Error::KmlError),
+                };
+            if !(-90.0..=90.0).contains(&latitude) {
+                return Err(Error::InvalidLatitude);
+            }
+            if !(-180.0..=180.0).contains(&longitude) {
+                return Err(Error::InvalidLongitude);
+            }
+            Ok(DMS3d::from_ddeg_angles(latitude, longitude, altitude))
+        })
+        .collect()
+}
+
+/// Placemark being read
+#[derive(Default)]
+struct PartialPlacemark {
+    name: Option<String>,
+    description: Option<String>,
+    geometry: Option<Geometry>,
+    altitude_mode: AltitudeMode,
+}
+
+impl Document {
+    /// Reads a KML document. Placemarks with no `Point` nor `LineString`
+    /// geometry are skipped, as well as elements not described here.
+    pub fn read<R: Read>(reader: R) -> Result<Self, Error> {
+        // containers being read: the document, then nested folders
+        let mut folders: Vec<Folder> = vec![Folder::default()];
+        let mut placemark: Option<PartialPlacemark> = None;
+        let mut elements: Vec<String> = Vec::new();
+        let mut text = String::new();
+        for event in EventReader::new(reader) {
+            match event.map_err(|_| Error::KmlError)? {
+                XmlEvent::StartElement { name, .. } => {
+                    // gx:altitudeMode and other extensions are ignored
+                    let local_name = match name.namespace.as_deref() {
+                        Some(ns) if ns != NAMESPACE => String::new(),
+                        _ => name.local_name,
+                    };
+                    match local_name.as_str() {
+                        "Folder" => folders.push(Folder::default()),
+                        "Placemark" => placemark = Some(PartialPlacemark::default()),
+                        _ => {}
+                    }
+                    elements.push(local_name);
+                    text.clear();
+                }
+                XmlEvent::Characters(s) | XmlEvent::CData(s) => text.push_str(&s),
+                XmlEvent::EndElement { .. } => {
+                    let element = elements.pop().unwrap_or_default();
+                    let parent = elements.last().map(|p| p.as_str()).unwrap_or("");
+                    let value = text.trim();
+                    match (element.as_str(), parent, placemark.as_mut()) {
+                        ("name", "Placemark", Some(placemark)) => {
+                            placemark.name = Some(value.to_string())
+                        }
+                        ("description", "Placemark", Some(placemark)) => {
+                            placemark.description = Some(value.to_string())
+                        }
+                        ("altitudeMode", "Point" | "LineString", Some(placemark)) => {
+                            placemark.altitude_mode = value.parse()?
+                        }
+                        ("coordinates", "Point", Some(placemark)) => {
+                            let coords = parse_coordinates(value)?;
+                            if coords.len() != 1 {
+                                return Err(Error::KmlError);
+                            }
+                            placemark.geometry = Some(Geometry::Point(coords[0]));
+                        }
+                        ("coordinates", "LineString", Some(placemark)) => {
+                            placemark.geometry =
+                                Some(Geometry::LineString(parse_coordinates(value)?));
+                        }
+                        ("name", "Folder" | "Document", None) => {
+                            if let Some(folder) = folders.last_mut() {
+                                folder.name = Some(value.to_string());
+                            }
+                        }
+                        ("Placemark", _, _) => {
+                            let p = placemark.take().unwrap_or_default();
+                            if let (Some(geometry), Some(folder)) = (p.geometry, folders.last_mut())
+                            {
+                                folder.placemarks.push(Placemark {
+                                    name: p.name,
+                                    description: p.description,
+                                    geometry,
+                                    altitude_mode: p.altitude_mode,
+                                });
+                            }
+                        }
+                        ("Folder", _, _) if folders.len() > 1 => {
+                            let folder = folders.pop().unwrap_or_default();
+                            if let Some(parent) = folders.last_mut() {
+                                parent.folders.push(folder);
+                            }
+                        }
+                        _ => {}
+                    }
+                    text.clear();
+                }
+                _ => {}
+            }
+        }
+        if folders.len() != 1 {
+            return Err(Error::KmlError);
+        }
+        let root = folders.pop().unwrap_or_default();
+        Ok(Self {
+            name: root.name,
+            placemarks: root.placemarks,
+            folders: root.folders,
+        })
+    }
+
+    /// Returns all placemarks, top level placemarks first,
+    /// followed by the content of each folder
+    pub fn all_placemarks(&self) -> Vec<&Placemark> {
+        fn collect<'a>(folder: &'a Folder, placemarks: &mut Vec<&'a Placemark>) {
+            placemarks.extend(folder.placemarks.iter());
+            for folder in &folder.folders {
+                collect(folder, placemarks);
+            }
+        }
+        let mut placemarks: Vec<&Placemark> = self.placemarks.iter().collect();
+        for folder in &self.folders {
+            collect(folder, &mut placemarks);
+        }
+        placemarks
+    }
+
+    /// Writes Self as a KML 2.2 document
+    pub fn write<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let mut writer = EmitterConfig::new()
+            .perform_indent(true)
+            .create_writer(writer);
+        write_start(
+            &mut writer,
+            WriterEvent::start_element("kml").default_ns(NAMESPACE),
+        )?;
+        write_start(&mut writer, WriterEvent::start_element("Document"))?;
+        write_content(&mut writer, &self.name, &self.placemarks, &self.folders)?;
+        write_end(&mut writer)?;
+        write_end(&mut writer)
+    }
+}
+
+/// Writes a start element
+fn write_start<W: Write>(
+    writer: &mut EventWriter<W>,
+    event: xml::writer::events::StartElementBuilder,
+) -> Result<(), Error> {
+    writer.write(event).map_err(|_| Error::KmlError)
+}
+
+/// Closes the current element
+fn write_end<W: Write>(writer: &mut EventWriter<W>) -> Result<(), Error> {
+    writer
+        .write(WriterEvent::end_element())
+        .map_err(|_| Error::KmlError)
+}
+
+/// Writes an element holding given text
+fn write_text<W: Write>(
+    writer: &mut EventWriter<W>,
+    element: &str,
+    text: &str,
+) -> Result<(), Error> {
+    write_start(writer, WriterEvent::start_element(element))?;
+    writer
+        .write(WriterEvent::characters(text))
+        .map_err(|_| Error::KmlError)?;
+    write_end(writer)
+}
+
+/// Formats coordinates as `lon,lat[,alt]`
+fn coordinates(coords: &DMS3d) -> String {
+    let (latitude, longitude) = (
+        coords.latitude.to_ddeg_angle(),
+        coords.longitude.to_ddeg_angle(),
+    );
+    match coords.altitude {
+        Some(altitude) => format!("{},{},{}", longitude, latitude, altitude),
+        None => format!("{},{}", longitude, latitude),
+    }
+}
+
+/// Writes the name, placemarks and folders of a container
+fn write_content<W: Write>(
+    writer: &mut EventWriter<W>,
+    name: &Option<String>,
+    placemarks: &[Placemark],
+    folders: &[Folder],
+) -> Result<(), Error> {
+    if let Some(name) = name {
+        write_text(writer, "name", name)?;
+    }
+    for placemark in placemarks {
+        write_start(writer, WriterEvent::start_element("Placemark"))?;
+        if let Some(name) = &placemark.name {
+            write_text(writer, "name", name)?;
+        }
+        if let Some(description) = &placemark.description {
+            write_text(writer, "description", description)?;
+        }
+        let (element, tuples) = match &placemark.geometry {
+            Geometry::Point(coords) => ("Point", coordinates(coords)),
+            Geometry::LineString(path) => (
+                "LineString",
+                path.iter().map(coordinates).collect::<Vec<_>>().join(" "),
+            ),
+        };
+        write_start(writer, WriterEvent::start_element(element))?;
+        if placemark.altitude_mode != AltitudeMode::ClampToGround {
+            write_text(writer, "altitudeMode", placemark.altitude_mode.as_str())?;
+        }
+        write_text(writer, "coordinates", &tuples)?;
+        write_end(writer)?;
+        write_end(writer)?;
+    }
+    for folder in folders {
+        write_start(writer, WriterEvent::start_element("Folder"))?;
+        write_content(writer, &folder.name, &folder.placemarks, &folder.folders)?;
+        write_end(writer)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,358 @@
+//! Validated latitude and longitude angles.
+//!
+//! [Latitude] and [Longitude] wrap a [DMS] angle that always carries
+//! a matching cardinal and lies within range, so they can only
+//! represent valid coordinates. [Position] holds both of them.
+use crate::dms::wrap_longitude;
+use crate::{Cardinal, DMS3d, Error, DMS};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde_derive::Serialize;
+
+/// Latitude angle, within `[-90°, 90°]`, North or South
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Latitude(DMS);
+
+/// Longitude angle, within `[-180°, 180°]`, East or West
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Longitude(DMS);
+
+/// 3D coordinates made of validated angles: unlike [DMS3d],
+/// which keeps public [DMS] fields for compatibility, a Position
+/// cannot hold an invalid latitude or longitude
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Position {
+    /// Latitude angle
+    pub latitude: Latitude,
+    /// Longitude angle
+    pub longitude: Longitude,
+    /// Optionnal altitude / depth
+    pub altitude: Option<f64>,
+}
+
+/// Returns true if minutes and seconds are within range
+fn valid_fields(dms: &DMS) -> bool {
+    dms.minutes < 60 && (0.0..60.0).contains(&dms.seconds)
+}
+
+impl Latitude {
+    /// Northern or southern cardinal
+    pub fn cardinal(&self) -> Cardinal {
+        self.0.cardinal.unwrap_or(Cardinal::North)
+    }
+
+    /// Returns Self expressed in decimal degrees, negative in the South
+    pub fn to_ddeg_angle(&self) -> f64 {
+        self.0.to_ddeg_angle()
+    }
+
+    /// Returns Self as a `D°M'S"` angle
+    pub fn to_dms(&self) -> DMS {
+        self.0
+    }
+}
+
+impl Longitude {
+    /// Eastern or western cardinal
+    pub fn cardinal(&self) -> Cardinal {
+        self.0.cardinal.unwrap_or(Cardinal::East)
+    }
+
+    /// Returns Self expressed in decimal degrees, negative in the West
+    pub fn to_ddeg_angle(&self) -> f64 {
+        self.0.to_ddeg_angle()
+    }
+
+    /// Returns Self as a `D°M'S"` angle
+    pub fn to_dms(&self) -> DMS {
+        self.0
+    }
+}
+
+impl Default for Latitude {
+    /// Equator
+    fn default() -> Self {
+        Self(DMS::from_ddeg_latitude(0.0))
+    }
+}
+
+impl Default for Longitude {
+    /// Prime meridian
+    fn default() -> Self {
+        Self(DMS::from_ddeg_longitude(0.0))
+    }
+}
+
+impl core::fmt::Display for Latitude {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl core::fmt::Display for Longitude {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<f64> for Latitude {
+    type Error = Error;
+    /// Builds a latitude from an angle in decimal degrees, negative in the South
+    fn try_from(angle: f64) -> Result<Self, Error> {
+        if !(-90.0..=90.0).contains(&angle) {
+            return Err(Error::InvalidLatitude);
+        }
+        Ok(Self(DMS::from_ddeg_latitude(angle)))
+    }
+}
+
+impl TryFrom<f64> for Longitude {
+    type Error = Error;
+    /// Builds a longitude from an angle in decimal degrees, negative in the West
+    fn try_from(angle: f64) -> Result<Self, Error> {
+        if !(-180.0..=180.0).contains(&angle) {
+            return Err(Error::InvalidLongitude);
+        }
+        Ok(Self(DMS::from_ddeg_longitude(angle)))
+    }
+}
+
+impl TryFrom<DMS> for Latitude {
+    type Error = Error;
+    /// Angle must carry a North or South cardinal
+    fn try_from(dms: DMS) -> Result<Self, Error> {
+        match dms.cardinal {
+            None => Err(Error::MissingLatitude),
+            Some(c) if !c.is_latitude() => Err(Error::InvalidLatitude),
+            _ if !valid_fields(&dms) || dms.total_seconds() > 90.0 * 3600.0 => {
+                Err(Error::InvalidLatitude)
+            }
+            _ => Ok(Self(dms)),
+        }
+    }
+}
+
+impl TryFrom<DMS> for Longitude {
+    type Error = Error;
+    /// Angle must carry an East or West cardinal
+    fn try_from(dms: DMS) -> Result<Self, Error> {
+        match dms.cardinal {
+            None => Err(Error::MissingLongitude),
+            Some(c) if !c.is_longitude() => Err(Error::InvalidLongitude),
+            _ if !valid_fields(&dms) || dms.total_seconds() > 180.0 * 3600.0 => {
+                Err(Error::InvalidLongitude)
+            }
+            _ => Ok(Self(dms)),
+        }
+    }
+}
+
+impl From<Latitude> for DMS {
+    fn from(latitude: Latitude) -> Self {
+        latitude.0
+    }
+}
+
+impl From<Longitude> for DMS {
+    fn from(longitude: Longitude) -> Self {
+        longitude.0
+    }
+}
+
+impl From<Latitude> for f64 {
+    fn from(latitude: Latitude) -> Self {
+        latitude.to_ddeg_angle()
+    }
+}
+
+impl From<Longitude> for f64 {
+    fn from(longitude: Longitude) -> Self {
+        longitude.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Add<f64> for Latitude {
+    type Output = Result<Self, Error>;
+    /// Moves Self northward by given angle in decimal degrees.
+    /// Going past a pole folds back towards the equator, no longitude
+    /// being moved: refer to [DMS3d](crate::DMS3d) arithmetics for positions.
+    /// Non finite angles are rejected
+    fn add(self, rhs: f64) -> Result<Self, Error> {
+        let angle = self.to_ddeg_angle() + rhs;
+        if !angle.is_finite() {
+            return Err(Error::InvalidLatitude);
+        }
+        Ok(Self(DMS::from_ddeg_latitude(angle)))
+    }
+}
+
+impl core::ops::Sub<f64> for Latitude {
+    type Output = Result<Self, Error>;
+    /// Moves Self southward by given angle in decimal degrees
+    fn sub(self, rhs: f64) -> Result<Self, Error> {
+        self + (-rhs)
+    }
+}
+
+impl core::ops::AddAssign<f64> for Latitude {
+    /// Self is left untouched when given angle is not finite
+    fn add_assign(&mut self, rhs: f64) {
+        if let Ok(latitude) = *self + rhs {
+            *self = latitude
+        }
+    }
+}
+
+impl core::ops::SubAssign<f64> for Latitude {
+    /// Self is left untouched when given angle is not finite
+    fn sub_assign(&mut self, rhs: f64) {
+        if let Ok(latitude) = *self - rhs {
+            *self = latitude
+        }
+    }
+}
+
+impl core::ops::Add<DMS> for Latitude {
+    type Output = Result<Self, Error>;
+    /// Moves Self by given angle, southward when it has a southern cardinal
+    fn add(self, rhs: DMS) -> Result<Self, Error> {
+        self + rhs.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Sub<DMS> for Latitude {
+    type Output = Result<Self, Error>;
+    fn sub(self, rhs: DMS) -> Result<Self, Error> {
+        self - rhs.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Sub<Latitude> for Latitude {
+    type Output = f64;
+    /// Returns the northward angle from `rhs` to Self, in decimal degrees
+    fn sub(self, rhs: Self) -> f64 {
+        self.to_ddeg_angle() - rhs.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Add<f64> for Longitude {
+    type Output = Result<Self, Error>;
+    /// Moves Self eastward by given angle in decimal degrees,
+    /// wrapping around the antimeridian. Non finite angles are rejected
+    fn add(self, rhs: f64) -> Result<Self, Error> {
+        let angle = self.to_ddeg_angle() + rhs;
+        if !angle.is_finite() {
+            return Err(Error::InvalidLongitude);
+        }
+        Ok(Self(DMS::from_ddeg_longitude(angle)))
+    }
+}
+
+impl core::ops::Sub<f64> for Longitude {
+    type Output = Result<Self, Error>;
+    /// Moves Self westward by given angle in decimal degrees
+    fn sub(self, rhs: f64) -> Result<Self, Error> {
+        self + (-rhs)
+    }
+}
+
+impl core::ops::AddAssign<f64> for Longitude {
+    /// Self is left untouched when given angle is not finite
+    fn add_assign(&mut self, rhs: f64) {
+        if let Ok(longitude) = *self + rhs {
+            *self = longitude
+        }
+    }
+}
+
+impl core::ops::SubAssign<f64> for Longitude {
+    /// Self is left untouched when given angle is not finite
+    fn sub_assign(&mut self, rhs: f64) {
+        if let Ok(longitude) = *self - rhs {
+            *self = longitude
+        }
+    }
+}
+
+impl core::ops::Add<DMS> for Longitude {
+    type Output = Result<Self, Error>;
+    /// Moves Self by given angle, westward when it has a western cardinal
+    fn add(self, rhs: DMS) -> Result<Self, Error> {
+        self + rhs.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Sub<DMS> for Longitude {
+    type Output = Result<Self, Error>;
+    fn sub(self, rhs: DMS) -> Result<Self, Error> {
+        self - rhs.to_ddeg_angle()
+    }
+}
+
+impl core::ops::Sub<Longitude> for Longitude {
+    type Output = f64;
+    /// Returns the shortest eastward angle from `rhs` to Self, in decimal degrees,
+    /// within `]-180°, 180°]`, crossing the antimeridian when shorter
+    fn sub(self, rhs: Self) -> f64 {
+        let angle = wrap_longitude(self.to_ddeg_angle() - rhs.to_ddeg_angle());
+        if angle == -180.0 {
+            180.0
+        } else {
+            angle
+        }
+    }
+}
+
+impl Position {
+    /// Builds 3D coordinates from validated angles
+    pub fn new(latitude: Latitude, longitude: Longitude, altitude: Option<f64>) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+}
+
+impl core::fmt::Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", DMS3d::from(*self))
+    }
+}
+
+impl From<Position> for DMS3d {
+    fn from(position: Position) -> Self {
+        DMS3d::from_lat_lon(position.latitude, position.longitude, position.altitude)
+    }
+}
+
+impl TryFrom<DMS3d> for Position {
+    type Error = Error;
+    /// Fails if either angle is not a valid latitude or longitude
+    fn try_from(coords: DMS3d) -> Result<Self, Error> {
+        let (latitude, longitude) = coords.lat_lon()?;
+        Ok(Self::new(latitude, longitude, coords.altitude))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Latitude {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let dms = DMS::deserialize(deserializer)?;
+        Self::try_from(dms).map_err(|_| de::Error::custom("invalid latitude"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Longitude {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let dms = DMS::deserialize(deserializer)?;
+        Self::try_from(dms).map_err(|_| de::Error::custom("invalid longitude"))
+    }
+}
@@ -3,8 +3,30 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod cardinal;
+pub mod datum;
 pub mod dms;
 pub mod dms3d;
+pub mod ellipsoid;
+pub mod format;
+pub mod geo_types;
+pub mod geodesic;
+pub mod geohash;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+#[cfg(feature = "gpx")]
+pub mod gpx;
+pub mod iso6709;
+#[cfg(feature = "kml")]
+pub mod kml;
+pub mod latlon;
+pub mod maidenhead;
+pub mod mgrs;
+pub mod nmea;
+pub mod plus_code;
+pub mod rhumb;
+pub mod utm;
+#[cfg(feature = "wkt")]
+pub mod wkt;
 
 #[derive(Debug)]
 pub enum Error {
@@ -12,16 +34,69 @@ pub enum Error {
     MissingLatitude,
     InvalidLongitude,
     MissingLongitude,
+    /// Angle with no cardinal is not within `[0°, 360°]`
+    InvalidAngle,
     /// When adding two cardinals toghether, they
     /// must be compatible.
     IncompatibleCardinals,
+    /// Altitude could not be parsed
+    InvalidAltitude,
+    /// Angle could not be parsed
+    ParsingError(dms::ParseError),
+    /// Iterative geodesic method did not converge,
+    /// like Vincenty's method for nearly antipodal points
+    NoConvergence,
+    /// UTM zone number is not within 1..=60
+    InvalidZone,
+    /// Latitude band letter is not valid
+    InvalidBand,
+    /// MGRS 100 km square letters are not valid
+    /// for the zone or the polar band
+    InvalidSquare,
+    /// Maidenhead locator is not valid
+    InvalidLocator,
+    /// Geohash is not valid
+    InvalidGeohash,
+    /// Plus Code is not valid, or is a short code where a full code is expected
+    InvalidPlusCode,
+    /// NMEA sentence could not be parsed
+    NmeaError(nmea::NmeaError),
+    /// GeoJSON could not be parsed, or holds an unsupported geometry
+    #[cfg(feature = "geojson")]
+    GeoJsonError,
+    /// WKT or WKB geometry could not be decoded
+    #[cfg(feature = "wkt")]
+    WktError(wkt::WktError),
+    /// KML document could not be read or written
+    #[cfg(feature = "kml")]
+    KmlError,
+    /// GPX file could not be read
     #[cfg(feature = "gpx")]
     GpxParsingError,
+    /// GPX file could not be written
     #[cfg(feature = "gpx")]
     GpxError,
 }
 
-pub use crate::{cardinal::Cardinal, dms::DMS, dms3d::DMS3d};
+pub use crate::{
+    cardinal::Cardinal,
+    datum::Datum,
+    dms::{Normalization, DDM, DMS},
+    dms3d::DMS3d,
+    ellipsoid::Ellipsoid,
+    geohash::Geohash,
+    latlon::{Latitude, Longitude, Position},
+    maidenhead::Maidenhead,
+    mgrs::MGRS,
+    plus_code::PlusCode,
+    utm::UTM,
+};
+
+impl From<dms::ParseError> for Error {
+    fn from(e: dms::ParseError) -> Self {
+        Self::ParsingError(e)
+    }
+}
 
 /// Mean radius of the Earth: 6.37 * 10^(6) m
 const EARTH_RADIUS: f64 = 6.37e6_f64;
@@ -29,16 +104,12 @@ const EARTH_RADIUS: f64 = 6.37e6_f64;
 /// Returns distance (m) between two decimal degrees coordinates
 /// coord1: (lat,lon), coord2: (lat, lon)
 pub fn projected_distance(coord1: (f64, f64), coord2: (f64, f64)) -> f64 {
-    let dphi = map_3d::deg2rad(coord2.0) - map_3d::deg2rad(coord1.0);
-    let d_lambda = map_3d::deg2rad(coord2.1) - map_3d::deg2rad(coord1.1);
+    let dphi = coord2.0.to_radians() - coord1.0.to_radians();
+    let d_lambda = coord2.1.to_radians() - coord1.1.to_radians();
     let a: f64 = (dphi / 2.0_f64).sin().powf(2.0_f64)
-        + map_3d::deg2rad(coord1.0).cos()
-            * map_3d::deg2rad(coord2.0).cos()
+        + coord1.0.to_radians().cos()
+            * coord2.0.to_radians().cos()
             * (d_lambda / 2.0_f64).sin().powf(2.0_f64);
     let c = 2.0_f64 * a.powf(0.5_f64).atan2((1.0 - a).powf(0.5_f64));
     EARTH_RADIUS * c
 }
-
-#[cfg(test)]
-#[macro_use]
-extern crate assert_float_eq;
@@ -0,0 +1,155 @@
+//! Maidenhead locator system (QTH locators), used by radio amateurs.
+//!
+//! A locator like `FN30as` is made of pairs of characters, longitude first:
+//! a field ('A' to 'R', 20° x 10°), a square ('0' to '9', 2° x 1°),
+//! a subsquare ('a' to 'x', 5' x 2.5'), an extended square ('0' to '9',
+//! 30" x 15") and an extended subsquare ('a' to 'x', 1.25" x 0.625").
+use crate::{DMS3d, Error};
+
+/// Number of divisions at each level, from the field to the extended subsquare
+const DIVISIONS: [u8; 5] = [18, 10, 24, 10, 24];
+/// Maximal number of characters
+const MAX_LENGTH: usize = 2 * DIVISIONS.len();
+
+/// Returns the character standing for the first division, at given level
+fn first_character(level: usize) -> u8 {
+    match level {
+        0 => b'A',
+        _ if level.is_multiple_of(2) => b'a',
+        _ => b'0',
+    }
+}
+
+/// Maidenhead locator, from 2 to 10 characters
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Maidenhead {
+    /// Locator characters
+    locator: [u8; MAX_LENGTH],
+    /// Number of characters
+    length: usize,
+}
+
+impl core::fmt::Display for Maidenhead {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl core::str::FromStr for Maidenhead {
+    type Err = Error;
+    /// Parses a locator like "FN", "FN30", "FN30as", "FN30as51" or "FN30as51kj",
+    /// case insensitive
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let s = s.trim().as_bytes();
+        if s.is_empty() || s.len() > MAX_LENGTH || !s.len().is_multiple_of(2) {
+            return Err(Error::InvalidLocator);
+        }
+        let mut locator = [0_u8; MAX_LENGTH];
+        for (i, c) in s.iter().enumerate() {
+            let level = i / 2;
+            let c = if level.is_multiple_of(2) {
+                let index = c.to_ascii_uppercase().wrapping_sub(b'A');
+                if index >= DIVISIONS[level] {
+                    return Err(Error::InvalidLocator);
+                }
+                first_character(level) + index
+            } else if c.is_ascii_digit() {
+                *c
+            } else {
+                return Err(Error::InvalidLocator);
+            };
+            locator[i] = c;
+        }
+        Ok(Self {
+            locator,
+            length: s.len(),
+        })
+    }
+}
+
+impl Maidenhead {
+    /// Returns the locator of the square containing (latitude, longitude),
+    /// in decimal degrees, with given number of characters: 2, 4, 6, 8 or 10
+    pub fn from_ddeg_angles(latitude: f64, longitude: f64, length: usize) -> Result<Self, Error> {
+        if length == 0 || length > MAX_LENGTH || !length.is_multiple_of(2) {
+            return Err(Error::InvalidLocator);
+        }
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(Error::InvalidLatitude);
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(Error::InvalidLongitude);
+        }
+        let mut locator = [0_u8; MAX_LENGTH];
+        // position within the current square, 0 <= x < 1
+        let mut position = [(longitude + 180.0) / 360.0, (latitude + 90.0) / 180.0];
+        for (level, &divisions) in DIVISIONS.iter().enumerate().take(length / 2) {
+            for (i, value) in position.iter_mut().enumerate() {
+                *value *= divisions as f64;
+                // the north pole and the antimeridian belong to the last square
+                let index = (value.floor() as u8).min(divisions - 1);
+                *value -= index as f64;
+                locator[2 * level + i] = first_character(level) + index;
+            }
+        }
+        Ok(Self { locator, length })
+    }
+
+    /// Returns locator characters
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.locator[..self.length]).unwrap_or_default()
+    }
+
+    /// Returns the number of characters
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if Self has no characters, which is never the case
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the south western corner, and the size of the square,
+    /// as (latitude, longitude) in decimal degrees
+    fn square(&self) -> ((f64, f64), (f64, f64)) {
+        let (mut width, mut height) = (360.0, 180.0);
+        let (mut longitude, mut latitude) = (-180.0, -90.0);
+        for (level, &divisions) in DIVISIONS.iter().enumerate().take(self.length / 2) {
+            let divisions = divisions as f64;
+            let first = first_character(level);
+            width /= divisions;
+            height /= divisions;
+            longitude += (self.locator[2 * level] - first) as f64 * width;
+            latitude += (self.locator[2 * level + 1] - first) as f64 * height;
+        }
+        ((latitude, longitude), (height, width))
+    }
+
+    /// Returns the south western and north eastern corners of the square
+    pub fn bounds(&self) -> (DMS3d, DMS3d) {
+        let ((latitude, longitude), (height, width)) = self.square();
+        (
+            DMS3d::from_ddeg_angles(latitude, longitude, None),
+            DMS3d::from_ddeg_angles(latitude + height, longitude + width, None),
+        )
+    }
+
+    /// Returns the centre of the square
+    pub fn centre(&self) -> DMS3d {
+        let ((latitude, longitude), (height, width)) = self.square();
+        DMS3d::from_ddeg_angles(latitude + height / 2.0, longitude + width / 2.0, None)
+    }
+
+    /// Returns the geodesic distance in meters, on the WGS84 ellipsoid,
+    /// between the centres of Self and `rhs` squares
+    pub fn distance(&self, rhs: &Self) -> f64 {
+        self.centre().distance(rhs.centre())
+    }
+
+    /// Returns the geodesic azimuth ɑ, where 0 <= ɑ < 360,
+    /// from the centre of Self square to the centre of `rhs` square
+    pub fn azimuth(&self, rhs: &Self) -> f64 {
+        self.centre().azimuth(rhs.centre())
+    }
+}
@@ -0,0 +1,353 @@
+//! Military Grid Reference System (MGRS), and its civilian equivalent
+//! the US National Grid (USNG).
+//!
+//! A grid reference identifies a square of the UTM grid, like `18TWL8395107251`:
+//! UTM zone and latitude band (`18T`), 100 km square column and row letters
+//! (`WL`), then as many easting as northing digits, from none (100 km square)
+//! to 5 digits each (1 m square). The polar areas, beyond 84°N and 80°S,
+//! use the UPS grid, with bands 'A' and 'B' in the south, 'Y' and 'Z'
+//! in the north, and no zone number.
+//! Grid references are always expressed on the WGS84 ellipsoid.
+use crate::{
+    dms::{ParseError, Scale},
+    utm::{BANDS, UPS},
+    Ellipsoid, Error, UTM,
+};
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Width of a grid square with no digits, in meters
+const SQUARE: f64 = 100_000.0;
+/// Maximal number of digits per axis
+const MAX_PRECISION: u8 = 5;
+/// UTM column letters, for each set of 3 consecutive zones
+const UTM_COLUMNS: [&[u8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+/// UTM row letters, shifted by 5 rows in even zones
+const UTM_ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+/// UPS column letters, and index of the first column, for each polar band
+const UPS_COLUMNS: [(char, &[u8], u8); 4] = [
+    ('A', b"JKLPQRSTUXYZ", 8),
+    ('B', b"ABCFGHJKLPQR", 20),
+    ('Y', b"RSTUXYZ", 13),
+    ('Z', b"ABCFGHJ", 20),
+];
+/// UPS row letters, and index of the first row, south then north
+const UPS_ROWS: [(&[u8], u8); 2] = [(b"ABCDEFGHJKLMNPQRSTUVWXYZ", 8), (b"ABCDEFGHJKLMNP", 13)];
+
+/// MGRS / USNG grid reference
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MGRS {
+    /// UTM zone number, None in the polar (UPS) areas
+    pub zone: Option<u8>,
+    /// Latitude band letter, 'C' to 'X' in UTM areas,
+    /// 'A', 'B', 'Y' or 'Z' in UPS areas
+    pub band: char,
+    /// 100 km square column and row letters
+    pub square: (char, char),
+    /// Easting within the 100 km square, in meters
+    pub easting: u32,
+    /// Northing within the 100 km square, in meters
+    pub northing: u32,
+    /// Number of digits per axis, from 0 (100 km) to 5 (1 m)
+    pub precision: u8,
+}
+
+impl core::fmt::Display for MGRS {
+    /// Displays grid reference like "18TWL8395107251",
+    /// or like "18T WL 83951 07251" (USNG style) with the alternate flag
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let separator = if f.alternate() { " " } else { "" };
+        if let Some(zone) = self.zone {
+            write!(f, "{}", zone)?;
+        }
+        write!(
+            f,
+            "{}{}{}{}",
+            self.band, separator, self.square.0, self.square.1
+        )?;
+        if self.precision > 0 {
+            let divider = 10_u32.pow((MAX_PRECISION - self.precision) as u32);
+            let width = self.precision as usize;
+            write!(
+                f,
+                "{}{:0width$}{}{:0width$}",
+                separator,
+                self.easting / divider,
+                separator,
+                self.northing / divider,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for MGRS {
+    type Err = Error;
+    /// Parses grid reference like "18TWL8395107251", "18T WL 83951 07251",
+    /// "4QFJ12345678" or "ZAH0000000000", case insensitive
+    fn from_str(s: &str) -> Result<Self, Error> {
+        // longest reference is "18TWL8395107251"
+        let mut buffer = [0_u8; 15];
+        let mut len = 0;
+        for c in s.chars().filter(|c| !c.is_whitespace()) {
+            if !c.is_ascii() || len == buffer.len() {
+                return Err(Error::ParsingError(ParseError::FormatNotRecognized));
+            }
+            buffer[len] = c.to_ascii_uppercase() as u8;
+            len += 1;
+        }
+        let s = &buffer[..len];
+        if s.is_empty() {
+            return Err(Error::ParsingError(ParseError::EmptyString));
+        }
+        let zone_digits = s.iter().take_while(|b| b.is_ascii_digit()).count();
+        let zone = match zone_digits {
+            0 => None,
+            1 | 2 => Some(
+                s[..zone_digits]
+                    .iter()
+                    .fold(0_u8, |zone, b| zone * 10 + (b - b'0')),
+            ),
+            _ => return Err(Error::InvalidZone),
+        };
+        let letters = &s[zone_digits..];
+        if letters.len() < 3 || !letters[..3].iter().all(|b| b.is_ascii_alphabetic()) {
+            return Err(Error::ParsingError(ParseError::FormatNotRecognized));
+        }
+        let digits = &letters[3..];
+        if digits.len() % 2 != 0
+            || digits.len() > 2 * MAX_PRECISION as usize
+            || !digits.iter().all(|b| b.is_ascii_digit())
+        {
+            return Err(Error::ParsingError(ParseError::FormatNotRecognized));
+        }
+        let precision = (digits.len() / 2) as u8;
+        let (easting, northing) = digits.split_at(precision as usize);
+        let multiplier = 10_u32.pow((MAX_PRECISION - precision) as u32);
+        let value = |digits: &[u8]| {
+            digits
+                .iter()
+                .fold(0_u32, |value, b| value * 10 + (b - b'0') as u32)
+                * multiplier
+        };
+        Self::new(
+            zone,
+            letters[0] as char,
+            (letters[1] as char, letters[2] as char),
+            value(easting),
+            value(northing),
+            precision,
+        )
+    }
+}
+
+/// Returns position of given letter in given set
+fn position(set: &[u8], letter: char) -> Option<usize> {
+    set.iter().position(|&b| b as char == letter)
+}
+
+/// Returns UPS column letters and first column index, for given band
+fn ups_columns(band: char) -> Option<(&'static [u8], u8)> {
+    UPS_COLUMNS
+        .iter()
+        .find(|(b, _, _)| *b == band)
+        .map(|(_, letters, first)| (*letters, *first))
+}
+
+/// Returns UPS row letters and first row index, for given hemisphere
+fn ups_rows(northern: bool) -> (&'static [u8], u8) {
+    UPS_ROWS[northern as usize]
+}
+
+impl MGRS {
+    /// Builds a grid reference, zone, band and 100 km square letters
+    /// being verified. Easting and northing are expressed in meters within
+    /// the 100 km square, and truncated to given precision (0 to 5 digits).
+    pub fn new(
+        zone: Option<u8>,
+        band: char,
+        square: (char, char),
+        easting: u32,
+        northing: u32,
+        precision: u8,
+    ) -> Result<Self, Error> {
+        let band = band.to_ascii_uppercase();
+        let square = (square.0.to_ascii_uppercase(), square.1.to_ascii_uppercase());
+        match zone {
+            Some(zone) => {
+                if !(1..=60).contains(&zone) {
+                    return Err(Error::InvalidZone);
+                }
+                if !BANDS.contains(band) {
+                    return Err(Error::InvalidBand);
+                }
+                let columns = UTM_COLUMNS[(zone as usize - 1) % 3];
+                if position(columns, square.0).is_none() || position(UTM_ROWS, square.1).is_none() {
+                    return Err(Error::InvalidSquare);
+                }
+            }
+            None => {
+                let (columns, _) = match ups_columns(band) {
+                    Some(columns) => columns,
+                    None if BANDS.contains(band) => return Err(Error::InvalidZone),
+                    None => return Err(Error::InvalidBand),
+                };
+                let (rows, _) = ups_rows(band >= 'Y');
+                if position(columns, square.0).is_none() || position(rows, square.1).is_none() {
+                    return Err(Error::InvalidSquare);
+                }
+            }
+        }
+        if precision > MAX_PRECISION || easting >= SQUARE as u32 || northing >= SQUARE as u32 {
+            return Err(Error::ParsingError(ParseError::FormatNotRecognized));
+        }
+        let resolution = 10_u32.pow((MAX_PRECISION - precision) as u32);
+        Ok(Self {
+            zone,
+            band,
+            square,
+            easting: easting / resolution * resolution,
+            northing: northing / resolution * resolution,
+            precision,
+        })
+    }
+
+    /// Returns the grid reference, with given number of digits per axis
+    /// (0 to 5, higher values being limited to 5), of the square containing
+    /// (latitude, longitude), in decimal degrees on the WGS84 ellipsoid
+    pub fn from_geodetic(latitude: f64, longitude: f64, precision: u8) -> Result<Self, Error> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(Error::InvalidLatitude);
+        }
+        let precision = precision.min(MAX_PRECISION);
+        let (zone, band, easting, northing, square) = if (-80.0..=84.0).contains(&latitude) {
+            let utm = UTM::project(Ellipsoid::WGS84, latitude, longitude, None)?;
+            let (column, row) = Self::hundred_km(utm.easting, utm.northing);
+            let columns = UTM_COLUMNS[(utm.zone as usize - 1) % 3];
+            let shift = if utm.zone % 2 == 0 { 5 } else { 0 };
+            let square = (
+                *columns
+                    .get(column.wrapping_sub(1))
+                    .ok_or(Error::InvalidSquare)? as char,
+                UTM_ROWS[(row + shift) % UTM_ROWS.len()] as char,
+            );
+            (Some(utm.zone), utm.band, utm.easting, utm.northing, square)
+        } else {
+            let ups = UPS::project(Ellipsoid::WGS84, latitude, longitude);
+            let band = match (ups.northern, ups.easting < 20.0 * SQUARE) {
+                (false, true) => 'A',
+                (false, false) => 'B',
+                (true, true) => 'Y',
+                (true, false) => 'Z',
+            };
+            let (column, row) = Self::hundred_km(ups.easting, ups.northing);
+            let (columns, first_column) = ups_columns(band).ok_or(Error::InvalidBand)?;
+            let (rows, first_row) = ups_rows(ups.northern);
+            let letter = |set: &[u8], index: usize, first: u8| {
+                index
+                    .checked_sub(first as usize)
+                    .and_then(|index| set.get(index))
+                    .map(|&b| b as char)
+                    .ok_or(Error::InvalidSquare)
+            };
+            let square = (
+                letter(columns, column, first_column)?,
+                letter(rows, row, first_row)?,
+            );
+            (None, band, ups.easting, ups.northing, square)
+        };
+        // grid references are truncated, not rounded:
+        // remove floating point noise first
+        let within = |value: f64| ((value.rem_euclid(SQUARE) * 1.0E6).round() / 1.0E6) as u32;
+        Self::new(
+            zone,
+            band,
+            square,
+            within(easting).min(SQUARE as u32 - 1),
+            within(northing).min(SQUARE as u32 - 1),
+            precision,
+        )
+    }
+
+    /// Returns 100 km column and row indexes of given easting and northing
+    fn hundred_km(easting: f64, northing: f64) -> (usize, usize) {
+        (
+            (easting / SQUARE).floor() as usize,
+            (northing / SQUARE).floor() as usize,
+        )
+    }
+
+    /// Returns the width of the square Self identifies, in meters
+    pub fn size(&self) -> f64 {
+        SQUARE / 10_f64.powi(self.precision as i32)
+    }
+
+    /// Returns the Scale matching the size of the square Self identifies,
+    /// from Scale::Country (100 km) to Scale::Tree (1 m)
+    pub fn scale(&self) -> Scale {
+        match self.precision {
+            0 => Scale::Country,
+            1 => Scale::LargeCity,
+            2 => Scale::City,
+            3 => Scale::Neighborhood,
+            4 => Scale::Street,
+            _ => Scale::Tree,
+        }
+    }
+
+    /// Returns (latitude, longitude), in decimal degrees on the WGS84
+    /// ellipsoid, of the centre of the square Self identifies
+    pub fn to_geodetic(&self) -> Result<(f64, f64), Error> {
+        let half = self.size() / 2.0;
+        match self.zone {
+            Some(zone) => {
+                let columns = UTM_COLUMNS[(zone as usize - 1) % 3];
+                let column = position(columns, self.square.0).ok_or(Error::InvalidSquare)?;
+                let row = position(UTM_ROWS, self.square.1).ok_or(Error::InvalidSquare)?;
+                let shift = if zone % 2 == 0 { 5 } else { 0 };
+                let row = (row + UTM_ROWS.len() - shift) % UTM_ROWS.len();
+                let band = BANDS.find(self.band).ok_or(Error::InvalidBand)?;
+                // rows repeat every 2000 km: use the northing of the bottom of
+                // the band, on the central meridian, with a 100 km margin
+                // for the curvature of parallels across the zone
+                let bottom = UTM::project(
+                    Ellipsoid::WGS84,
+                    -80.0 + 8.0 * band as f64,
+                    UTM::central_meridian(zone),
+                    Some(zone),
+                )?;
+                let bottom = (bottom.northing / SQUARE).floor() * SQUARE - SQUARE;
+                let mut northing = row as f64 * SQUARE + self.northing as f64 + half;
+                while northing < bottom {
+                    northing += 20.0 * SQUARE;
+                }
+                let utm = UTM::new(
+                    zone,
+                    self.band,
+                    (column + 1) as f64 * SQUARE + self.easting as f64 + half,
+                    northing,
+                )?;
+                Ok(utm.unproject(Ellipsoid::WGS84))
+            }
+            None => {
+                let (columns, first_column) = ups_columns(self.band).ok_or(Error::InvalidBand)?;
+                let (rows, first_row) = ups_rows(self.band >= 'Y');
+                let column = position(columns, self.square.0).ok_or(Error::InvalidSquare)?;
+                let row = position(rows, self.square.1).ok_or(Error::InvalidSquare)?;
+                let ups = UPS {
+                    northern: self.band >= 'Y',
+                    easting: (column + first_column as usize) as f64 * SQUARE
+                        + self.easting as f64
+                        + half,
+                    northing: (row + first_row as usize) as f64 * SQUARE
+                        + self.northing as f64
+                        + half,
+                };
+                Ok(ups.unproject(Ellipsoid::WGS84))
+            }
+        }
+    }
+}
@@ -0,0 +1,640 @@
+//! NMEA 0183 sentences, as emitted by GNSS receivers.
+//!
+//! GGA, RMC, GLL, GNS and WPL sentences are parsed from a byte buffer, without
+//! any allocation. Latitude and longitude fields are `ddmm.mmmm` [DDM]
+//! angles, followed by their hemisphere, the checksum is mandatory.
+//! GGA, RMC, GLL and WPL sentences are generated from [DMS3d] coordinates,
+//! with [DMS3d::to_nmea]. The requested "RLL" sentence is not part of NMEA 0183
+//! and was read as RMC, the standard sentence carrying a position with time,
+//! speed and course.
+use crate::{dms::DDM, Cardinal, DMS3d, Error, DMS};
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Maximal number of fields in a sentence, address included
+const MAX_FIELDS: usize = 24;
+
+/// NMEA parsing errors
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NmeaError {
+    /// Sentence does not start with '$', or has no checksum
+    InvalidFormat,
+    /// Checksum does not match the sentence content
+    ChecksumMismatch,
+    /// Sentence type is not supported
+    UnsupportedSentence,
+    /// A field could not be parsed, or is out of range
+    InvalidField,
+}
+
+impl core::fmt::Display for NmeaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            NmeaError::InvalidFormat => write!(f, "invalid sentence format"),
+            NmeaError::ChecksumMismatch => write!(f, "checksum mismatch"),
+            NmeaError::UnsupportedSentence => write!(f, "unsupported sentence"),
+            NmeaError::InvalidField => write!(f, "invalid field"),
+        }
+    }
+}
+
+/// Supported sentences
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SentenceKind {
+    /// Fix data: time, position, fix quality and altitude
+    GGA,
+    /// Recommended minimum data: time, position, speed and course
+    RMC,
+    /// Geographic position: position and time
+    GLL,
+    /// Multi constellation fix data: time, position and altitude
+    GNS,
+    /// Waypoint location
+    WPL,
+}
+
+/// UTC time of day
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Time {
+    /// Hours, 0 to 23
+    pub hours: u8,
+    /// Minutes, 0 to 59
+    pub minutes: u8,
+    /// Seconds, with fractional part, 0 <= s < 61 (leap second)
+    pub seconds: f64,
+}
+
+/// Quality of the position fix
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FixQuality {
+    /// No fix, position is not valid
+    Invalid,
+    /// Autonomous GNSS fix
+    Gps,
+    /// Differential GNSS fix
+    Dgps,
+    /// Precise (PPS) fix
+    Pps,
+    /// Real Time Kinematic, fixed integers
+    Rtk,
+    /// Real Time Kinematic, float integers
+    FloatRtk,
+    /// Estimated, dead reckoning
+    Estimated,
+    /// Manual input
+    Manual,
+    /// Simulated
+    Simulation,
+}
+
+impl FixQuality {
+    /// Builds fix quality from GGA quality indicator
+    fn from_indicator(indicator: u8) -> Result<Self, Error> {
+        match indicator {
+            b'0' => Ok(Self::Invalid),
+            b'1' => Ok(Self::Gps),
+            b'2' => Ok(Self::Dgps),
+            b'3' => Ok(Self::Pps),
+            b'4' => Ok(Self::Rtk),
+            b'5' => Ok(Self::FloatRtk),
+            b'6' => Ok(Self::Estimated),
+            b'7' => Ok(Self::Manual),
+            b'8' => Ok(Self::Simulation),
+            _ => Err(NmeaError::InvalidField.into()),
+        }
+    }
+    /// Returns GGA quality indicator
+    fn indicator(&self) -> char {
+        match self {
+            Self::Invalid => '0',
+            Self::Gps => '1',
+            Self::Dgps => '2',
+            Self::Pps => '3',
+            Self::Rtk => '4',
+            Self::FloatRtk => '5',
+            Self::Estimated => '6',
+            Self::Manual => '7',
+            Self::Simulation => '8',
+        }
+    }
+    /// Returns RMC, GLL and GNS mode indicator
+    fn mode(&self) -> char {
+        match self {
+            Self::Invalid => 'N',
+            Self::Gps => 'A',
+            Self::Dgps => 'D',
+            Self::Pps => 'P',
+            Self::Rtk => 'R',
+            Self::FloatRtk => 'F',
+            Self::Estimated => 'E',
+            Self::Manual => 'M',
+            Self::Simulation => 'S',
+        }
+    }
+    /// Builds fix quality from RMC, GLL and GNS mode indicator
+    fn from_mode(mode: u8) -> Result<Self, Error> {
+        match mode {
+            b'N' => Ok(Self::Invalid),
+            b'A' => Ok(Self::Gps),
+            b'D' => Ok(Self::Dgps),
+            b'P' => Ok(Self::Pps),
+            b'R' => Ok(Self::Rtk),
+            b'F' => Ok(Self::FloatRtk),
+            b'E' => Ok(Self::Estimated),
+            b'M' => Ok(Self::Manual),
+            b'S' => Ok(Self::Simulation),
+            _ => Err(NmeaError::InvalidField.into()),
+        }
+    }
+}
+
+/// Parsed NMEA sentence. Fields a sentence does not carry,
+/// or left empty by the receiver, are None.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sentence {
+    /// Talker identifier, like "GP" (GPS) or "GN" (multi constellation)
+    pub talker: [u8; 2],
+    /// Sentence type
+    pub kind: SentenceKind,
+    /// Position. Altitude, above mean sea level,
+    /// is only carried by GGA and GNS sentences.
+    pub coords: Option<DMS3d>,
+    /// UTC time of the fix
+    pub time: Option<Time>,
+    /// Fix quality
+    pub fix_quality: Option<FixQuality>,
+    /// Number of satellites in use
+    pub satellites: Option<u8>,
+    /// Geoid separation: height of the geoid above the ellipsoid, in meters
+    pub geoid_separation: Option<f64>,
+    /// Speed over ground, in knots
+    pub speed: Option<f64>,
+    /// Course over ground, in degrees from true North
+    pub course: Option<f64>,
+}
+
+impl core::str::FromStr for Sentence {
+    type Err = Error;
+    /// Parses a sentence like "$GPGLL,4916.45,N,12311.12,W,225444,A,*1D"
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::parse(s.as_bytes())
+    }
+}
+
+impl From<NmeaError> for Error {
+    fn from(e: NmeaError) -> Self {
+        Self::NmeaError(e)
+    }
+}
+
+/// Returns the checksum of given sentence content,
+/// between '$' and '*' excluded
+pub fn checksum(content: &[u8]) -> u8 {
+    content.iter().fold(0, |checksum, b| checksum ^ b)
+}
+
+/// Returns true if given field is a `[-]digits[.digits]` decimal number,
+/// which excludes "NaN", "inf" or exponents accepted by Rust parsers
+fn is_numeric(field: &[u8]) -> bool {
+    let field = field.strip_prefix(b"-").unwrap_or(field);
+    let mut parts = field.splitn(2, |&b| b == b'.');
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next().unwrap_or_default();
+    !(integer.is_empty() && fraction.is_empty())
+        && integer
+            .iter()
+            .chain(fraction.iter())
+            .all(|b| b.is_ascii_digit())
+}
+
+/// Parses an optional number
+fn number<T: core::str::FromStr>(field: &[u8]) -> Result<Option<T>, Error> {
+    if field.is_empty() {
+        return Ok(None);
+    }
+    if !is_numeric(field) {
+        return Err(NmeaError::InvalidField.into());
+    }
+    core::str::from_utf8(field)
+        .ok()
+        .and_then(|field| field.parse::<T>().ok())
+        .map(Some)
+        .ok_or_else(|| NmeaError::InvalidField.into())
+}
+
+/// Parses an optional `ddmm.mmmm` or `dddmm.mmmm` angle, and its hemisphere
+fn angle(value: &[u8], hemisphere: &[u8], latitude: bool) -> Result<Option<DMS>, Error> {
+    let value = match number::<f64>(value)? {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    let cardinal = match (hemisphere, latitude) {
+        (b"N", true) => Cardinal::North,
+        (b"S", true) => Cardinal::South,
+        (b"E", false) => Cardinal::East,
+        (b"W", false) => Cardinal::West,
+        _ => return Err(NmeaError::InvalidField.into()),
+    };
+    let degrees = (value / 100.0).floor();
+    let minutes = value - degrees * 100.0;
+    let max = if latitude { 90.0 } else { 180.0 };
+    if value < 0.0 || minutes >= 60.0 || degrees + minutes / 60.0 > max {
+        return Err(NmeaError::InvalidField.into());
+    }
+    Ok(Some(
+        DDM::new(degrees as u16, minutes, Some(cardinal)).into(),
+    ))
+}
+
+/// Parses an optional `hhmmss.ss` time
+fn time(field: &[u8]) -> Result<Option<Time>, Error> {
+    if field.is_empty() {
+        return Ok(None);
+    }
+    if field.len() < 6 {
+        return Err(NmeaError::InvalidField.into());
+    }
+    let hours = number::<u8>(&field[..2])?.unwrap_or(0);
+    let minutes = number::<u8>(&field[2..4])?.unwrap_or(0);
+    let seconds = number::<f64>(&field[4..])?.unwrap_or(0.0);
+    if hours > 23 || minutes > 59 || !(0.0..61.0).contains(&seconds) {
+        return Err(NmeaError::InvalidField.into());
+    }
+    Ok(Some(Time {
+        hours,
+        minutes,
+        seconds,
+    }))
+}
+
+/// Builds position from latitude, longitude, and optional altitude fields
+fn coords(fields: &[&[u8]], altitude: Option<f64>) -> Result<Option<DMS3d>, Error> {
+    let latitude = angle(fields[0], fields[1], true)?;
+    let longitude = angle(fields[2], fields[3], false)?;
+    Ok(match (latitude, longitude) {
+        (Some(latitude), Some(longitude)) => Some(DMS3d {
+            latitude,
+            longitude,
+            altitude,
+        }),
+        _ => None,
+    })
+}
+
+/// Returns fix quality from status ('A' valid, 'V' void),
+/// and optional mode indicator
+fn status(status: &[u8], mode: Option<&[u8]>) -> Result<Option<FixQuality>, Error> {
+    match (status, mode) {
+        (b"V", _) => Ok(Some(FixQuality::Invalid)),
+        (b"A", Some(&[mode])) => FixQuality::from_mode(mode).map(Some),
+        (b"A", _) => Ok(Some(FixQuality::Gps)),
+        (b"", _) => Ok(None),
+        _ => Err(NmeaError::InvalidField.into()),
+    }
+}
+
+impl Sentence {
+    /// Parses a sentence from given buffer, trailing "\r\n" being optional
+    pub fn parse(buffer: &[u8]) -> Result<Self, Error> {
+        let buffer = buffer.trim_ascii();
+        let (content, checksum_field) = match buffer {
+            [b'$', content @ ..] => {
+                let star = content
+                    .iter()
+                    .rposition(|&b| b == b'*')
+                    .ok_or(NmeaError::InvalidFormat)?;
+                (&content[..star], &content[star + 1..])
+            }
+            _ => return Err(NmeaError::InvalidFormat.into()),
+        };
+        let expected = core::str::from_utf8(checksum_field)
+            .ok()
+            .filter(|field| field.len() == 2)
+            .and_then(|field| u8::from_str_radix(field, 16).ok())
+            .ok_or(NmeaError::InvalidFormat)?;
+        if checksum(content) != expected {
+            return Err(NmeaError::ChecksumMismatch.into());
+        }
+        let mut fields: [&[u8]; MAX_FIELDS] = [&[]; MAX_FIELDS];
+        let mut count = 0;
+        for field in content.split(|&b| b == b',') {
+            if count == MAX_FIELDS {
+                return Err(NmeaError::InvalidFormat.into());
+            }
+            fields[count] = field;
+            count += 1;
+        }
+        let (talker, kind) = match fields[0] {
+            [t1, t2, kind @ ..] if kind.len() == 3 && *t1 != b'P' => {
+                let kind = match kind {
+                    b"GGA" => SentenceKind::GGA,
+                    b"RMC" => SentenceKind::RMC,
+                    b"GLL" => SentenceKind::GLL,
+                    b"GNS" => SentenceKind::GNS,
+                    b"WPL" => SentenceKind::WPL,
+                    _ => return Err(NmeaError::UnsupportedSentence.into()),
+                };
+                ([*t1, *t2], kind)
+            }
+            _ => return Err(NmeaError::UnsupportedSentence.into()),
+        };
+        let minimum = match kind {
+            SentenceKind::GGA => 15,
+            SentenceKind::RMC => 12,
+            SentenceKind::GLL => 7,
+            SentenceKind::GNS => 13,
+            SentenceKind::WPL => 6,
+        };
+        if count < minimum {
+            return Err(NmeaError::InvalidFormat.into());
+        }
+        let optional = |index: usize| {
+            if index < count {
+                Some(fields[index])
+            } else {
+                None
+            }
+        };
+        let mut sentence = Self {
+            talker,
+            kind,
+            coords: None,
+            time: None,
+            fix_quality: None,
+            satellites: None,
+            geoid_separation: None,
+            speed: None,
+            course: None,
+        };
+        match kind {
+            SentenceKind::GGA => {
+                let altitude = match fields[10] {
+                    b"M" | b"" => number::<f64>(fields[9])?,
+                    _ => return Err(NmeaError::InvalidField.into()),
+                };
+                sentence.time = time(fields[1])?;
+                sentence.coords = coords(&fields[2..6], altitude)?;
+                sentence.fix_quality = match fields[6] {
+                    [] => None,
+                    [indicator] => Some(FixQuality::from_indicator(*indicator)?),
+                    _ => return Err(NmeaError::InvalidField.into()),
+                };
+                sentence.satellites = number(fields[7])?;
+                sentence.geoid_separation = number(fields[11])?;
+            }
+            SentenceKind::RMC => {
+                sentence.time = time(fields[1])?;
+                sentence.fix_quality = status(fields[2], optional(12))?;
+                sentence.coords = coords(&fields[3..7], None)?;
+                sentence.speed = number(fields[7])?;
+                sentence.course = number(fields[8])?;
+            }
+            SentenceKind::GLL => {
+                sentence.coords = coords(&fields[1..5], None)?;
+                sentence.time = time(fields[5])?;
+                sentence.fix_quality = status(fields[6], optional(7))?;
+            }
+            SentenceKind::GNS => {
+                sentence.time = time(fields[1])?;
+                sentence.coords = coords(&fields[2..6], number::<f64>(fields[9])?)?;
+                // one mode indicator per constellation: retain the first one in use
+                sentence.fix_quality = match fields[6] {
+                    [] => None,
+                    modes => Some(
+                        modes
+                            .iter()
+                            .map(|&mode| FixQuality::from_mode(mode))
+                            .find(|quality| !matches!(quality, Ok(FixQuality::Invalid)))
+                            .unwrap_or(Ok(FixQuality::Invalid))?,
+                    ),
+                };
+                sentence.satellites = number(fields[7])?;
+                sentence.geoid_separation = number(fields[10])?;
+            }
+            SentenceKind::WPL => {
+                sentence.coords = coords(&fields[1..5], None)?;
+            }
+        }
+        Ok(sentence)
+    }
+}
+
+/// Number of decimal minutes, in generated sentences
+const MINUTES_DECIMALS: u32 = 4;
+
+/// Sentence generated from 3D coordinates, returned by [DMS3d::to_nmea].
+/// Time, fix quality, speed and course are optional, fix quality defaulting
+/// to [FixQuality::Gps]. The sentence, checksum included, is generated when
+/// this object is displayed, without any allocation nor line ending.
+#[derive(Debug, Copy, Clone)]
+pub struct DelayedSentence<'a> {
+    talker: [u8; 2],
+    kind: SentenceKind,
+    coords: DMS3d,
+    time: Option<Time>,
+    fix_quality: FixQuality,
+    satellites: Option<u8>,
+    speed: Option<f64>,
+    course: Option<f64>,
+    name: &'a str,
+}
+
+/// Writer that computes the checksum of what it writes
+struct ChecksumWriter<'a, 'b> {
+    f: &'a mut core::fmt::Formatter<'b>,
+    checksum: u8,
+}
+
+impl<'a, 'b> core::fmt::Write for ChecksumWriter<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.checksum ^= checksum(s.as_bytes());
+        self.f.write_str(s)
+    }
+}
+
+/// Writes given angle as `ddmm.mmmm` (`dddmm.mmmm` for longitudes) and
+/// its hemisphere. Minutes are rounded, carrying over to degrees.
+fn write_angle(w: &mut impl core::fmt::Write, angle: &DMS, latitude: bool) -> core::fmt::Result {
+    let scale = 10_u64.pow(MINUTES_DECIMALS);
+    let units = (angle.total_seconds() / 60.0 * scale as f64).round() as u64;
+    let (degrees, minutes) = (units / (60 * scale), units % (60 * scale));
+    let negative = angle.is_negative();
+    let hemisphere = match (latitude, negative) {
+        (true, false) => 'N',
+        (true, true) => 'S',
+        (false, false) => 'E',
+        (false, true) => 'W',
+    };
+    write!(
+        w,
+        "{:0width$}{:02}.{:0decimals$},{}",
+        degrees,
+        minutes / scale,
+        minutes % scale,
+        hemisphere,
+        width = if latitude { 2 } else { 3 },
+        decimals = MINUTES_DECIMALS as usize,
+    )
+}
+
+/// Writes given optional time as `hhmmss.ss`
+fn write_time(w: &mut impl core::fmt::Write, time: Option<Time>) -> core::fmt::Result {
+    if let Some(time) = time {
+        let centiseconds = (time.hours as u64 * 3600 + time.minutes as u64 * 60) * 100
+            + (time.seconds * 100.0).round() as u64;
+        let centiseconds = centiseconds % (24 * 3600 * 100);
+        let seconds = centiseconds / 100;
+        write!(
+            w,
+            "{:02}{:02}{:02}.{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            centiseconds % 100
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes given optional value, with one decimal
+fn write_optional(w: &mut impl core::fmt::Write, value: Option<f64>) -> core::fmt::Result {
+    if let Some(value) = value {
+        write!(w, "{:.1}", value)?;
+    }
+    Ok(())
+}
+
+impl<'a> DelayedSentence<'a> {
+    /// Builds a sentence of given kind, GNS being generated as GGA
+    pub(crate) fn new(coords: DMS3d, kind: SentenceKind) -> Self {
+        Self {
+            talker: *b"GP",
+            kind: match kind {
+                SentenceKind::GNS => SentenceKind::GGA,
+                kind => kind,
+            },
+            coords,
+            time: None,
+            fix_quality: FixQuality::Gps,
+            satellites: None,
+            speed: None,
+            course: None,
+            name: "",
+        }
+    }
+    /// Returns same sentence with given talker identifier, "GP" by default
+    pub fn with_talker(&self, talker: [u8; 2]) -> Self {
+        Self { talker, ..*self }
+    }
+    /// Returns same sentence with given UTC time (GGA, RMC, GLL)
+    pub fn with_time(&self, time: Time) -> Self {
+        Self {
+            time: Some(time),
+            ..*self
+        }
+    }
+    /// Returns same sentence with given fix quality (GGA, RMC, GLL)
+    pub fn with_fix_quality(&self, fix_quality: FixQuality) -> Self {
+        Self {
+            fix_quality,
+            ..*self
+        }
+    }
+    /// Returns same sentence with given number of satellites in use (GGA)
+    pub fn with_satellites(&self, satellites: u8) -> Self {
+        Self {
+            satellites: Some(satellites),
+            ..*self
+        }
+    }
+    /// Returns same sentence with given speed over ground, in knots (RMC)
+    pub fn with_speed(&self, speed: f64) -> Self {
+        Self {
+            speed: Some(speed),
+            ..*self
+        }
+    }
+    /// Returns same sentence with given course over ground,
+    /// in degrees from true North (RMC)
+    pub fn with_course(&self, course: f64) -> Self {
+        Self {
+            course: Some(course),
+            ..*self
+        }
+    }
+    /// Returns same sentence with given waypoint name (WPL)
+    pub fn with_name(&self, name: &'a str) -> Self {
+        Self { name, ..*self }
+    }
+
+    /// Writes sentence content, between '$' and '*' excluded
+    fn write_content(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        let (t1, t2) = (self.talker[0] as char, self.talker[1] as char);
+        let valid = self.fix_quality != FixQuality::Invalid;
+        let status = if valid { 'A' } else { 'V' };
+        match self.kind {
+            SentenceKind::GGA | SentenceKind::GNS => {
+                write!(w, "{}{}GGA,", t1, t2)?;
+                write_time(w, self.time)?;
+                w.write_char(',')?;
+                write_angle(w, &self.coords.latitude, true)?;
+                w.write_char(',')?;
+                write_angle(w, &self.coords.longitude, false)?;
+                write!(w, ",{},", self.fix_quality.indicator())?;
+                if let Some(satellites) = self.satellites {
+                    write!(w, "{:02}", satellites)?;
+                }
+                w.write_str(",,")?;
+                match self.coords.altitude {
+                    Some(altitude) => write!(w, "{:.1},M", altitude)?,
+                    None => w.write_char(',')?,
+                }
+                w.write_str(",,,,")
+            }
+            SentenceKind::RMC => {
+                write!(w, "{}{}RMC,", t1, t2)?;
+                write_time(w, self.time)?;
+                write!(w, ",{},", status)?;
+                write_angle(w, &self.coords.latitude, true)?;
+                w.write_char(',')?;
+                write_angle(w, &self.coords.longitude, false)?;
+                w.write_char(',')?;
+                write_optional(w, self.speed)?;
+                w.write_char(',')?;
+                write_optional(w, self.course)?;
+                write!(w, ",,,,{}", self.fix_quality.mode())
+            }
+            SentenceKind::GLL => {
+                write!(w, "{}{}GLL,", t1, t2)?;
+                write_angle(w, &self.coords.latitude, true)?;
+                w.write_char(',')?;
+                write_angle(w, &self.coords.longitude, false)?;
+                w.write_char(',')?;
+                write_time(w, self.time)?;
+                write!(w, ",{},{}", status, self.fix_quality.mode())
+            }
+            SentenceKind::WPL => {
+                write!(w, "{}{}WPL,", t1, t2)?;
+                write_angle(w, &self.coords.latitude, true)?;
+                w.write_char(',')?;
+                write_angle(w, &self.coords.longitude, false)?;
+                write!(w, ",{}", self.name)
+            }
+        }
+    }
+}
+
+impl<'a> core::fmt::Display for DelayedSentence<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("$")?;
+        let mut writer = ChecksumWriter { f, checksum: 0 };
+        self.write_content(&mut writer)?;
+        let checksum = writer.checksum;
+        write!(f, "*{:02X}", checksum)
+    }
+}
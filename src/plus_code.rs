@@ -0,0 +1,395 @@
+//! Open Location Codes, also known as Plus Codes.
+//!
+//! A full code like `8FVC9G8F+6X` identifies an area, 10 digits standing for
+//! a 1/8000° x 1/8000° area (about 14 m x 14 m), each digit beyond dividing
+//! the area into a 4 x 5 grid. Codes may be shortened relative to a nearby
+//! reference location, like `9G8F+6X`, and recovered from that location.
+//! Implementation follows the Open Location Code specification,
+//! integer arithmetic being used for encoding.
+use crate::{DMS3d, Error};
+
+/// Base 20 alphabet
+const ALPHABET: &[u8; 20] = b"23456789CFGHJMPQRVWX";
+/// Separator between the 8th and 9th digits
+const SEPARATOR: u8 = b'+';
+/// Position of the separator
+const SEPARATOR_POSITION: usize = 8;
+/// Padding digit, for codes shorter than 8 digits
+const PADDING: u8 = b'0';
+/// Number of digits encoded as pairs
+const PAIR_LENGTH: usize = 10;
+/// Maximal number of digits
+const MAX_DIGITS: usize = 15;
+/// Place value of the first pair, in pair precision units
+const PAIR_FIRST_PLACE_VALUE: i64 = 160_000;
+/// Number of pair precision units in one degree
+const PAIR_PRECISION: i64 = 8_000;
+/// Grid rows and columns, for digits beyond the pairs
+const GRID_ROWS: i64 = 5;
+const GRID_COLUMNS: i64 = 4;
+/// Number of final precision units in one degree, in latitude and longitude
+const FINAL_LAT_PRECISION: i64 = PAIR_PRECISION * 3_125;
+const FINAL_LNG_PRECISION: i64 = PAIR_PRECISION * 1_024;
+/// Minimal number of digits of a code that can be shortened
+const MIN_TRIMMABLE_LENGTH: usize = 6;
+/// Resolution of each pair, in degrees
+const PAIR_RESOLUTIONS: [f64; 5] = [20.0, 1.0, 0.05, 0.0025, 0.000125];
+
+/// Area of a full code, in decimal degrees
+struct Area {
+    south: f64,
+    west: f64,
+    height: f64,
+    width: f64,
+}
+
+/// Plus Code, either full or short
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct PlusCode {
+    /// Code characters, separator and padding included
+    code: [u8; MAX_DIGITS + 1],
+    /// Number of characters
+    length: usize,
+}
+
+impl core::fmt::Display for PlusCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl core::str::FromStr for PlusCode {
+    type Err = Error;
+    /// Parses a full code like "8FVC9G8F+6X" or "8FVC0000+",
+    /// or a short code like "9G8F+6X", case insensitive
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let s = s.trim().as_bytes();
+        if s.len() > MAX_DIGITS + 1 {
+            return Err(Error::InvalidPlusCode);
+        }
+        let mut code = [0_u8; MAX_DIGITS + 1];
+        for (i, c) in s.iter().enumerate() {
+            code[i] = c.to_ascii_uppercase();
+        }
+        let code = Self {
+            code,
+            length: s.len(),
+        };
+        code.validate()?;
+        Ok(code)
+    }
+}
+
+/// Returns value of given digit
+fn value(digit: u8) -> i64 {
+    ALPHABET.iter().position(|&c| c == digit).unwrap_or(0) as i64
+}
+
+/// Clips latitude to [-90, 90] and wraps longitude to [-180, 180)
+fn normalize(latitude: f64, longitude: f64) -> (f64, f64) {
+    (
+        latitude.clamp(-90.0, 90.0),
+        (longitude + 180.0).rem_euclid(360.0) - 180.0,
+    )
+}
+
+impl PlusCode {
+    /// Returns the full code of the area containing (latitude, longitude),
+    /// in decimal degrees, with given number of digits: 2, 4, 6, 8,
+    /// or 10 and above (up to 15). 10 digits is the usual length.
+    pub fn from_ddeg_angles(latitude: f64, longitude: f64, length: usize) -> Result<Self, Error> {
+        if !(2..=MAX_DIGITS).contains(&length)
+            || (length < PAIR_LENGTH && !length.is_multiple_of(2))
+        {
+            return Err(Error::InvalidPlusCode);
+        }
+        let (latitude, longitude) = normalize(latitude, longitude);
+        // remove floating point noise, before truncation
+        let to_integer = |value: f64, precision: i64| {
+            ((value * precision as f64 * 1.0E6).round() / 1.0E6).floor() as i64
+        };
+        let mut lat = to_integer(latitude + 90.0, FINAL_LAT_PRECISION)
+            .clamp(0, 180 * FINAL_LAT_PRECISION - 1);
+        let mut lng = to_integer(longitude + 180.0, FINAL_LNG_PRECISION)
+            .rem_euclid(360 * FINAL_LNG_PRECISION);
+        // digits, from the last one
+        let mut digits = [0_u8; MAX_DIGITS];
+        for i in (PAIR_LENGTH..MAX_DIGITS).rev() {
+            digits[i] = ALPHABET[((lat % GRID_ROWS) * GRID_COLUMNS + lng % GRID_COLUMNS) as usize];
+            lat /= GRID_ROWS;
+            lng /= GRID_COLUMNS;
+        }
+        for i in (0..PAIR_LENGTH / 2).rev() {
+            digits[2 * i + 1] = ALPHABET[(lng % 20) as usize];
+            digits[2 * i] = ALPHABET[(lat % 20) as usize];
+            lat /= 20;
+            lng /= 20;
+        }
+        let mut code = [0_u8; MAX_DIGITS + 1];
+        let mut position = 0;
+        for (i, &digit) in digits
+            .iter()
+            .enumerate()
+            .take(length.max(SEPARATOR_POSITION))
+        {
+            if i == SEPARATOR_POSITION {
+                code[position] = SEPARATOR;
+                position += 1;
+            }
+            code[position] = if i < length { digit } else { PADDING };
+            position += 1;
+        }
+        if length <= SEPARATOR_POSITION {
+            code[position] = SEPARATOR;
+            position += 1;
+        }
+        Ok(Self {
+            code,
+            length: position,
+        })
+    }
+
+    /// Verifies Self, as per the specification
+    fn validate(&self) -> Result<(), Error> {
+        let code = &self.code[..self.length];
+        let mut separators = code.iter().filter(|&&c| c == SEPARATOR);
+        let separator = match (
+            code.iter().position(|&c| c == SEPARATOR),
+            separators.next(),
+            separators.next(),
+        ) {
+            (Some(separator), Some(_), None) => separator,
+            _ => return Err(Error::InvalidPlusCode),
+        };
+        if separator > SEPARATOR_POSITION || !separator.is_multiple_of(2) {
+            return Err(Error::InvalidPlusCode);
+        }
+        if let Some(padding) = code.iter().position(|&c| c == PADDING) {
+            // padding is only allowed in full codes, in a single
+            // block of even length, right before the separator
+            let padding_length = code[padding..]
+                .iter()
+                .take_while(|&&c| c == PADDING)
+                .count();
+            if separator < SEPARATOR_POSITION
+                || padding == 0
+                || !padding.is_multiple_of(2)
+                || !padding_length.is_multiple_of(2)
+                || padding + padding_length != separator
+                || code.len() > separator + 1
+            {
+                return Err(Error::InvalidPlusCode);
+            }
+        }
+        if code.len() - separator - 1 == 1 {
+            return Err(Error::InvalidPlusCode);
+        }
+        let digits = code
+            .iter()
+            .filter(|&&c| c != SEPARATOR && c != PADDING)
+            .all(|c| ALPHABET.contains(c));
+        if !digits {
+            return Err(Error::InvalidPlusCode);
+        }
+        if separator == SEPARATOR_POSITION {
+            // first latitude digit must be below 90°N, and first longitude
+            // digit below 180°E
+            if value(code[0]) * 20 >= 180 || value(code[1]) * 20 >= 360 {
+                return Err(Error::InvalidPlusCode);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns code characters
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.code[..self.length]).unwrap_or_default()
+    }
+
+    /// Returns true if Self is a full code, that can be decoded
+    pub fn is_full(&self) -> bool {
+        self.separator() == SEPARATOR_POSITION
+    }
+
+    /// Returns true if Self is a short code,
+    /// to be recovered from a reference location
+    pub fn is_short(&self) -> bool {
+        !self.is_full()
+    }
+
+    /// Returns the position of the separator
+    fn separator(&self) -> usize {
+        self.code
+            .iter()
+            .position(|&c| c == SEPARATOR)
+            .unwrap_or(SEPARATOR_POSITION)
+    }
+
+    /// Returns significant digits, separator and padding removed, and their number
+    fn digits(&self) -> ([u8; MAX_DIGITS], usize) {
+        let mut digits = [0_u8; MAX_DIGITS];
+        let mut length = 0;
+        for &c in self.code[..self.length].iter() {
+            if c != SEPARATOR && c != PADDING {
+                digits[length] = c;
+                length += 1;
+            }
+        }
+        (digits, length)
+    }
+
+    /// Returns the area of a full code
+    fn area(&self) -> Result<Area, Error> {
+        if !self.is_full() {
+            return Err(Error::InvalidPlusCode);
+        }
+        let (digits, length) = self.digits();
+        let (mut lat, mut lng) = (-90 * PAIR_PRECISION, -180 * PAIR_PRECISION);
+        let mut place_value = PAIR_FIRST_PLACE_VALUE;
+        let pairs = length.min(PAIR_LENGTH);
+        for i in (0..pairs).step_by(2) {
+            lat += value(digits[i]) * place_value;
+            lng += value(digits[i + 1]) * place_value;
+            if i < pairs - 2 {
+                place_value /= 20;
+            }
+        }
+        let mut south = lat as f64 / PAIR_PRECISION as f64;
+        let mut west = lng as f64 / PAIR_PRECISION as f64;
+        let mut height = place_value as f64 / PAIR_PRECISION as f64;
+        let mut width = height;
+        if length > PAIR_LENGTH {
+            let (mut row_value, mut column_value) = (625, 256);
+            let (mut lat, mut lng) = (0, 0);
+            for (i, &digit) in digits.iter().enumerate().take(length).skip(PAIR_LENGTH) {
+                let value = value(digit);
+                lat += value / GRID_COLUMNS * row_value;
+                lng += value % GRID_COLUMNS * column_value;
+                if i < length - 1 {
+                    row_value /= GRID_ROWS;
+                    column_value /= GRID_COLUMNS;
+                }
+            }
+            south += lat as f64 / FINAL_LAT_PRECISION as f64;
+            west += lng as f64 / FINAL_LNG_PRECISION as f64;
+            height = row_value as f64 / FINAL_LAT_PRECISION as f64;
+            width = column_value as f64 / FINAL_LNG_PRECISION as f64;
+        }
+        Ok(Area {
+            south,
+            west,
+            height,
+            width,
+        })
+    }
+
+    /// Returns the number of significant digits
+    pub fn len(&self) -> usize {
+        self.digits().1
+    }
+
+    /// Returns true if Self has no significant digits,
+    /// like short code "+"
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the south western and north eastern corners of the area
+    /// of a full code. Error::InvalidPlusCode is returned for short codes.
+    pub fn bounds(&self) -> Result<(DMS3d, DMS3d), Error> {
+        let area = self.area()?;
+        Ok((
+            DMS3d::from_ddeg_angles(area.south, area.west, None),
+            DMS3d::from_ddeg_angles(
+                (area.south + area.height).min(90.0),
+                area.west + area.width,
+                None,
+            ),
+        ))
+    }
+
+    /// Returns the centre of the area of a full code.
+    /// Error::InvalidPlusCode is returned for short codes.
+    pub fn centre(&self) -> Result<DMS3d, Error> {
+        let (latitude, longitude) = self.centre_ddeg()?;
+        Ok(DMS3d::from_ddeg_angles(latitude, longitude, None))
+    }
+
+    /// Returns the centre of the area of a full code,
+    /// as (latitude, longitude) in decimal degrees
+    fn centre_ddeg(&self) -> Result<(f64, f64), Error> {
+        let area = self.area()?;
+        Ok((
+            (area.south + area.height / 2.0).min(90.0),
+            (area.west + area.width / 2.0).min(180.0),
+        ))
+    }
+
+    /// Shortens a full code of at least 6 digits, relative to
+    /// given reference location, as much as it can be recovered
+    /// from that location
+    pub fn shorten(&self, reference: DMS3d) -> Result<Self, Error> {
+        let (centre, length) = (self.centre_ddeg()?, self.len());
+        if self.code.contains(&PADDING) || length < MIN_TRIMMABLE_LENGTH {
+            return Err(Error::InvalidPlusCode);
+        }
+        let (latitude, longitude) = normalize(
+            reference.latitude.to_ddeg_angle(),
+            reference.longitude.to_ddeg_angle(),
+        );
+        let range = (centre.0 - latitude)
+            .abs()
+            .max((centre.1 - longitude).abs());
+        for i in (1..PAIR_RESOLUTIONS.len() - 1).rev() {
+            // a safety factor is used, for the area to be
+            // recovered from locations that are not its centre
+            if range < PAIR_RESOLUTIONS[i] * 0.3 {
+                let removed = 2 * (i + 1);
+                let mut code = [0_u8; MAX_DIGITS + 1];
+                code[..self.length - removed].copy_from_slice(&self.code[removed..self.length]);
+                return Ok(Self {
+                    code,
+                    length: self.length - removed,
+                });
+            }
+        }
+        Ok(*self)
+    }
+
+    /// Recovers the full code, of the area nearest to given
+    /// reference location, that matches a short code.
+    /// Recovered codes are truncated to 15 digits, full codes are returned as is.
+    pub fn recover(&self, reference: DMS3d) -> Result<Self, Error> {
+        if self.is_full() {
+            return Ok(*self);
+        }
+        let (latitude, longitude) = normalize(
+            reference.latitude.to_ddeg_angle(),
+            reference.longitude.to_ddeg_angle(),
+        );
+        let prefix_length = SEPARATOR_POSITION - self.separator();
+        let resolution = 20.0_f64.powi(2 - (prefix_length / 2) as i32);
+        let half_resolution = resolution / 2.0;
+        // prefix the short code with the reference location code
+        let prefix = Self::from_ddeg_angles(latitude, longitude, PAIR_LENGTH)?;
+        // the recovered code is truncated to the maximal number of digits
+        let length = (prefix_length + self.length).min(MAX_DIGITS + 1);
+        let mut code = [0_u8; MAX_DIGITS + 1];
+        code[..prefix_length].copy_from_slice(&prefix.code[..prefix_length]);
+        code[prefix_length..length].copy_from_slice(&self.code[..length - prefix_length]);
+        let full = Self { code, length };
+        let (mut lat, mut lng) = full.centre_ddeg()?;
+        // the nearest matching area may be in the neighbouring cell
+        if latitude + half_resolution < lat && lat - resolution >= -90.0 {
+            lat -= resolution;
+        } else if latitude - half_resolution > lat && lat + resolution <= 90.0 {
+            lat += resolution;
+        }
+        if longitude + half_resolution < lng {
+            lng -= resolution;
+        } else if longitude - half_resolution > lng {
+            lng += resolution;
+        }
+        Self::from_ddeg_angles(lat, lng, full.len())
+    }
+}
@@ -0,0 +1,105 @@
+//! Rhumb line (loxodrome) navigation, on a reference ellipsoid.
+//!
+//! A rhumb line crosses all meridians at the same angle: it is the
+//! path followed when steering a constant compass course, a straight line
+//! on a Mercator chart. It is longer than the geodesic, except along
+//! meridians and the equator. Mercator sailing formulas are used, with the
+//! isometric latitude and the meridian arc of the ellipsoid, so they reduce
+//! to the usual spherical formulas on [Ellipsoid::sphere].
+use crate::{
+    geodesic::{ang_normalize, normalize_azimuth},
+    Cardinal, Ellipsoid, Error, DMS,
+};
+
+/// Rhumb line between two points
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rhumb {
+    /// Length of the rhumb line, in meters
+    pub distance: f64,
+    /// Constant bearing, in decimal degrees, 0 <= ɑ < 360, 0 being North
+    pub bearing: f64,
+}
+
+impl Rhumb {
+    /// Returns constant course to steer, as a D°M'S" angle
+    pub fn course(&self) -> DMS {
+        DMS::from_ddeg_angle(self.bearing)
+    }
+    /// Returns nearest Cardinal to the course to steer
+    pub fn cardinal(&self) -> Cardinal {
+        Cardinal::nearest(self.bearing)
+    }
+}
+
+/// Latitude difference (radians) under which the rhumb line is considered
+/// to follow a parallel, for the meridian arc to isometric latitude ratio
+/// to remain accurate
+const PARALLEL_THRESHOLD: f64 = 1.0E-5;
+
+/// Returns the ratio between the meridian arc difference and the
+/// isometric latitude difference, which tends to the radius of the parallel
+/// when both latitudes get closer. Latitudes in radians.
+fn stretch(ellipsoid: Ellipsoid, phi1: f64, phi2: f64, dm: f64, dpsi: f64) -> f64 {
+    if (phi2 - phi1).abs() > PARALLEL_THRESHOLD {
+        dm / dpsi
+    } else {
+        let phi = (phi1 + phi2) / 2.0;
+        ellipsoid.prime_vertical_radius(phi) * phi.cos()
+    }
+}
+
+/// Returns the rhumb line between point 1 and point 2, both expressed
+/// as (latitude, longitude) in decimal degrees, on given ellipsoid.
+/// The shortest rhumb line is returned, which crosses the antimeridian when
+/// longitudes differ by more than 180°.
+pub fn inverse(ellipsoid: Ellipsoid, p1: (f64, f64), p2: (f64, f64)) -> Rhumb {
+    let (phi1, phi2) = (p1.0.to_radians(), p2.0.to_radians());
+    let dlambda = ang_normalize(p2.1 - p1.1).to_radians();
+    let dm = ellipsoid.meridian_arc(phi2) - ellipsoid.meridian_arc(phi1);
+    let dpsi = ellipsoid.isometric_latitude(phi2) - ellipsoid.isometric_latitude(phi1);
+    let q = stretch(ellipsoid, phi1, phi2, dm, dpsi);
+    let bearing = dlambda.atan2(dpsi).to_degrees();
+    Rhumb {
+        distance: dm.hypot(q * dlambda),
+        bearing: normalize_azimuth(bearing),
+    }
+}
+
+/// Returns (latitude, longitude), in decimal degrees, reached after
+/// travelling `distance` meters from point 1 on given constant `bearing`
+/// (decimal degrees, 0 being North), on given ellipsoid.
+/// A rhumb line spirals towards the pole without crossing it:
+/// Error::InvalidLatitude is returned when the pole would be passed.
+pub fn direct(
+    ellipsoid: Ellipsoid,
+    p1: (f64, f64),
+    bearing: f64,
+    distance: f64,
+) -> Result<(f64, f64), Error> {
+    let phi1 = p1.0.to_radians();
+    let (sin_theta, cos_theta) = bearing.to_radians().sin_cos();
+    let dm = distance * cos_theta;
+    let m2 = ellipsoid.meridian_arc(phi1) + dm;
+    let quarter_meridian = ellipsoid.meridian_arc(core::f64::consts::FRAC_PI_2);
+    if m2.abs() > quarter_meridian + 1.0E-6 {
+        return Err(Error::InvalidLatitude);
+    }
+    let m2 = m2.clamp(-quarter_meridian, quarter_meridian);
+    let phi2 = if m2.abs() == quarter_meridian {
+        core::f64::consts::FRAC_PI_2.copysign(m2)
+    } else {
+        ellipsoid.meridian_arc_latitude(m2)
+    };
+    let dpsi = ellipsoid.isometric_latitude(phi2) - ellipsoid.isometric_latitude(phi1);
+    let q = stretch(ellipsoid, phi1, phi2, dm, dpsi);
+    // longitude is not defined at the pole
+    let dlambda = if q == 0.0 {
+        0.0
+    } else {
+        distance * sin_theta / q
+    };
+    Ok((
+        phi2.to_degrees(),
+        ang_normalize(p1.1 + dlambda.to_degrees()),
+    ))
+}
@@ -0,0 +1,349 @@
+//! Universal Transverse Mercator (UTM) projection.
+//!
+//! The transverse Mercator projection uses Krüger's series to the 6th order
+//! in the third flattening, as given by Karney (J. Geodesy 2011),
+//! accurate to a few nanometers within the UTM zones.
+//! UTM covers latitudes from 80°S to 84°N, the polar areas
+//! being covered by the Universal Polar Stereographic (UPS) projection.
+use crate::{dms::ParseError, geodesic::ang_normalize, Ellipsoid, Error};
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Scale factor on the central meridian
+const K0: f64 = 0.9996;
+/// Easting of the central meridian, in meters
+const FALSE_EASTING: f64 = 500_000.0;
+/// Northing of the equator, in the southern hemisphere, in meters
+const FALSE_NORTHING: f64 = 10_000_000.0;
+/// Latitude bands, 8° high from 80°S, band X being 12° high
+pub(crate) const BANDS: &str = "CDEFGHJKLMNPQRSTUVWX";
+
+/// UTM grid coordinates
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UTM {
+    /// Zone number, 1 to 60, each zone being 6° wide
+    pub zone: u8,
+    /// Latitude band letter, 'C' to 'X' ('I' and 'O' excluded),
+    /// 'N' and above being in the northern hemisphere
+    pub band: char,
+    /// Easting, in meters
+    pub easting: f64,
+    /// Northing, in meters
+    pub northing: f64,
+}
+
+impl core::fmt::Display for UTM {
+    /// Displays grid coordinates like "31U 448251.795 5411932.678"
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{} {:.3} {:.3}",
+            self.zone, self.band, self.easting, self.northing
+        )
+    }
+}
+
+impl core::str::FromStr for UTM {
+    type Err = Error;
+    /// Parses grid coordinates like "31U 448251 5411932",
+    /// zone and band may be separated, like "31 U 448251 5411932"
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let s = s.trim();
+        let digits = s.bytes().take_while(|b| b.is_ascii_digit()).count();
+        let zone = s[..digits].parse::<u8>().map_err(|_| Error::InvalidZone)?;
+        let mut items = s[digits..].split_whitespace();
+        let band = items
+            .next()
+            .ok_or(ParseError::FormatNotRecognized)?
+            .to_ascii_uppercase();
+        let mut band_chars = band.chars();
+        let band = match (band_chars.next(), band_chars.next()) {
+            (Some(band), None) => band,
+            _ => return Err(Error::InvalidBand),
+        };
+        let easting = items
+            .next()
+            .and_then(|e| e.parse::<f64>().ok())
+            .ok_or(ParseError::FormatNotRecognized)?;
+        let northing = items
+            .next()
+            .and_then(|n| n.parse::<f64>().ok())
+            .ok_or(ParseError::FormatNotRecognized)?;
+        if items.next().is_some() {
+            return Err(Error::ParsingError(ParseError::FormatNotRecognized));
+        }
+        Self::new(zone, band, easting, northing)
+    }
+}
+
+/// Returns latitude band letter, for given latitude in decimal degrees
+pub fn band(latitude: f64) -> Result<char, Error> {
+    if !(-80.0..=84.0).contains(&latitude) {
+        return Err(Error::InvalidLatitude);
+    }
+    let index = (((latitude + 80.0) / 8.0).floor() as usize).min(BANDS.len() - 1);
+    Ok(BANDS.as_bytes()[index] as char)
+}
+
+/// Returns UTM zone number for given (latitude, longitude),
+/// in decimal degrees, including the Norway and Svalbard exceptions
+pub fn zone(latitude: f64, longitude: f64) -> u8 {
+    let longitude = (longitude + 180.0).rem_euclid(360.0) - 180.0;
+    let zone = (((longitude + 180.0) / 6.0).floor() as u8).min(59) + 1;
+    if (56.0..64.0).contains(&latitude) && (3.0..12.0).contains(&longitude) {
+        // south western Norway
+        return 32;
+    }
+    if (72.0..=84.0).contains(&latitude) && longitude >= 0.0 {
+        // Svalbard
+        match zone {
+            32 => return if longitude < 9.0 { 31 } else { 33 },
+            34 => return if longitude < 21.0 { 33 } else { 35 },
+            36 => return if longitude < 33.0 { 35 } else { 37 },
+            _ => {}
+        }
+    }
+    zone
+}
+
+/// Krüger series coefficients, to the 6th order in n
+struct Kruger {
+    /// Rectifying radius
+    a: f64,
+    /// Eccentricity
+    e: f64,
+    /// Forward series
+    alpha: [f64; 6],
+    /// Inverse series
+    beta: [f64; 6],
+}
+
+impl Kruger {
+    fn new(ellipsoid: Ellipsoid) -> Self {
+        let n = ellipsoid.third_flattening();
+        let (n2, n3, n4, n5, n6) = (n * n, n.powi(3), n.powi(4), n.powi(5), n.powi(6));
+        Self {
+            a: ellipsoid.semi_major_axis / (1.0 + n) * (1.0 + n2 / 4.0 + n4 / 64.0 + n6 / 256.0),
+            e: ellipsoid.eccentricity_squared().sqrt(),
+            alpha: [
+                n / 2.0 - 2.0 / 3.0 * n2 + 5.0 / 16.0 * n3 + 41.0 / 180.0 * n4 - 127.0 / 288.0 * n5
+                    + 7891.0 / 37800.0 * n6,
+                13.0 / 48.0 * n2 - 3.0 / 5.0 * n3 + 557.0 / 1440.0 * n4 + 281.0 / 630.0 * n5
+                    - 1983433.0 / 1935360.0 * n6,
+                61.0 / 240.0 * n3 - 103.0 / 140.0 * n4
+                    + 15061.0 / 26880.0 * n5
+                    + 167603.0 / 181440.0 * n6,
+                49561.0 / 161280.0 * n4 - 179.0 / 168.0 * n5 + 6601661.0 / 7257600.0 * n6,
+                34729.0 / 80640.0 * n5 - 3418889.0 / 1995840.0 * n6,
+                212378941.0 / 319334400.0 * n6,
+            ],
+            beta: [
+                n / 2.0 - 2.0 / 3.0 * n2 + 37.0 / 96.0 * n3 - 1.0 / 360.0 * n4 - 81.0 / 512.0 * n5
+                    + 96199.0 / 604800.0 * n6,
+                n2 / 48.0 + n3 / 15.0 - 437.0 / 1440.0 * n4 + 46.0 / 105.0 * n5
+                    - 1118711.0 / 3870720.0 * n6,
+                17.0 / 480.0 * n3 - 37.0 / 840.0 * n4 - 209.0 / 4480.0 * n5 + 5569.0 / 90720.0 * n6,
+                4397.0 / 161280.0 * n4 - 11.0 / 504.0 * n5 - 830251.0 / 7257600.0 * n6,
+                4583.0 / 161280.0 * n5 - 108847.0 / 3991680.0 * n6,
+                20648693.0 / 638668800.0 * n6,
+            ],
+        }
+    }
+
+    /// Projects (latitude, longitude relative to the central meridian),
+    /// in radians, to (x, y) in meters, scale factor excluded
+    fn forward(&self, phi: f64, lambda: f64) -> (f64, f64) {
+        let e = self.e;
+        // conformal latitude
+        let t = (phi.sin().atanh() - e * (e * phi.sin()).atanh()).sinh();
+        let xi_p = t.atan2(lambda.cos());
+        let eta_p = (lambda.sin() / (1.0 + t * t).sqrt()).atanh();
+        let mut xi = xi_p;
+        let mut eta = eta_p;
+        for (j, alpha) in self.alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi += alpha * (k * xi_p).sin() * (k * eta_p).cosh();
+            eta += alpha * (k * xi_p).cos() * (k * eta_p).sinh();
+        }
+        (self.a * eta, self.a * xi)
+    }
+
+    /// Inverse projection of (x, y) in meters, scale factor excluded,
+    /// to (latitude, longitude relative to the central meridian) in radians
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let e = self.e;
+        let xi = y / self.a;
+        let eta = x / self.a;
+        let mut xi_p = xi;
+        let mut eta_p = eta;
+        for (j, beta) in self.beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi_p -= beta * (k * xi).sin() * (k * eta).cosh();
+            eta_p -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+        let sinh_eta_p = eta_p.sinh();
+        let (sin_xi_p, cos_xi_p) = xi_p.sin_cos();
+        let tau_p = sin_xi_p / (sinh_eta_p * sinh_eta_p + cos_xi_p * cos_xi_p).sqrt();
+        // conformal to geodetic latitude, Newton's method
+        let e2 = e * e;
+        let mut tau = tau_p;
+        for _ in 0..10 {
+            let sigma = (e * (e * tau / (1.0 + tau * tau).sqrt()).atanh()).sinh();
+            let tau_i = tau * (1.0 + sigma * sigma).sqrt() - sigma * (1.0 + tau * tau).sqrt();
+            let delta = (tau_p - tau_i) / (1.0 + tau_i * tau_i).sqrt()
+                * (1.0 + (1.0 - e2) * tau * tau)
+                / ((1.0 - e2) * (1.0 + tau * tau).sqrt());
+            tau += delta;
+            if delta.abs() < 1.0E-12 {
+                break;
+            }
+        }
+        (tau.atan(), sinh_eta_p.atan2(cos_xi_p))
+    }
+}
+
+impl UTM {
+    /// Builds UTM grid coordinates, zone number and band letter being verified
+    pub fn new(zone: u8, band: char, easting: f64, northing: f64) -> Result<Self, Error> {
+        if !(1..=60).contains(&zone) {
+            return Err(Error::InvalidZone);
+        }
+        let band = band.to_ascii_uppercase();
+        if !BANDS.contains(band) {
+            return Err(Error::InvalidBand);
+        }
+        Ok(Self {
+            zone,
+            band,
+            easting,
+            northing,
+        })
+    }
+
+    /// Returns true if Self lies in the northern hemisphere
+    pub fn is_northern(&self) -> bool {
+        self.band >= 'N'
+    }
+
+    /// Returns longitude of the zone central meridian, in decimal degrees
+    pub fn central_meridian(zone: u8) -> f64 {
+        zone as f64 * 6.0 - 183.0
+    }
+
+    /// Projects (latitude, longitude), in decimal degrees, on given ellipsoid.
+    /// The standard zone is used unless a zone is given, in which case
+    /// coordinates are projected in that zone, even far from it.
+    pub fn project(
+        ellipsoid: Ellipsoid,
+        latitude: f64,
+        longitude: f64,
+        zone: Option<u8>,
+    ) -> Result<Self, Error> {
+        let band = band(latitude)?;
+        let zone = zone.unwrap_or_else(|| self::zone(latitude, longitude));
+        if !(1..=60).contains(&zone) {
+            return Err(Error::InvalidZone);
+        }
+        let lambda = ang_normalize(longitude - Self::central_meridian(zone)).to_radians();
+        let (x, y) = Kruger::new(ellipsoid).forward(latitude.to_radians(), lambda);
+        let northing = K0 * y + if latitude < 0.0 { FALSE_NORTHING } else { 0.0 };
+        Ok(Self {
+            zone,
+            band,
+            easting: FALSE_EASTING + K0 * x,
+            northing,
+        })
+    }
+
+    /// Returns (latitude, longitude), in decimal degrees,
+    /// of Self projected on given ellipsoid
+    pub fn unproject(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
+        let x = (self.easting - FALSE_EASTING) / K0;
+        let y = if self.is_northern() {
+            self.northing
+        } else {
+            self.northing - FALSE_NORTHING
+        } / K0;
+        let (phi, lambda) = Kruger::new(ellipsoid).inverse(x, y);
+        (
+            phi.to_degrees(),
+            ang_normalize(lambda.to_degrees() + Self::central_meridian(self.zone)),
+        )
+    }
+}
+
+/// UPS scale factor at the pole
+const UPS_K0: f64 = 0.994;
+/// UPS false easting and northing, in meters
+const UPS_FALSE_ORIGIN: f64 = 2_000_000.0;
+
+/// Universal Polar Stereographic grid coordinates,
+/// used beyond 84°N and 80°S
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UPS {
+    /// True for the north polar area
+    pub northern: bool,
+    /// Easting, in meters
+    pub easting: f64,
+    /// Northing, in meters
+    pub northing: f64,
+}
+
+impl UPS {
+    /// Returns 2 a k0 / sqrt((1 + e)^(1 + e) (1 - e)^(1 - e))
+    fn radius(ellipsoid: Ellipsoid) -> f64 {
+        let e = ellipsoid.eccentricity_squared().sqrt();
+        2.0 * ellipsoid.semi_major_axis * UPS_K0
+            / ((1.0 + e).powf(1.0 + e) * (1.0 - e).powf(1.0 - e)).sqrt()
+    }
+
+    /// Projects (latitude, longitude), in decimal degrees, on given ellipsoid,
+    /// on the polar area of the hemisphere latitude belongs to
+    pub fn project(ellipsoid: Ellipsoid, latitude: f64, longitude: f64) -> Self {
+        let e = ellipsoid.eccentricity_squared().sqrt();
+        let northern = latitude >= 0.0;
+        let phi = latitude.abs().to_radians();
+        let (sin_lambda, cos_lambda) = longitude.to_radians().sin_cos();
+        let t = (core::f64::consts::FRAC_PI_4 - phi / 2.0).tan()
+            / ((1.0 - e * phi.sin()) / (1.0 + e * phi.sin())).powf(e / 2.0);
+        let rho = Self::radius(ellipsoid) * t;
+        Self {
+            northern,
+            easting: UPS_FALSE_ORIGIN + rho * sin_lambda,
+            northing: if northern {
+                UPS_FALSE_ORIGIN - rho * cos_lambda
+            } else {
+                UPS_FALSE_ORIGIN + rho * cos_lambda
+            },
+        }
+    }
+
+    /// Returns (latitude, longitude), in decimal degrees,
+    /// of Self projected on given ellipsoid
+    pub fn unproject(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
+        let e = ellipsoid.eccentricity_squared().sqrt();
+        let dx = self.easting - UPS_FALSE_ORIGIN;
+        let dy = self.northing - UPS_FALSE_ORIGIN;
+        let t = dx.hypot(dy) / Self::radius(ellipsoid);
+        let half_pi = core::f64::consts::FRAC_PI_2;
+        let mut phi = half_pi - 2.0 * t.atan();
+        for _ in 0..20 {
+            let sin_phi = phi.sin();
+            let next = half_pi
+                - 2.0 * (t * ((1.0 - e * sin_phi) / (1.0 + e * sin_phi)).powf(e / 2.0)).atan();
+            let delta = (next - phi).abs();
+            phi = next;
+            if delta < 1.0E-14 {
+                break;
+            }
+        }
+        if self.northern {
+            (phi.to_degrees(), dx.atan2(-dy).to_degrees())
+        } else {
+            (-phi.to_degrees(), dx.atan2(dy).to_degrees())
+        }
+    }
+}
@@ -0,0 +1,522 @@
+//! Well-Known Text (WKT) and Well-Known Binary (WKB) geometries,
+//! made of [DMS3d] coordinates, as used by PostGIS and GeoPackage.
+//!
+//! Positions are written `lon lat [alt]` in decimal degrees and meters.
+//! The Z dimension is used when every position has an altitude.
+//! Binary geometries are encoded as ISO WKB, or as PostGIS Extended WKB (EWKB)
+//! which may carry an SRID, in either byte order.
+use crate::{DMS3d, Error};
+
+/// EWKB flag of geometries having a Z dimension
+const EWKB_Z: u32 = 0x8000_0000;
+/// EWKB flag of geometries having an M dimension
+const EWKB_M: u32 = 0x4000_0000;
+/// EWKB flag of geometries followed by an SRID
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Errors returned when decoding WKT and WKB geometries
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WktError {
+    /// Text does not follow the WKT grammar
+    InvalidText,
+    /// Byte order flag is neither 0 (big endian) nor 1 (little endian)
+    InvalidByteOrder,
+    /// Geometry type, or M dimension, is not supported
+    UnsupportedGeometry,
+    /// Binary geometry is truncated, or followed by extra bytes
+    InvalidLength,
+    /// Line string with a single position,
+    /// or polygon ring with less than 4 positions
+    TooFewPositions,
+    /// Polygon ring whose first and last positions differ
+    UnclosedRing,
+}
+
+impl core::fmt::Display for WktError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            WktError::InvalidText => write!(f, "invalid well-known text"),
+            WktError::InvalidByteOrder => write!(f, "invalid byte order"),
+            WktError::UnsupportedGeometry => write!(f, "unsupported geometry"),
+            WktError::InvalidLength => write!(f, "invalid length"),
+            WktError::TooFewPositions => write!(f, "too few positions"),
+            WktError::UnclosedRing => write!(f, "unclosed ring"),
+        }
+    }
+}
+
+impl From<WktError> for Error {
+    fn from(e: WktError) -> Self {
+        Self::WktError(e)
+    }
+}
+
+/// WKB byte order
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Big endian (XDR), flagged 0
+    BigEndian,
+    /// Little endian (NDR), flagged 1
+    LittleEndian,
+}
+
+/// Supported geometries
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    /// Single position
+    Point(DMS3d),
+    /// Path going through two or more positions, none when empty
+    LineString(Vec<DMS3d>),
+    /// Closed linear rings, the exterior ring coming first, followed by holes
+    Polygon(Vec<Vec<DMS3d>>),
+}
+
+impl From<DMS3d> for Geometry {
+    fn from(coords: DMS3d) -> Self {
+        Self::Point(coords)
+    }
+}
+
+impl From<Vec<DMS3d>> for Geometry {
+    fn from(coords: Vec<DMS3d>) -> Self {
+        Self::LineString(coords)
+    }
+}
+
+/// Builds coordinates from a `lon lat [alt]` position
+fn coords(longitude: f64, latitude: f64, altitude: Option<f64>) -> Result<DMS3d, Error> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(Error::InvalidLatitude);
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(Error::InvalidLongitude);
+    }
+    if altitude.is_some_and(|a| !a.is_finite()) {
+        return Err(Error::InvalidAltitude);
+    }
+    Ok(DMS3d::from_ddeg_angles(latitude, longitude, altitude))
+}
+
+/// Verifies that a line string has none or two positions or more
+fn validate_path(path: &[DMS3d]) -> Result<(), Error> {
+    if path.len() == 1 {
+        return Err(WktError::TooFewPositions.into());
+    }
+    Ok(())
+}
+
+/// Verifies that a polygon ring has 4 positions or more, and is closed
+fn validate_ring(ring: &[DMS3d]) -> Result<(), Error> {
+    if ring.len() < 4 {
+        return Err(WktError::TooFewPositions.into());
+    }
+    if ring.first() != ring.last() {
+        return Err(WktError::UnclosedRing.into());
+    }
+    Ok(())
+}
+
+impl Geometry {
+    /// Returns true when every position has an altitude
+    fn has_altitude(&self) -> bool {
+        let path = |path: &[DMS3d]| !path.is_empty() && path.iter().all(|c| c.altitude.is_some());
+        match self {
+            Self::Point(coords) => coords.altitude.is_some(),
+            Self::LineString(coords) => path(coords),
+            Self::Polygon(rings) => !rings.is_empty() && rings.iter().all(|ring| path(ring)),
+        }
+    }
+
+    /// Returns WKB geometry type, with no dimension
+    fn wkb_type(&self) -> u32 {
+        match self {
+            Self::Point(_) => 1,
+            Self::LineString(_) => 2,
+            Self::Polygon(_) => 3,
+        }
+    }
+
+    /// Encodes Self as ISO WKB
+    /// ```
+    /// use dms_coordinates::{DMS3d, wkt::{ByteOrder, Geometry}};
+    /// let point = Geometry::from(DMS3d::from_ddeg_angles(2.0, 1.0, None));
+    /// assert_eq!(
+    ///     point.to_wkb(ByteOrder::LittleEndian),
+    ///     [1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0, 0, 0, 0, 0, 0, 0, 0x40],
+    /// );
+    /// ```
+    pub fn to_wkb(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let z = self.has_altitude();
+        let mut writer = WkbWriter::new(byte_order);
+        writer.u32(self.wkb_type() + if z { 1000 } else { 0 });
+        writer.geometry(self, z);
+        writer.bytes
+    }
+
+    /// Encodes Self as PostGIS EWKB, with an optionnal SRID, like 4326 for WGS84
+    pub fn to_ewkb(&self, byte_order: ByteOrder, srid: Option<u32>) -> Vec<u8> {
+        let z = self.has_altitude();
+        let mut writer = WkbWriter::new(byte_order);
+        let mut wkb_type = self.wkb_type();
+        if z {
+            wkb_type |= EWKB_Z;
+        }
+        if srid.is_some() {
+            wkb_type |= EWKB_SRID;
+        }
+        writer.u32(wkb_type);
+        if let Some(srid) = srid {
+            writer.u32(srid);
+        }
+        writer.geometry(self, z);
+        writer.bytes
+    }
+
+    /// Decodes an ISO WKB or EWKB geometry, the SRID being dropped
+    pub fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_ewkb(bytes).map(|(geometry, _)| geometry)
+    }
+
+    /// Decodes an ISO WKB or EWKB geometry, and its SRID if any
+    pub fn from_ewkb(bytes: &[u8]) -> Result<(Self, Option<u32>), Error> {
+        let mut reader = WkbReader {
+            bytes,
+            big_endian: false,
+        };
+        reader.big_endian = match reader.take::<1>()? {
+            [0] => true,
+            [1] => false,
+            _ => return Err(WktError::InvalidByteOrder.into()),
+        };
+        let wkb_type = reader.u32()?;
+        let (base, z, srid) = if wkb_type & (EWKB_Z | EWKB_M | EWKB_SRID) != 0 {
+            if wkb_type & EWKB_M != 0 {
+                return Err(WktError::UnsupportedGeometry.into());
+            }
+            let srid = if wkb_type & EWKB_SRID != 0 {
+                Some(reader.u32()?)
+            } else {
+                None
+            };
+            (wkb_type & 0x0fff_ffff, wkb_type & EWKB_Z != 0, srid)
+        } else {
+            match wkb_type / 1000 {
+                0 => (wkb_type, false, None),
+                1 => (wkb_type % 1000, true, None),
+                _ => return Err(WktError::UnsupportedGeometry.into()),
+            }
+        };
+        let geometry = match base {
+            1 => {
+                // empty points are encoded with NaN coordinates
+                let bytes = reader.bytes;
+                let (longitude, latitude) = (reader.f64()?, reader.f64()?);
+                if longitude.is_nan() && latitude.is_nan() {
+                    return Err(WktError::UnsupportedGeometry.into());
+                }
+                reader.bytes = bytes;
+                Self::Point(reader.position(z)?)
+            }
+            2 => {
+                let path = reader.path(z)?;
+                validate_path(&path)?;
+                Self::LineString(path)
+            }
+            3 => {
+                let count = reader.u32()?;
+                let mut rings = Vec::new();
+                for _ in 0..count {
+                    let ring = reader.path(z)?;
+                    validate_ring(&ring)?;
+                    rings.push(ring);
+                }
+                Self::Polygon(rings)
+            }
+            _ => return Err(WktError::UnsupportedGeometry.into()),
+        };
+        if !reader.bytes.is_empty() {
+            return Err(WktError::InvalidLength.into());
+        }
+        Ok((geometry, srid))
+    }
+}
+
+/// WKB encoder
+struct WkbWriter {
+    bytes: Vec<u8>,
+    byte_order: ByteOrder,
+}
+
+impl WkbWriter {
+    /// Starts a geometry, with its byte order flag
+    fn new(byte_order: ByteOrder) -> Self {
+        let flag = match byte_order {
+            ByteOrder::BigEndian => 0,
+            ByteOrder::LittleEndian => 1,
+        };
+        Self {
+            bytes: vec![flag],
+            byte_order,
+        }
+    }
+    fn u32(&mut self, value: u32) {
+        match self.byte_order {
+            ByteOrder::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            ByteOrder::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+    fn f64(&mut self, value: f64) {
+        match self.byte_order {
+            ByteOrder::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            ByteOrder::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+    fn position(&mut self, coords: &DMS3d, z: bool) {
+        self.f64(coords.longitude.to_ddeg_angle());
+        self.f64(coords.latitude.to_ddeg_angle());
+        if z {
+            self.f64(coords.altitude.unwrap_or(0.0));
+        }
+    }
+    fn path(&mut self, path: &[DMS3d], z: bool) {
+        self.u32(path.len() as u32);
+        for coords in path {
+            self.position(coords, z);
+        }
+    }
+    /// Writes the body of given geometry
+    fn geometry(&mut self, geometry: &Geometry, z: bool) {
+        match geometry {
+            Geometry::Point(coords) => self.position(coords, z),
+            Geometry::LineString(path) => self.path(path, z),
+            Geometry::Polygon(rings) => {
+                self.u32(rings.len() as u32);
+                for ring in rings {
+                    self.path(ring, z);
+                }
+            }
+        }
+    }
+}
+
+/// WKB decoder, consuming its bytes
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        if self.bytes.len() < N {
+            return Err(WktError::InvalidLength.into());
+        }
+        let (value, bytes) = self.bytes.split_at(N);
+        self.bytes = bytes;
+        let mut array = [0_u8; N];
+        array.copy_from_slice(value);
+        Ok(array)
+    }
+    fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take::<4>()?;
+        Ok(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+    fn f64(&mut self) -> Result<f64, Error> {
+        let bytes = self.take::<8>()?;
+        Ok(if self.big_endian {
+            f64::from_be_bytes(bytes)
+        } else {
+            f64::from_le_bytes(bytes)
+        })
+    }
+    fn position(&mut self, z: bool) -> Result<DMS3d, Error> {
+        let (longitude, latitude) = (self.f64()?, self.f64()?);
+        let altitude = if z { Some(self.f64()?) } else { None };
+        coords(longitude, latitude, altitude)
+    }
+    fn path(&mut self, z: bool) -> Result<Vec<DMS3d>, Error> {
+        let count = self.u32()? as usize;
+        // each position takes 16 or 24 bytes
+        if count > self.bytes.len() / 16 {
+            return Err(WktError::InvalidLength.into());
+        }
+        (0..count).map(|_| self.position(z)).collect()
+    }
+}
+
+/// Writes `lon lat [alt]` positions, comma separated
+fn write_path(f: &mut core::fmt::Formatter, path: &[DMS3d], z: bool) -> core::fmt::Result {
+    f.write_str("(")?;
+    for (i, coords) in path.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(
+            f,
+            "{} {}",
+            coords.longitude.to_ddeg_angle(),
+            coords.latitude.to_ddeg_angle()
+        )?;
+        if z {
+            write!(f, " {}", coords.altitude.unwrap_or(0.0))?;
+        }
+    }
+    f.write_str(")")
+}
+
+impl core::fmt::Display for Geometry {
+    /// Formats Self as WKT, like `POINT Z (2.2945 48.858222 330)`
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let z = self.has_altitude();
+        f.write_str(match self {
+            Self::Point(_) => "POINT",
+            Self::LineString(_) => "LINESTRING",
+            Self::Polygon(_) => "POLYGON",
+        })?;
+        f.write_str(if z { " Z " } else { " " })?;
+        match self {
+            Self::Point(coords) => write_path(f, core::slice::from_ref(coords), z),
+            Self::LineString(path) if path.is_empty() => f.write_str("EMPTY"),
+            Self::LineString(path) => write_path(f, path, z),
+            Self::Polygon(rings) if rings.is_empty() => f.write_str("EMPTY"),
+            Self::Polygon(rings) => {
+                f.write_str("(")?;
+                for (i, ring) in rings.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_path(f, ring, z)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// WKT decoder, consuming its text
+struct WktReader<'a> {
+    text: &'a str,
+}
+
+impl<'a> WktReader<'a> {
+    /// Consumes given character, after optionnal whitespaces
+    fn consume(&mut self, c: char) -> bool {
+        self.text = self.text.trim_start();
+        match self.text.strip_prefix(c) {
+            Some(text) => {
+                self.text = text;
+                true
+            }
+            None => false,
+        }
+    }
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            Err(WktError::InvalidText.into())
+        }
+    }
+    /// Consumes a word, or a number, after optionnal whitespaces
+    fn token(&mut self) -> &'a str {
+        self.text = self.text.trim_start();
+        let end = self
+            .text
+            .find(|c: char| c.is_whitespace() || "(),".contains(c))
+            .unwrap_or(self.text.len());
+        let (token, text) = self.text.split_at(end);
+        self.text = text;
+        token
+    }
+    /// Consumes a `lon lat [alt]` position, altitude being mandatory when `z` is set
+    fn position(&mut self, z: bool) -> Result<DMS3d, Error> {
+        let mut values = [0.0_f64; 3];
+        let mut count = 0;
+        loop {
+            let token = self.token();
+            if token.is_empty() {
+                break;
+            }
+            if count == values.len() {
+                return Err(WktError::InvalidText.into());
+            }
+            values[count] = token.parse().map_err(|_| WktError::InvalidText)?;
+            count += 1;
+        }
+        match (count, z) {
+            (2, false) => coords(values[0], values[1], None),
+            (3, _) => coords(values[0], values[1], Some(values[2])),
+            _ => Err(WktError::InvalidText.into()),
+        }
+    }
+    /// Consumes a parenthesized list of positions
+    fn path(&mut self, z: bool) -> Result<Vec<DMS3d>, Error> {
+        self.expect('(')?;
+        let mut path = vec![self.position(z)?];
+        while self.consume(',') {
+            path.push(self.position(z)?);
+        }
+        self.expect(')')?;
+        Ok(path)
+    }
+}
+
+impl core::str::FromStr for Geometry {
+    type Err = Error;
+    /// Parses a WKT geometry, like `POINT Z (2.2945 48.858222 330)`, case insensitive.
+    /// Positions of three values are accepted without the Z marker.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut reader = WktReader { text: s };
+        let kind = reader.token();
+        let mut token = reader.token();
+        let z = token.eq_ignore_ascii_case("Z");
+        if z {
+            token = reader.token();
+        } else if token.eq_ignore_ascii_case("M") || token.eq_ignore_ascii_case("ZM") {
+            return Err(WktError::UnsupportedGeometry.into());
+        }
+        let empty = token.eq_ignore_ascii_case("EMPTY");
+        if !empty && !token.is_empty() {
+            return Err(WktError::InvalidText.into());
+        }
+        let geometry = if kind.eq_ignore_ascii_case("POINT") {
+            if empty {
+                return Err(WktError::UnsupportedGeometry.into());
+            }
+            reader.expect('(')?;
+            let coords = reader.position(z)?;
+            reader.expect(')')?;
+            Self::Point(coords)
+        } else if kind.eq_ignore_ascii_case("LINESTRING") {
+            let path = if empty { Vec::new() } else { reader.path(z)? };
+            validate_path(&path)?;
+            Self::LineString(path)
+        } else if kind.eq_ignore_ascii_case("POLYGON") {
+            let mut rings = Vec::new();
+            if !empty {
+                reader.expect('(')?;
+                loop {
+                    let ring = reader.path(z)?;
+                    validate_ring(&ring)?;
+                    rings.push(ring);
+                    if !reader.consume(',') {
+                        break;
+                    }
+                }
+                reader.expect(')')?;
+            }
+            Self::Polygon(rings)
+        } else if kind.is_empty() {
+            return Err(WktError::InvalidText.into());
+        } else {
+            return Err(WktError::UnsupportedGeometry.into());
+        };
+        if !reader.text.trim().is_empty() {
+            return Err(WktError::InvalidText.into());
+        }
+        Ok(geometry)
+    }
+}
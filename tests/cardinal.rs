@@ -109,4 +109,14 @@ mod tests {
         assert_eq!(Cardinal::North + 360 + 180, Cardinal::South);
         assert_eq!(Cardinal::NorthEast + 360 + 180, Cardinal::SouthWest);
     }
+    #[test]
+    fn test_nearest() {
+        assert_eq!(Cardinal::nearest(0.0), Cardinal::North);
+        assert_eq!(Cardinal::nearest(22.4), Cardinal::North);
+        assert_eq!(Cardinal::nearest(22.6), Cardinal::NorthEast);
+        assert_eq!(Cardinal::nearest(260.1), Cardinal::West);
+        assert_eq!(Cardinal::nearest(350.0), Cardinal::North);
+        assert_eq!(Cardinal::nearest(-45.0), Cardinal::NorthWest);
+        assert_eq!(Cardinal::nearest(540.0), Cardinal::South);
+    }
 }
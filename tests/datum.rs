@@ -0,0 +1,104 @@
+use dms_coordinates::{
+    datum::{Helmert, Transformation},
+    Cardinal, DMS3d, Datum,
+};
+
+#[cfg(test)]
+mod datum {
+    use super::*;
+    #[test]
+    fn test_helmert_inverse() {
+        let helmert = Helmert::new(446.448, -125.157, 542.060, 0.1502, 0.2470, 0.8421, -20.4894);
+        let (x, y, z) = (3_980_581.0, -111.0, 4_966_825.0);
+        let (x1, y1, z1) = helmert.apply(x, y, z);
+        assert!((x1 - x).abs() > 100.0);
+        let (x2, y2, z2) = helmert.apply_inverse(x1, y1, z1);
+        assert!((x2 - x).abs() < 1E-6);
+        assert!((y2 - y).abs() < 1E-6);
+        assert!((z2 - z).abs() < 1E-6);
+        let identity = Helmert::default();
+        assert_eq!(identity.apply(x, y, z), (x, y, z));
+    }
+    #[test]
+    fn test_shifts() {
+        // (datum, WGS84 coordinates, expected shift in arc seconds (lat, lon))
+        let cases = [
+            (Datum::ED50, (48.8566, 2.3522, 100.0), (3.292, 4.628)),
+            (Datum::OSGB36, (51.477811, -0.001475, 46.0), (-1.857, 5.831)),
+            (Datum::NAD27, (40.0, -100.0, 0.0), (-0.034, 1.503)),
+            (Datum::TOKYO, (35.68, 139.76, 0.0), (-11.761, 11.590)),
+            (Datum::AGD66, (-33.87, 151.21, 0.0), (-5.696, -4.129)),
+        ];
+        for (datum, coords, (dlat, dlon)) in cases {
+            let shifted = Datum::WGS84.transform(&datum, coords, Transformation::Helmert);
+            assert!(((shifted.0 - coords.0) * 3600.0 - dlat).abs() < 1E-3);
+            assert!(((shifted.1 - coords.1) * 3600.0 - dlon).abs() < 1E-3);
+            // both directions
+            let back = datum.transform(&Datum::WGS84, shifted, Transformation::Helmert);
+            assert!((back.0 - coords.0).abs() < 1E-10);
+            assert!((back.1 - coords.1).abs() < 1E-10);
+            assert!((back.2 - coords.2).abs() < 1E-6);
+        }
+    }
+    #[test]
+    fn test_molodensky() {
+        // Molodensky agrees with Helmert for 3 parameter datums
+        for datum in [Datum::ED50, Datum::NAD27, Datum::TOKYO, Datum::AGD66] {
+            let coords = (45.0, 10.0, 250.0);
+            let helmert = Datum::WGS84.transform(&datum, coords, Transformation::Helmert);
+            let molodensky = Datum::WGS84.transform(&datum, coords, Transformation::Molodensky);
+            assert!((helmert.0 - molodensky.0).abs() * 3600.0 < 0.01);
+            assert!((helmert.1 - molodensky.1).abs() * 3600.0 < 0.01);
+            assert!((helmert.2 - molodensky.2).abs() < 0.1);
+            let back = datum.transform(&Datum::WGS84, molodensky, Transformation::Molodensky);
+            assert!((back.0 - coords.0).abs() * 3600.0 < 0.01);
+            assert!((back.1 - coords.1).abs() * 3600.0 < 0.01);
+        }
+        // between two datums other than WGS84
+        let coords = (35.0, 135.0, 0.0);
+        let helmert = Datum::TOKYO.transform(&Datum::ED50, coords, Transformation::Helmert);
+        let molodensky = Datum::TOKYO.transform(&Datum::ED50, coords, Transformation::Molodensky);
+        assert!((helmert.0 - molodensky.0).abs() * 3600.0 < 0.05);
+        assert!((helmert.1 - molodensky.1).abs() * 3600.0 < 0.05);
+        // longitudes are wrapped across the antimeridian
+        let coords = (35.0, -179.99999, 0.0);
+        let (_, longitude, _) =
+            Datum::TOKYO.transform(&Datum::WGS84, coords, Transformation::Molodensky);
+        assert!((-180.0..=180.0).contains(&longitude));
+        assert!(longitude > 179.99);
+        // and the poles are well defined
+        for datum in [Datum::ED50, Datum::NAD27] {
+            for coords in [(90.0, 10.0, 0.0), (-90.0, -10.0, 0.0)] {
+                let (latitude, longitude, height) =
+                    Datum::WGS84.transform(&datum, coords, Transformation::Molodensky);
+                assert!((-90.0..=90.0).contains(&latitude));
+                assert!((-180.0..=180.0).contains(&longitude));
+                assert!(height.is_finite());
+            }
+        }
+    }
+    #[test]
+    fn test_dms3d() {
+        let coords = DMS3d::from_ddeg_angles(48.8566, 2.3522, Some(100.0));
+        let ed50 = coords.to_europe50().unwrap();
+        assert_eq!(ed50.latitude.cardinal, Some(Cardinal::North));
+        assert_eq!(ed50.longitude.cardinal, Some(Cardinal::East));
+        assert!(((ed50.latitude.to_ddeg_angle() - 48.8566) * 3600.0 - 3.292).abs() < 1E-3);
+        assert!(((ed50.longitude.to_ddeg_angle() - 2.3522) * 3600.0 - 4.628).abs() < 1E-3);
+        assert!((ed50.altitude.unwrap() - 51.702).abs() < 1E-3);
+        let back = ed50.transform(Datum::ED50, Datum::WGS84, Transformation::Helmert);
+        assert!((back.latitude.to_ddeg_angle() - 48.8566).abs() < 1E-10);
+        assert!((back.longitude.to_ddeg_angle() - 2.3522).abs() < 1E-10);
+        assert!((back.altitude.unwrap() - 100.0).abs() < 1E-6);
+        // undefined altitude remains undefined
+        let coords = DMS3d::from_ddeg_angles(35.68, 139.76, None);
+        let tokyo = coords.transform(Datum::WGS84, Datum::TOKYO, Transformation::Molodensky);
+        assert_eq!(tokyo.altitude, None);
+        assert!(((tokyo.latitude.to_ddeg_angle() - 35.68) * 3600.0 - -11.76).abs() < 0.01);
+        // western longitudes
+        let coords = DMS3d::from_ddeg_angles(40.0, -100.0, None);
+        let nad27 = coords.transform(Datum::NAD83, Datum::NAD27, Transformation::Helmert);
+        assert_eq!(nad27.longitude.cardinal, Some(Cardinal::West));
+        assert!(((nad27.longitude.to_ddeg_angle() + 100.0) * 3600.0 - 1.503).abs() < 1E-3);
+    }
+}
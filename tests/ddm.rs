@@ -0,0 +1,167 @@
+use dms_coordinates::{Cardinal, Error, Normalization, DDM, DMS};
+
+#[cfg(test)]
+#[macro_use]
+extern crate assert_float_eq;
+mod ddm {
+    use super::*;
+    #[test]
+    fn constructor() {
+        let ddm = DDM::new(40, 43.8366, Some(Cardinal::North));
+        assert_eq!(ddm.degrees, 40);
+        assert_float_relative_eq!(ddm.minutes, 43.8366, 1E-9);
+        assert_eq!(ddm.cardinal, Some(Cardinal::North));
+
+        let ddm = DDM::new(40, 61.5, None);
+        assert_eq!(ddm.degrees, 41);
+        assert_float_relative_eq!(ddm.minutes, 1.5, 1E-9);
+
+        // same default policy as DMS
+        let ddm = DDM::new(95, 30.0, Some(Cardinal::North));
+        assert_eq!(ddm.degrees, 84);
+        assert_float_relative_eq!(ddm.minutes, 30.0, 1E-9);
+        assert_eq!(ddm.cardinal, Some(Cardinal::North));
+        let ddm = DDM::new(190, 0.0, Some(Cardinal::East));
+        assert_eq!(ddm.degrees, 170);
+        assert_eq!(ddm.cardinal, Some(Cardinal::West));
+        let ddm = DDM::new_with(95, 30.0, Some(Cardinal::North), Normalization::Clamp).unwrap();
+        assert_eq!(ddm.degrees, 90);
+        assert_float_relative_eq!(ddm.minutes, 0.0, 1E-9);
+        assert!(matches!(
+            DDM::new_with(95, 30.0, Some(Cardinal::North), Normalization::Reject),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            DDM::new_with(361, 0.0, None, Normalization::Reject),
+            Err(Error::InvalidAngle)
+        ));
+        let ddm = DDM::new_with(40, 43.8366, Some(Cardinal::North), Normalization::Reject);
+        assert_eq!(ddm.unwrap(), DDM::new(40, 43.8366, Some(Cardinal::North)));
+    }
+    #[test]
+    fn test_to_string() {
+        let ddm = DDM::new(40, 43.8366, Some(Cardinal::North));
+        assert_eq!(ddm.to_string(), "40°43.8366'N");
+        let ddm: DDM = "N40°43.8366'".parse().unwrap();
+        assert_eq!(ddm.degrees, 40);
+        assert_float_relative_eq!(ddm.minutes, 43.8366, 1E-9);
+        assert_eq!(ddm.cardinal, Some(Cardinal::North));
+    }
+    #[test]
+    fn test_dms_conversion() {
+        let dms = DMS::new(40, 43, 50.196, Some(Cardinal::North));
+        let ddm = DDM::from(dms);
+        assert_eq!(ddm.degrees, 40);
+        assert_float_relative_eq!(ddm.minutes, 43.8366, 1E-9);
+        let back = DMS::from(ddm);
+        assert_eq!(back.degrees, 40);
+        assert_eq!(back.minutes, 43);
+        assert_float_relative_eq!(back.seconds, 50.196, 1E-9);
+        assert_eq!(back.cardinal, Some(Cardinal::North));
+    }
+    #[test]
+    fn test_ddeg() {
+        let ddm = DDM::from_ddeg_longitude(-73.935242);
+        assert_eq!(ddm.degrees, 73);
+        assert_float_relative_eq!(ddm.minutes, 56.11452, 1E-6);
+        assert_eq!(ddm.cardinal, Some(Cardinal::West));
+        assert_float_relative_eq!(ddm.to_ddeg_angle(), -73.935242, 1E-9);
+        let ddeg: f64 = ddm.into();
+        assert_float_relative_eq!(ddeg, -73.935242, 1E-9);
+
+        let ddm = DDM::from_ddeg_latitude(-3.5);
+        assert_eq!(ddm.cardinal, Some(Cardinal::South));
+        assert_float_relative_eq!(ddm.total_minutes(), 210.0, 1E-9);
+    }
+    #[test]
+    fn test_ops() {
+        let d0 = DDM::new(10, 21.75, None);
+        let d1 = DDM::new(0, 10.25, None);
+        let d = (d0 + d1).unwrap();
+        assert_eq!(d.degrees, 10);
+        assert_float_relative_eq!(d.minutes, 32.0, 1E-9);
+
+        let d0 = DDM::new(10, 30.0, Some(Cardinal::North));
+        let d1 = DDM::new(1, 0.0, Some(Cardinal::East));
+        assert!(matches!(d0 + d1, Err(Error::IncompatibleCardinals)));
+
+        let d = DDM::new(10, 30.0, Some(Cardinal::North)) + 1.0;
+        assert_eq!(d.degrees, 11);
+        assert_float_relative_eq!(d.minutes, 30.0, 1E-9);
+        let mut d = d - 0.5;
+        assert_eq!(d.degrees, 11);
+        assert_float_relative_eq!(d.minutes, 0.0, 1E-9);
+        d *= 2.0;
+        assert_eq!(d.degrees, 22);
+        d /= 4.0;
+        assert_eq!(d.degrees, 5);
+        assert_float_relative_eq!(d.minutes, 30.0, 1E-9);
+
+        // signed like DMS
+        let dms = -DMS::new(10, 30, 0.0, None);
+        let d = DDM::from(dms);
+        assert_eq!(d.degrees, 10);
+        assert_float_relative_eq!(d.minutes, 30.0, 1E-9);
+        assert!(d.is_negative());
+        assert_eq!(d.to_string(), "-10°30.0000'");
+        assert_float_relative_eq!(d.to_ddeg_angle(), -10.5, 1E-9);
+        assert_eq!(DMS::from(d), dms);
+        let d = DDM::new(10, 0.0, None) - 20.0 * 3600.0;
+        assert_eq!(d.degrees, 10);
+        assert!(d.is_negative());
+        let d = DDM::from_minutes(-90.0);
+        assert_eq!(d.degrees, 1);
+        assert!(d.is_negative());
+        let d = DDM::new(0, -30.0, Some(Cardinal::North));
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        assert!(d.is_negative());
+        assert_eq!("-10.5".parse::<DDM>().unwrap(), DDM::from_ddeg_angle(-10.5));
+    }
+    #[test]
+    fn test_neg_sub_ops() {
+        let d = -DDM::new(10, 30.0, None);
+        assert_eq!(d.degrees, 10);
+        assert_float_relative_eq!(d.minutes, 30.0, 1E-9);
+        assert!(d.is_negative());
+        assert_eq!(-d, DDM::new(10, 30.0, None));
+        let d = -DDM::new(40, 43.8366, Some(Cardinal::North));
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        assert_float_relative_eq!(d.minutes, 43.8366, 1E-9);
+
+        // angles with no cardinal may turn negative
+        let d = (DDM::new(10, 15.0, None) - DDM::new(20, 45.0, None)).unwrap();
+        assert_eq!(d.degrees, 10);
+        assert_float_relative_eq!(d.minutes, 30.0, 1E-9);
+        assert!(d.is_negative());
+
+        let d0 = DDM::new(10, 30.0, Some(Cardinal::North));
+        let d = (d0 - DDM::new(20, 0.0, Some(Cardinal::North))).unwrap();
+        assert_eq!(d.degrees, 9);
+        assert_float_relative_eq!(d.minutes, 30.0, 1E-9);
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d1 = DDM::new(1, 0.0, Some(Cardinal::East));
+        assert!(matches!(d0 - d1, Err(Error::IncompatibleCardinals)));
+
+        let mut d = DDM::new(10, 30.0, Some(Cardinal::North));
+        d -= DDM::new(0, 45.0, Some(Cardinal::North));
+        assert_eq!(d.degrees, 9);
+        assert_float_relative_eq!(d.minutes, 45.0, 1E-9);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        // left untouched when cardinals are not compatible
+        d -= d1;
+        assert_eq!(d.degrees, 9);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+    }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        for d in [
+            DDM::new(40, 43.8366, Some(Cardinal::North)),
+            DDM::from_ddeg_longitude(-73.935242),
+            DDM::from_ddeg_angle(-10.5),
+        ] {
+            let json = serde_json::to_string(&d).unwrap();
+            assert_eq!(serde_json::from_str::<DDM>(&json).unwrap(), d);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use dms_coordinates::{Cardinal, DMS};
+use dms_coordinates::{dms::ParseError, Cardinal, Error, Normalization, DMS};
 
 #[cfg(test)]
 #[macro_use]
@@ -61,6 +61,13 @@ mod dms {
         assert_eq!(d.minutes, 21);
         assert_float_relative_eq!(d.seconds, 25.254, 1E-6);
         assert_eq!(d.cardinal, None);
+        // poles and antimeridian are preserved
+        let d = DMS::from_ddeg_latitude(-90.0);
+        assert_eq!(d.degrees, 90);
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = DMS::from_ddeg_longitude(180.0);
+        assert_eq!(d.degrees, 180);
+        assert_eq!(d.cardinal, Some(Cardinal::East));
     }
     #[test]
     fn test_to_ddeg() {
@@ -94,4 +101,427 @@ mod dms {
         assert_eq!(d.seconds, 55.0);
         assert_eq!(d.cardinal, None);
     }
+    #[test]
+    fn test_from_str() {
+        let d: DMS = "40°43'50.196\"N".parse().unwrap();
+        assert_eq!(d.degrees, 40);
+        assert_eq!(d.minutes, 43);
+        assert_float_relative_eq!(d.seconds, 50.196, 1E-9);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+
+        for s in [
+            "40°43'50.196\"N",
+            "40°43′50.196″N",
+            "40°43'50.196''n",
+            "40 43 50.196 N",
+            "40:43:50.196N",
+            "N40°43.8366'",
+            "n 40.730610",
+        ] {
+            let d: DMS = s.parse().unwrap();
+            assert_eq!(d.cardinal, Some(Cardinal::North), "{}", s);
+            assert_float_relative_eq!(d.to_ddeg_angle(), 40.730610, 1E-8);
+        }
+
+        let d: DMS = "73°56'6.871\"w".parse().unwrap();
+        assert_eq!(d.cardinal, Some(Cardinal::West));
+        assert_float_relative_eq!(d.to_ddeg_angle(), -73.935242, 1E-6);
+
+        let d: DMS = "39°40'43.0000\"SW".parse().unwrap();
+        assert_eq!(d, DMS::new(39, 40, 43.0, Some(Cardinal::SouthWest)));
+
+        let d: DMS = "35°39'10.0000\"".parse().unwrap();
+        assert_eq!(d, DMS::new(35, 39, 10.0, None));
+
+        let d: DMS = "-40.730610".parse().unwrap();
+        assert_eq!(d.cardinal, None);
+        assert!(d.is_negative());
+        assert_float_relative_eq!(d.to_ddeg_angle(), -40.730610, 1E-8);
+        assert_eq!("-10.5".parse::<DMS>().unwrap(), DMS::from_ddeg_angle(-10.5));
+        assert!(!"-0".parse::<DMS>().unwrap().is_negative());
+    }
+    #[test]
+    fn test_from_str_display_roundtrip() {
+        let d = DMS::new(12, 7, 3.25, Some(Cardinal::East));
+        let parsed: DMS = d.to_string().parse().unwrap();
+        assert_eq!(parsed.degrees, d.degrees);
+        assert_eq!(parsed.minutes, d.minutes);
+        assert_float_relative_eq!(parsed.seconds, d.seconds, 1E-6);
+        assert_eq!(parsed.cardinal, d.cardinal);
+        // negative angles with no cardinal
+        let d = -DMS::new(10, 30, 0.0, None);
+        assert_eq!(d.to_string(), "-10°30'0.0000\"");
+        assert_eq!(d.to_string().parse::<DMS>().unwrap(), d);
+    }
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("".parse::<DMS>(), Err(ParseError::EmptyString));
+        assert_eq!("  ".parse::<DMS>(), Err(ParseError::EmptyString));
+        assert_eq!("N".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("40.5°43'".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("95°N".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("181°0'0\"E".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        // range is checked before wrapping
+        assert_eq!("400N".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("400".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("-400".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("200.5E".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("90°0'1\"N".parse::<DMS>(), Err(ParseError::InvalidDegrees));
+        assert_eq!("40°61'N".parse::<DMS>(), Err(ParseError::InvalidMinutes));
+        assert_eq!(
+            "40°43'60\"N".parse::<DMS>(),
+            Err(ParseError::InvalidSeconds)
+        );
+        assert_eq!("40°50\"N".parse::<DMS>(), Err(ParseError::InvalidMinutes));
+        assert_eq!("40°43'X".parse::<DMS>(), Err(ParseError::InvalidCardinal));
+        assert_eq!("N40°43'S".parse::<DMS>(), Err(ParseError::InvalidCardinal));
+        assert_eq!("-40°43'N".parse::<DMS>(), Err(ParseError::SignAndCardinal));
+        assert_eq!(
+            "40°43'N 10".parse::<DMS>(),
+            Err(ParseError::FormatNotRecognized)
+        );
+        assert_eq!("40;43".parse::<DMS>(), Err(ParseError::FormatNotRecognized));
+    }
+    #[test]
+    fn test_default_normalization() {
+        // latitudes fold over the poles
+        let d = DMS::from_ddeg_latitude(95.0);
+        assert_eq!((d.degrees, d.minutes), (85, 0));
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d = DMS::from_ddeg_latitude(-100.5);
+        assert_eq!((d.degrees, d.minutes), (79, 30));
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = DMS::from_ddeg_latitude(185.0);
+        assert_eq!(d.degrees, 5);
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = DMS::from_ddeg_latitude(450.0);
+        assert_eq!(d.degrees, 90);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        // longitudes wrap across the antimeridian
+        let d = DMS::from_ddeg_longitude(190.0);
+        assert_eq!(d.degrees, 170);
+        assert_eq!(d.cardinal, Some(Cardinal::West));
+        let d = DMS::from_ddeg_longitude(-181.0);
+        assert_eq!(d.degrees, 179);
+        assert_eq!(d.cardinal, Some(Cardinal::East));
+        let d = DMS::from_ddeg_longitude(540.0);
+        assert_eq!(d.degrees, 180);
+        assert_eq!(d.cardinal, Some(Cardinal::East));
+        let d = DMS::from_ddeg_longitude(-180.0);
+        assert_eq!(d.degrees, 180);
+        assert_eq!(d.cardinal, Some(Cardinal::West));
+        // angles wrap at 360°, keeping their sign
+        let d = DMS::from_seconds(-3600.0);
+        assert_eq!((d.degrees, d.minutes), (1, 0));
+        assert!(d.is_negative());
+        let d = DMS::from_seconds(-361.0 * 3600.0);
+        assert_eq!(d.degrees, 1);
+        assert!(d.is_negative());
+        assert_eq!(DMS::from_seconds(360.0 * 3600.0).degrees, 0);
+        assert_eq!(DMS::from_ddeg_angle(370.5).degrees, 10);
+        // constructor applies the same policy
+        let d = DMS::new(95, 0, 0.0, Some(Cardinal::North));
+        assert_eq!(d, DMS::from_ddeg_latitude(95.0));
+        assert_eq!(d.degrees, 85);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d = DMS::new(190, 30, 0.0, Some(Cardinal::West));
+        assert_eq!((d.degrees, d.minutes), (169, 30));
+        assert_eq!(d.cardinal, Some(Cardinal::East));
+        let d = DMS::new(400, 0, 0.0, Some(Cardinal::North));
+        assert_eq!(d.degrees, 40);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d = DMS::new(400, 0, 0.0, None);
+        assert_eq!(d.degrees, 40);
+        let d = DMS::new(90, 0, 0.0, Some(Cardinal::North));
+        assert_eq!(d.degrees, 90);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d = DMS::new(0, 0, -36.0, Some(Cardinal::North));
+        assert_eq!((d.degrees, d.minutes), (0, 0));
+        assert!((d.seconds - 36.0).abs() < 1E-6);
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        // arithmetics
+        let d = DMS::new(80, 0, 0.0, Some(Cardinal::North)) + 15.0;
+        assert_eq!(d.degrees, 85);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d = DMS::new(170, 0, 0.0, Some(Cardinal::East)) + 20.0;
+        assert_eq!(d.degrees, 170);
+        assert_eq!(d.cardinal, Some(Cardinal::West));
+        let d = DMS::new(60, 0, 0.0, Some(Cardinal::South)) * 2.0;
+        assert_eq!(d.degrees, 60);
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = DMS::new(10, 0, 0.0, None) - 20.0 * 3600.0;
+        assert_eq!(d.degrees, 10);
+        assert!(d.is_negative());
+        let d = DMS::new(350, 0, 0.0, None) + 20.0 * 3600.0;
+        assert_eq!(d.degrees, 10);
+        assert!(!d.is_negative());
+        let d = (DMS::new(60, 0, 0.0, Some(Cardinal::North))
+            + DMS::new(40, 0, 0.0, Some(Cardinal::North)))
+        .unwrap();
+        assert_eq!(d.degrees, 80);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+    }
+    #[test]
+    fn test_normalization_policies() {
+        assert_eq!(Normalization::default(), Normalization::Fold);
+        // values within range are left untouched, whatever the policy
+        for policy in [
+            Normalization::Wrap,
+            Normalization::Fold,
+            Normalization::Clamp,
+            Normalization::Reject,
+        ] {
+            assert_eq!(policy.latitude(-90.0).unwrap(), -90.0);
+            assert_eq!(policy.longitude(-180.0).unwrap(), -180.0);
+            assert_eq!(policy.longitude(180.0).unwrap(), 180.0);
+            assert_eq!(policy.angle(359.5).unwrap(), 359.5);
+            assert_eq!(policy.angle(-359.5).unwrap(), -359.5);
+        }
+        assert_eq!(Normalization::Wrap.latitude(95.0).unwrap(), -85.0);
+        assert_eq!(Normalization::Wrap.latitude(-95.0).unwrap(), 85.0);
+        assert_eq!(Normalization::Fold.latitude(95.0).unwrap(), 85.0);
+        assert_eq!(Normalization::Fold.latitude(-95.0).unwrap(), -85.0);
+        assert_eq!(Normalization::Clamp.latitude(95.0).unwrap(), 90.0);
+        assert_eq!(Normalization::Clamp.latitude(-95.0).unwrap(), -90.0);
+        assert!(matches!(
+            Normalization::Reject.latitude(95.0),
+            Err(Error::InvalidLatitude)
+        ));
+
+        for policy in [Normalization::Wrap, Normalization::Fold] {
+            assert_eq!(policy.longitude(190.0).unwrap(), -170.0);
+            assert_eq!(policy.longitude(-190.0).unwrap(), 170.0);
+            assert_eq!(policy.longitude(-540.0).unwrap(), 180.0);
+            assert_eq!(policy.angle(-370.0).unwrap(), -10.0);
+            assert_eq!(policy.angle(360.0).unwrap(), 0.0);
+            assert_eq!(policy.angle(725.0).unwrap(), 5.0);
+        }
+        assert_eq!(Normalization::Clamp.longitude(190.0).unwrap(), 180.0);
+        assert_eq!(Normalization::Clamp.longitude(-190.0).unwrap(), -180.0);
+        assert_eq!(Normalization::Clamp.angle(-370.0).unwrap(), -360.0);
+        assert_eq!(Normalization::Clamp.angle(370.0).unwrap(), 360.0);
+        assert_eq!(Normalization::Clamp.angle(360.0).unwrap(), 360.0);
+        assert!(matches!(
+            Normalization::Reject.longitude(180.5),
+            Err(Error::InvalidLongitude)
+        ));
+        assert!(matches!(
+            Normalization::Reject.angle(-360.5),
+            Err(Error::InvalidAngle)
+        ));
+        assert_eq!(Normalization::Reject.angle(360.0).unwrap(), 360.0);
+
+        // NaN and infinite angles never make it through
+        for policy in [
+            Normalization::Wrap,
+            Normalization::Fold,
+            Normalization::Clamp,
+            Normalization::Reject,
+        ] {
+            for angle in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                assert!(matches!(
+                    policy.latitude(angle),
+                    Err(Error::InvalidLatitude)
+                ));
+                assert!(matches!(
+                    policy.longitude(angle),
+                    Err(Error::InvalidLongitude)
+                ));
+                assert!(matches!(policy.angle(angle), Err(Error::InvalidAngle)));
+                assert!(policy.coordinates(angle, 0.0).is_err());
+            }
+        }
+    }
+    #[test]
+    fn test_normalized_constructors() {
+        let d = DMS::from_ddeg_latitude_with(95.0, Normalization::Wrap).unwrap();
+        assert_eq!(d.degrees, 85);
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = DMS::from_ddeg_latitude_with(95.0, Normalization::Clamp).unwrap();
+        assert_eq!(d.degrees, 90);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        assert!(matches!(
+            DMS::from_ddeg_latitude_with(-90.5, Normalization::Reject),
+            Err(Error::InvalidLatitude)
+        ));
+        let d = DMS::from_ddeg_longitude_with(-190.0, Normalization::Clamp).unwrap();
+        assert_eq!(d.degrees, 180);
+        assert_eq!(d.cardinal, Some(Cardinal::West));
+        assert!(matches!(
+            DMS::from_ddeg_longitude_with(190.0, Normalization::Reject),
+            Err(Error::InvalidLongitude)
+        ));
+        let d = DMS::from_seconds_with(-361.0 * 3600.0, Normalization::Wrap).unwrap();
+        assert_eq!((d.degrees, d.minutes), (1, 0));
+        assert!(d.is_negative());
+        let d = DMS::from_seconds_with(-361.0 * 3600.0, Normalization::Clamp).unwrap();
+        assert_eq!(d.degrees, 360);
+        assert!(d.is_negative());
+        assert!(matches!(
+            DMS::from_seconds_with(361.0 * 3600.0, Normalization::Reject),
+            Err(Error::InvalidAngle)
+        ));
+    }
+    #[test]
+    fn test_new_with() {
+        let d = DMS::new_with(95, 30, 0.0, Some(Cardinal::North), Normalization::Wrap).unwrap();
+        assert_eq!((d.degrees, d.minutes), (84, 30));
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = DMS::new_with(95, 30, 0.0, Some(Cardinal::North), Normalization::Clamp).unwrap();
+        assert_eq!((d.degrees, d.minutes), (90, 0));
+        assert!(matches!(
+            DMS::new_with(95, 30, 0.0, Some(Cardinal::North), Normalization::Reject),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            DMS::new_with(400, 0, 0.0, None, Normalization::Reject),
+            Err(Error::InvalidAngle)
+        ));
+        assert!(matches!(
+            DMS::new_with(10, 0, f64::NAN, Some(Cardinal::East), Normalization::Fold),
+            Err(Error::InvalidLongitude)
+        ));
+        // fields carry over before the range is checked
+        let d = DMS::new_with(89, 59, 60.0, Some(Cardinal::North), Normalization::Reject).unwrap();
+        assert_eq!((d.degrees, d.minutes, d.seconds), (90, 0, 0.0));
+        let d = DMS::new_with(40, 43, 50.196, Some(Cardinal::North), Normalization::Reject);
+        assert_eq!(d.unwrap(), DMS::new(40, 43, 50.196, Some(Cardinal::North)));
+    }
+    #[test]
+    fn test_normalize() {
+        let raw = DMS {
+            degrees: 95,
+            minutes: 30,
+            seconds: 0.0,
+            cardinal: Some(Cardinal::North),
+            negative: false,
+        };
+        let d = raw.normalize(Normalization::Fold).unwrap();
+        assert_eq!((d.degrees, d.minutes), (84, 30));
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d = raw.normalize(Normalization::Clamp).unwrap();
+        assert_eq!((d.degrees, d.minutes), (90, 0));
+        assert!(matches!(
+            raw.normalize(Normalization::Reject),
+            Err(Error::InvalidLatitude)
+        ));
+        // fields out of range are carried over
+        let mut raw = DMS::new(10, 0, 0.0, Some(Cardinal::West));
+        raw.minutes = 75;
+        let d = raw.normalize(Normalization::Reject).unwrap();
+        assert_eq!((d.degrees, d.minutes), (11, 15));
+        assert_eq!(d.cardinal, Some(Cardinal::West));
+        // valid angles are returned as is
+        let d = DMS::new(40, 43, 50.196, Some(Cardinal::North));
+        assert_eq!(d.normalize(Normalization::Reject).unwrap(), d);
+        let raw = DMS {
+            degrees: 365,
+            ..Default::default()
+        };
+        assert_eq!(raw.normalize(Normalization::Wrap).unwrap().degrees, 5);
+        assert!(matches!(
+            raw.normalize(Normalization::Reject),
+            Err(Error::InvalidAngle)
+        ));
+    }
+    #[test]
+    fn test_neg() {
+        let d = -DMS::new(10, 20, 30.0, None);
+        assert_eq!((d.degrees, d.minutes, d.seconds), (10, 20, 30.0));
+        assert!(d.is_negative());
+        assert_eq!(d.cardinal, None);
+        assert_float_relative_eq!(
+            d.to_ddeg_angle(),
+            -(10.0 + 20.0 / 60.0 + 30.0 / 3600.0),
+            1E-9
+        );
+        assert_eq!(-d, DMS::new(10, 20, 30.0, None));
+        // zero has no sign
+        assert!(!(-DMS::default()).is_negative());
+        // sign may be set on the struct directly
+        let lit = DMS {
+            degrees: 10,
+            minutes: 20,
+            seconds: 30.0,
+            cardinal: None,
+            negative: true,
+        };
+        assert_eq!(lit, d);
+        // cardinals are reversed
+        let d = -DMS::new(40, 43, 50.196, Some(Cardinal::North));
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        assert!(d.is_negative());
+        assert_eq!((-d).cardinal, Some(Cardinal::North));
+        // cardinal angles are signed by their cardinal only
+        let mut d = -DMS::new(10, 0, 0.0, None);
+        d.cardinal = Some(Cardinal::North);
+        assert!(!d.is_negative());
+        assert_eq!(d, DMS::new(10, 0, 0.0, Some(Cardinal::North)));
+        assert_eq!(d.to_ddeg_angle(), 10.0);
+        assert_eq!(
+            (-DMS::new(73, 56, 6.871, Some(Cardinal::West))).cardinal,
+            Some(Cardinal::East)
+        );
+        assert_eq!(
+            (-DMS::new(39, 40, 43.0, Some(Cardinal::SouthWest))).cardinal,
+            Some(Cardinal::NorthEast)
+        );
+    }
+    #[test]
+    fn test_sub_ops() {
+        let d0 = DMS::new(0, 20, 55.0, None);
+        let d1 = DMS::new(0, 3, 5.0, None);
+        let d = (d0 - d1).unwrap();
+        assert_eq!((d.degrees, d.minutes, d.seconds), (0, 17, 50.0));
+        assert!(!d.is_negative());
+        // going negative
+        let d = (d1 - d0).unwrap();
+        assert_eq!((d.degrees, d.minutes, d.seconds), (0, 17, 50.0));
+        assert!(d.is_negative());
+        assert_eq!(d.to_string(), "-0°17'50.0000\"");
+        assert_eq!(d.format("%-%D°%M'").to_string(), "-0°17'");
+        assert_eq!((d - d1).unwrap(), -d0);
+        assert_eq!((d0 - d0).unwrap(), DMS::default());
+        let mut d = d1;
+        d -= d0;
+        assert_eq!(d, (d1 - d0).unwrap());
+        d -= -d0;
+        assert_eq!(d, d1);
+
+        // latitudes and longitudes
+        let d0 = DMS::new(10, 0, 0.0, Some(Cardinal::North));
+        let d1 = DMS::new(20, 30, 0.0, Some(Cardinal::North));
+        let d = (d0 - d1).unwrap();
+        assert_eq!((d.degrees, d.minutes), (10, 30));
+        assert_eq!(d.cardinal, Some(Cardinal::South));
+        let d = (d0 - DMS::new(85, 0, 0.0, Some(Cardinal::South))).unwrap();
+        assert_eq!(d.degrees, 85);
+        assert_eq!(d.cardinal, Some(Cardinal::North));
+        let d0 = DMS::new(170, 0, 0.0, Some(Cardinal::West));
+        let d = (d0 - DMS::new(20, 0, 0.0, Some(Cardinal::East))).unwrap();
+        assert_eq!(d.degrees, 170);
+        assert_eq!(d.cardinal, Some(Cardinal::East));
+
+        // cardinals must be compatible, like (+)
+        let lat = DMS::new(10, 0, 0.0, Some(Cardinal::North));
+        let lon = DMS::new(10, 0, 0.0, Some(Cardinal::East));
+        assert!(matches!(lat - lon, Err(Error::IncompatibleCardinals)));
+        assert!(matches!(lat + lon, Err(Error::IncompatibleCardinals)));
+        let mut d = lat;
+        d -= lon;
+        assert_eq!(d, lat);
+    }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let d = -DMS::new(10, 20, 30.0, None);
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(serde_json::from_str::<DMS>(&json).unwrap(), d);
+        // sign defaults to positive
+        let d: DMS = serde_json::from_str(
+            "{\"degrees\":10,\"minutes\":20,\"seconds\":30.0,\"cardinal\":null}",
+        )
+        .unwrap();
+        assert_eq!(d, DMS::new(10, 20, 30.0, None));
+    }
 }
@@ -1,4 +1,4 @@
-use dms_coordinates::{Cardinal, DMS3d};
+use dms_coordinates::{dms::ParseError, Cardinal, DMS3d, Error, Normalization, DMS};
 
 #[cfg(test)]
 mod dms3d {
@@ -33,9 +33,10 @@ mod dms3d {
             2.3522219,
             Some(10.0),
         );
-        let expected_km = 5831.0_f64;
+        // geodesic distance on the WGS84 ellipsoid
+        let expected_km = 5846.946_f64;
         let d_km = dms1.distance(dms2) / 1000.0_f64;
-        assert!((expected_km - d_km).abs() < 1.0);
+        assert!((expected_km - d_km).abs() < 0.001);
     }
     #[test]
     fn test_azimuth() {
@@ -47,7 +48,7 @@ mod dms3d {
             // Paris
             48.85, 2.2321, None,
         );
-        assert!((53.78 - dms1.azimuth(dms2)) < 0.01);
+        assert!((53.80 - dms1.azimuth(dms2)).abs() < 0.01);
         let dms1 = DMS3d::from_ddeg_angles(
             // Paris
             48.85, 2.2321, None,
@@ -56,7 +57,9 @@ mod dms3d {
             // Sydney
             48.86, 2.287, None,
         );
-        assert!((68.49 - dms1.azimuth(dms2)) < 0.01)
+        assert!((74.55 - dms1.azimuth(dms2)).abs() < 0.01);
+        // westward azimuths are within 0 <= ɑ < 360
+        assert!((254.59 - dms2.azimuth(dms1)).abs() < 0.01);
     }
     #[test]
     fn test_to_cartesian() {
@@ -75,4 +78,154 @@ mod dms3d {
         assert!((cartesian.y / 1000.0 - xyz.y / 1000.0).abs() < 50.0);
         assert!((cartesian.z / 1000.0 - xyz.z / 1000.0).abs() < 50.0);
     }
+    #[test]
+    fn test_from_str() {
+        let expected = DMS3d::from_ddeg_angles(40.730610, -73.935242, Some(10.0));
+        for s in [
+            "40°43'50.196\"N 73°56'6.871\"W 10m",
+            "73°56'6.871\"W, 40°43'50.196\"N, 10",
+            "40 43 50.196 N 73 56 6.871 W 10 m",
+            "N40°43.8366' W73°56.1145' 10",
+            "40.730610, -73.935242, 10m",
+            "40.730610 -73.935242 32.8084ft",
+        ] {
+            let coords: DMS3d = s.parse().unwrap();
+            assert_eq!(coords.latitude.cardinal, Some(Cardinal::North), "{}", s);
+            assert_eq!(coords.longitude.cardinal, Some(Cardinal::West), "{}", s);
+            assert!(
+                (coords.latitude.to_ddeg_angle() - 40.730610).abs() < 1E-5,
+                "{}",
+                s
+            );
+            assert!(
+                (coords.longitude.to_ddeg_angle() - -73.935242).abs() < 1E-5,
+                "{}",
+                s
+            );
+            assert!((coords.altitude.unwrap() - 10.0).abs() < 1E-6, "{}", s);
+        }
+        let coords: DMS3d = expected.to_string().parse().unwrap();
+        assert_eq!(coords.latitude.degrees, 40);
+        assert_eq!(coords.longitude.minutes, 56);
+        assert!((coords.latitude.seconds - expected.latitude.seconds).abs() < 1E-4);
+        assert_eq!(coords.altitude, Some(10.0));
+
+        let coords: DMS3d = "-33.8698439, 151.2082848".parse().unwrap();
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        assert_eq!(coords.altitude, None);
+    }
+    #[test]
+    fn test_from_str_errors() {
+        assert!(matches!(
+            "40°43'50.196\"N".parse::<DMS3d>(),
+            Err(Error::MissingLongitude)
+        ));
+        assert!(matches!(
+            "73°56'6.871\"W".parse::<DMS3d>(),
+            Err(Error::MissingLatitude)
+        ));
+        assert!(matches!(
+            "40°43'50.196\"N 10°0'0\"S".parse::<DMS3d>(),
+            Err(Error::InvalidLongitude)
+        ));
+        assert!(matches!(
+            "95.0, 10.0".parse::<DMS3d>(),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            "40.0, 10.0, abc".parse::<DMS3d>(),
+            Err(Error::InvalidAltitude)
+        ));
+        assert!(matches!(
+            "40°61'N 10°W".parse::<DMS3d>(),
+            Err(Error::ParsingError(ParseError::InvalidMinutes))
+        ));
+    }
+    #[test]
+    fn test_normalization() {
+        // folding over the north pole moves to the opposite meridian
+        let coords = DMS3d::from_ddeg_angles(95.0, 10.0, Some(10.0));
+        assert_eq!(coords.latitude.degrees, 85);
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::North));
+        assert_eq!(coords.longitude.degrees, 170);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+        assert_eq!(coords.altitude, Some(10.0));
+        // and over the south pole
+        let coords = DMS3d::from_ddeg_angles(-91.0, -170.0, None);
+        assert_eq!(coords.latitude.degrees, 89);
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(coords.longitude.degrees, 10);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        // a full turn around the globe goes over both poles
+        let coords = DMS3d::from_ddeg_angles(370.0, 10.0, None);
+        assert_eq!(coords.latitude.degrees, 10);
+        assert_eq!(coords.longitude.degrees, 10);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        // antimeridian
+        let coords = DMS3d::from_ddeg_angles(10.0, 200.0, None);
+        assert_eq!(coords.longitude.degrees, 160);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+
+        let coords = DMS3d::from_ddeg_angles_with(95.0, 10.0, None, Normalization::Clamp).unwrap();
+        assert_eq!(coords.latitude.degrees, 90);
+        assert_eq!(coords.longitude.degrees, 10);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        let coords = DMS3d::from_ddeg_angles_with(95.0, 10.0, None, Normalization::Wrap).unwrap();
+        assert_eq!(coords.latitude.degrees, 85);
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(coords.longitude.degrees, 10);
+        assert!(matches!(
+            DMS3d::from_ddeg_angles_with(45.0, 180.5, None, Normalization::Reject),
+            Err(Error::InvalidLongitude)
+        ));
+
+        // constructor, from raw angles out of range
+        let raw = DMS {
+            degrees: 100,
+            minutes: 0,
+            seconds: 0.0,
+            cardinal: Some(Cardinal::North),
+            negative: false,
+        };
+        let coords = DMS3d::new(raw, DMS::new(20, 0, 0.0, Some(Cardinal::East)), None).unwrap();
+        assert_eq!(coords.latitude.degrees, 80);
+        assert_eq!(coords.longitude.degrees, 160);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+        // same position as from decimal degrees
+        let raw = DMS { degrees: 95, ..raw };
+        let coords = DMS3d::new(raw, DMS::new(10, 0, 0.0, Some(Cardinal::East)), None).unwrap();
+        assert_eq!(coords, DMS3d::from_ddeg_angles(95.0, 10.0, None));
+        assert_eq!(coords.longitude.degrees, 170);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+        // DMS::new folds the latitude on its own, the longitude is not moved
+        let coords = DMS3d::new(
+            DMS::new(95, 0, 0.0, Some(Cardinal::North)),
+            DMS::new(10, 0, 0.0, Some(Cardinal::East)),
+            None,
+        )
+        .unwrap();
+        assert_eq!(coords.latitude.degrees, 85);
+        assert_eq!(coords.longitude.degrees, 10);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        let mut raw = coords;
+        raw.latitude.degrees = 100;
+        assert!(matches!(
+            raw.normalize(Normalization::Reject),
+            Err(Error::InvalidLatitude)
+        ));
+        let valid = DMS3d::from_ddeg_angles(48.858222, 2.2945, Some(330.0));
+        assert_eq!(valid.normalize(Normalization::Reject).unwrap(), valid);
+
+        // going over the pole with arithmetics
+        let a = DMS3d::from_ddeg_angles(80.0, 10.0, None);
+        let b = DMS3d::from_ddeg_angles(20.0, 0.0, None);
+        let sum = (a + b).unwrap();
+        assert!((sum.latitude.to_ddeg_angle() - 80.0).abs() < 1E-9);
+        assert!((sum.longitude.to_ddeg_angle() + 170.0).abs() < 1E-9);
+        let b = DMS3d::from_ddeg_angles(5.0, 175.0, None);
+        let sum = (a + b).unwrap();
+        assert!((sum.latitude.to_ddeg_angle() - 85.0).abs() < 1E-9);
+        assert!((sum.longitude.to_ddeg_angle() + 175.0).abs() < 1E-9);
+    }
 }
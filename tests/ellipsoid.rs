@@ -0,0 +1,90 @@
+use dms_coordinates::{DMS3d, Ellipsoid};
+
+#[cfg(test)]
+mod ellipsoid {
+    use super::*;
+    #[test]
+    fn test_parameters() {
+        let wgs84 = Ellipsoid::WGS84;
+        assert_eq!(Ellipsoid::default(), wgs84);
+        assert!((wgs84.semi_minor_axis() - 6_356_752.314_245).abs() < 1E-6);
+        assert!((wgs84.eccentricity_squared() - 0.006_694_379_990_14).abs() < 1E-12);
+        let grs80 = Ellipsoid::GRS80;
+        assert!((grs80.semi_minor_axis() - 6_356_752.314_140).abs() < 1E-6);
+        let sphere = Ellipsoid::sphere(6.37E6);
+        assert_eq!(sphere.semi_minor_axis(), 6.37E6);
+        assert_eq!(sphere.eccentricity_squared(), 0.0);
+    }
+    #[test]
+    fn test_geodetic_to_ecef() {
+        // Sydney
+        let (x, y, z) = Ellipsoid::WGS84.geodetic_to_ecef(-33.8698439, 151.2082848, 0.0);
+        assert!((x - -4645949.480).abs() < 1E-3);
+        assert!((y - 2553257.584).abs() < 1E-3);
+        assert!((z - -3534468.529).abs() < 1E-3);
+        // poles & equator
+        let (x, y, z) = Ellipsoid::WGS84.geodetic_to_ecef(90.0, 0.0, 100.0);
+        assert!(x.abs() < 1E-6 && y.abs() < 1E-6);
+        assert!((z - 6_356_852.314_245).abs() < 1E-6);
+        let (x, y, z) = Ellipsoid::WGS84.geodetic_to_ecef(0.0, 90.0, -100.0);
+        assert!(x.abs() < 1E-6 && z.abs() < 1E-6);
+        assert!((y - 6_378_037.0).abs() < 1E-6);
+    }
+    #[test]
+    fn test_roundtrip() {
+        for ellipsoid in [
+            Ellipsoid::WGS84,
+            Ellipsoid::INTERNATIONAL_1924,
+            Ellipsoid::AIRY_1830,
+            Ellipsoid::sphere(6.37E6),
+        ] {
+            for lat in [-90.0, -89.9, -45.0, -0.001, 0.0, 12.345, 60.0, 89.99, 90.0] {
+                for lon in [-180.0, -73.9, 0.0, 2.35, 151.2, 179.99] {
+                    for h in [-400.0, 0.0, 8848.0, 35_786_000.0] {
+                        let (x, y, z) = ellipsoid.geodetic_to_ecef(lat, lon, h);
+                        let (lat2, lon2, h2) = ellipsoid.ecef_to_geodetic(x, y, z);
+                        assert!((lat2 - lat).abs() < 1E-9, "{} {} {}", lat, lon, h);
+                        if lat.abs() < 90.0 {
+                            let dlon = ((lon2 - lon + 180.0).rem_euclid(360.0) - 180.0).abs();
+                            assert!(dlon < 1E-9);
+                        }
+                        assert!((h2 - h).abs() < 1E-5, "{} {} {}: {}", lat, lon, h, h2);
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_dms3d_ecef() {
+        let coords = DMS3d::from_ddeg_angles(48.856614, 2.3522219, Some(35.0));
+        let back = DMS3d::from_cartesian(coords.to_cartesian());
+        assert!((back.latitude.to_ddeg_angle() - 48.856614).abs() < 1E-9);
+        assert!((back.longitude.to_ddeg_angle() - 2.3522219).abs() < 1E-9);
+        assert!((back.altitude.unwrap() - 35.0).abs() < 1E-6);
+
+        let xyz = coords.to_ecef(Ellipsoid::INTERNATIONAL_1924);
+        let back = DMS3d::from_ecef(xyz, Ellipsoid::INTERNATIONAL_1924);
+        assert!((back.latitude.to_ddeg_angle() - 48.856614).abs() < 1E-9);
+        assert!((back.altitude.unwrap() - 35.0).abs() < 1E-6);
+    }
+    #[test]
+    fn test_meridian_arc() {
+        let wgs84 = Ellipsoid::WGS84;
+        let quarter = wgs84.meridian_arc(90.0_f64.to_radians());
+        assert!((quarter - 10_001_965.729_3).abs() < 1E-3);
+        assert!((wgs84.meridian_arc(60.0_f64.to_radians()) - 6_654_072.819_5).abs() < 1E-3);
+        for latitude in [-89.0_f64, -45.0, 0.0, 12.5, 60.0, 89.9] {
+            let arc = wgs84.meridian_arc(latitude.to_radians());
+            let lat = wgs84.meridian_arc_latitude(arc).to_degrees();
+            assert!((lat - latitude).abs() < 1E-10);
+        }
+        // sphere
+        let sphere = Ellipsoid::sphere(6.37E6);
+        assert!((sphere.meridian_arc(1.0) - 6.37E6).abs() < 1E-6);
+        assert!((sphere.isometric_latitude(0.5) - 0.5_f64.tan().asinh()).abs() < 1E-12);
+        assert_eq!(
+            wgs84.isometric_latitude(90.0_f64.to_radians()),
+            f64::INFINITY
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use dms_coordinates::{format::is_valid, Cardinal, DMS3d, DMS};
+
+#[cfg(test)]
+mod format {
+    use super::*;
+    #[test]
+    fn test_dms_specifiers() {
+        let dms = DMS::new(40, 43, 50.196, Some(Cardinal::North));
+        assert_eq!(dms.format("%D°%M'%S\"%C").to_string(), dms.to_string());
+        assert_eq!(dms.format("%D°%M'%.3S\"%C").to_string(), "40°43'50.196\"N");
+        assert_eq!(dms.format("%D %M %.0S %C").to_string(), "40 43 50 N");
+        assert_eq!(dms.format("%C%D°%.4m'").to_string(), "N40°43.8366'");
+        assert_eq!(dms.format("%+%.6d").to_string(), "+40.730610");
+        assert_eq!(dms.format("%-%.2d").to_string(), "40.73");
+        assert_eq!(dms.format("100%% %C").to_string(), "100% N");
+
+        let dms = DMS::new(3, 7, 5.5, Some(Cardinal::West));
+        assert_eq!(
+            dms.format("%0D°%0M'%0.1S\"%C").to_string(),
+            "003°07'05.5\"W"
+        );
+        assert_eq!(dms.format("%-%0.3d").to_string(), "-003.118");
+        assert_eq!(dms.format("%+%D%0M").to_string(), "-307");
+
+        let dms = DMS::new(3, 7, 5.5, Some(Cardinal::South));
+        assert_eq!(dms.format("%0D%0M%0.1S%C").to_string(), "030705.5S");
+
+        let dms = DMS::new(3, 7, 5.5, None);
+        assert_eq!(dms.format("%D°%M'%.1S\"%C").to_string(), "3°7'5.5\"");
+        assert_eq!(dms.format("%+%D").to_string(), "+3");
+    }
+    #[test]
+    fn test_rounding_carry() {
+        let dms = DMS::new(10, 59, 59.9999, Some(Cardinal::East));
+        assert_eq!(dms.format("%D°%M'%.2S\"").to_string(), "11°0'0.00\"");
+        assert_eq!(dms.format("%D°%.2m'").to_string(), "11°0.00'");
+        assert_eq!(dms.format("%D°%M'%.4S\"").to_string(), "10°59'59.9999\"");
+    }
+    #[test]
+    fn test_dms3d() {
+        let coords = DMS3d::from_ddeg_angles(40.730610, -73.935242, Some(10.0));
+        assert_eq!(
+            coords
+                .format("%(lat)%0D%.2m%C,%(lon)%0D%.2m%C,%.0A")
+                .to_string(),
+            "4043.84N,07356.11W,10"
+        );
+        assert_eq!(
+            coords.format("%+%.4d %(lon)%+%.4d").to_string(),
+            "+40.7306 -73.9352"
+        );
+        let coords = DMS3d::from_ddeg_angles(40.730610, -73.935242, None);
+        assert_eq!(coords.format("%A").to_string(), "0.0");
+        // each axis is rounded to its own precision
+        let coords = DMS3d::from_ddeg_angles(50.2, 6.87123, None);
+        assert_eq!(
+            coords.format("%D°%M'%.1S\" %(lon)%D°%M'%.3S\"").to_string(),
+            "50°12'0.0\" 6°52'16.428\""
+        );
+        assert_eq!(coords.format("%.0m %(lon)%.3m").to_string(), "12 52.274");
+    }
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(is_valid("%D°%M'%.3S\"%C"));
+        assert!(is_valid("%(lat)%D %(lon)%D"));
+        assert!(!is_valid("%Q"));
+        assert!(!is_valid("%.S"));
+        assert!(!is_valid("%"));
+        use core::fmt::Write;
+        let dms = DMS::new(40, 43, 50.196, Some(Cardinal::North));
+        let mut s = String::new();
+        assert!(write!(s, "{}", dms.format("%D %Q")).is_err());
+        assert!(write!(s, "{}", dms.format("%(lon)%D")).is_err());
+        assert!(write!(s, "{}", dms.format("%A")).is_err());
+    }
+}
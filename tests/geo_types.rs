@@ -0,0 +1,104 @@
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use dms_coordinates::geo_types::{Path, Polygon};
+use dms_coordinates::{Cardinal, DMS3d, Error};
+
+#[cfg(test)]
+mod geo_types {
+    use super::*;
+    #[test]
+    fn test_point() {
+        let eiffel = DMS3d::from_ddeg_angles(48.858222, 2.2945, Some(330.0));
+        let point = ::geo_types::Point::from(eiffel);
+        assert_eq!(point.x(), eiffel.longitude.to_ddeg_angle());
+        assert_eq!(point.y(), eiffel.latitude.to_ddeg_angle());
+        let coord = ::geo_types::Coord::from(eiffel);
+        assert_eq!(coord, point.0);
+
+        let coords = DMS3d::try_from(::geo_types::Point::new(-73.935242, -33.8725)).unwrap();
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+        assert!((coords.latitude.to_ddeg_angle() + 33.8725).abs() < 1E-12);
+        assert!((coords.longitude.to_ddeg_angle() + 73.935242).abs() < 1E-12);
+        assert_eq!(coords.altitude, None);
+
+        // altitude is restored explicitly
+        let coords = DMS3d::try_from(point).unwrap().with_altitude(330.0);
+        assert!((coords.latitude.to_ddeg_angle() - 48.858222).abs() < 1E-12);
+        assert_eq!(coords.altitude, Some(330.0));
+    }
+    #[test]
+    fn test_invalid_point() {
+        assert!(matches!(
+            DMS3d::try_from(::geo_types::Point::new(2.0, 90.5)),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            DMS3d::try_from(::geo_types::Coord { x: -180.5, y: 0.0 }),
+            Err(Error::InvalidLongitude)
+        ));
+        assert!(matches!(
+            DMS3d::try_from(::geo_types::Coord {
+                x: 0.0,
+                y: f64::NAN
+            }),
+            Err(Error::InvalidLatitude)
+        ));
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_line_string() {
+        let path = Path(vec![
+            DMS3d::from_ddeg_angles(48.858222, 2.2945, Some(35.0)),
+            DMS3d::from_ddeg_angles(48.8530, 2.3499, None),
+            DMS3d::from_ddeg_angles(48.8606, 2.3376, Some(40.0)),
+        ]);
+        let line = ::geo_types::LineString::from(path.clone());
+        assert_eq!(line.0.len(), 3);
+        assert_eq!(line.0[1].x, path.0[1].longitude.to_ddeg_angle());
+        assert_eq!(line.0[1].y, path.0[1].latitude.to_ddeg_angle());
+
+        let altitudes = path.altitudes();
+        assert_eq!(altitudes, vec![Some(35.0), None, Some(40.0)]);
+        let converted = Path::try_from(&line).unwrap();
+        assert!(converted.0.iter().all(|c| c.altitude.is_none()));
+        let restored = converted.with_altitudes(&altitudes);
+        for (a, b) in restored.0.iter().zip(&path.0) {
+            assert!((a.latitude.to_ddeg_angle() - b.latitude.to_ddeg_angle()).abs() < 1E-12);
+            assert!((a.longitude.to_ddeg_angle() - b.longitude.to_ddeg_angle()).abs() < 1E-12);
+            assert_eq!(a.altitude, b.altitude);
+        }
+
+        let line = ::geo_types::LineString::from(vec![(0.0, 0.0), (200.0, 1.0)]);
+        assert!(matches!(
+            Path::try_from(&line),
+            Err(Error::InvalidLongitude)
+        ));
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_polygon() {
+        let ring = |points: &[(f64, f64)]| {
+            Path(
+                points
+                    .iter()
+                    .map(|(lat, lon)| DMS3d::from_ddeg_angles(*lat, *lon, None))
+                    .collect(),
+            )
+        };
+        let polygon = Polygon {
+            exterior: ring(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)]),
+            interiors: vec![ring(&[(2.0, 2.0), (2.0, 4.0), (4.0, 4.0), (2.0, 2.0)])],
+        };
+        let geo = ::geo_types::Polygon::from(polygon.clone());
+        // exterior ring gets closed
+        assert_eq!(geo.exterior().0.len(), 5);
+        assert_eq!(geo.exterior().0[1], ::geo_types::Coord { x: 10.0, y: 0.0 });
+        assert_eq!(geo.interiors().len(), 1);
+
+        let converted = Polygon::try_from(&geo).unwrap();
+        assert_eq!(converted.exterior.0.len(), 5);
+        assert_eq!(&converted.exterior.0[..4], &polygon.exterior.0[..]);
+        assert_eq!(converted.interiors, polygon.interiors);
+    }
+}
@@ -0,0 +1,205 @@
+use dms_coordinates::{
+    geodesic::{direct, inverse, Method},
+    Cardinal, DMS3d, Ellipsoid, Error,
+};
+
+#[cfg(test)]
+mod geodesic {
+    use super::*;
+    #[test]
+    fn test_inverse_jfk_cdg() {
+        let jfk = DMS3d::from_ddeg_angles(40.6, -73.8, None);
+        let cdg = DMS3d::from_ddeg_angles(49.01666667, 2.55, None);
+        let karney = jfk.geodesic_inverse(cdg, Method::Karney).unwrap();
+        assert!((karney.distance - 5_853_226.255_613).abs() < 1E-3);
+        assert!((karney.forward_azimuth - 53.470_218_24).abs() < 1E-6);
+        assert!((karney.final_azimuth() - 111.593_669_51).abs() < 1E-6);
+        assert!((karney.reverse_azimuth - 291.593_669_51).abs() < 1E-6);
+        let vincenty = jfk.geodesic_inverse(cdg, Method::Vincenty).unwrap();
+        assert!((vincenty.distance - karney.distance).abs() < 1E-3);
+        assert!((vincenty.forward_azimuth - karney.forward_azimuth).abs() < 1E-6);
+        assert!((vincenty.reverse_azimuth - karney.reverse_azimuth).abs() < 1E-6);
+        // spherical approximation is within 0.5%
+        let haversine = jfk.geodesic_inverse(cdg, Method::Haversine).unwrap();
+        assert!((haversine.distance - karney.distance).abs() / karney.distance < 5E-3);
+        assert!((haversine.forward_azimuth - karney.forward_azimuth).abs() < 0.5);
+        // reverse problem
+        let reverse = cdg.geodesic_inverse(jfk, Method::Karney).unwrap();
+        assert!((reverse.distance - karney.distance).abs() < 1E-6);
+        assert!((reverse.forward_azimuth - karney.reverse_azimuth).abs() < 1E-6);
+    }
+    #[test]
+    fn test_inverse_antipodal() {
+        let cases = [
+            ((0.0, 0.0), (0.5, 179.7), 19_944_127.420_750, 15.556_882_79),
+            (
+                (-30.0, 0.0),
+                (29.9, 179.8),
+                19_989_832.827_610,
+                161.890_524_74,
+            ),
+            ((0.0, 0.0), (0.0, 179.5), 19_980_861.908_891, 55.966_495_14),
+            ((89.9, 10.0), (-89.9, -170.0), 20_003_931.458_625, 0.0),
+            ((0.0, 0.0), (0.0, 180.0), 20_003_931.458_625, 0.0),
+        ];
+        for (p1, p2, distance, azimuth) in cases {
+            let karney = inverse(Ellipsoid::WGS84, p1, p2, Method::Karney).unwrap();
+            assert!((karney.distance - distance).abs() < 1E-3);
+            assert!((karney.forward_azimuth - azimuth).abs() < 1E-6);
+            let vincenty = inverse(Ellipsoid::WGS84, p1, p2, Method::Vincenty);
+            assert!(matches!(vincenty, Err(Error::NoConvergence)));
+        }
+    }
+    #[test]
+    fn test_inverse_special_cases() {
+        // pole to pole, along a meridian
+        let karney = inverse(Ellipsoid::WGS84, (90.0, 0.0), (-90.0, 0.0), Method::Karney).unwrap();
+        assert!((karney.distance - 20_003_931.458_625).abs() < 1E-3);
+        assert_eq!(karney.forward_azimuth, 180.0);
+        // along the equator
+        let karney = inverse(Ellipsoid::WGS84, (0.0, 0.0), (0.0, 90.0), Method::Karney).unwrap();
+        assert!((karney.distance - 10_018_754.171_395).abs() < 1E-3);
+        assert_eq!(karney.forward_azimuth, 90.0);
+        assert_eq!(karney.reverse_azimuth, 270.0);
+        // across the antimeridian
+        let p1 = (45.0, -170.0);
+        let p2 = (-45.0, 175.0);
+        let karney = inverse(Ellipsoid::WGS84, p1, p2, Method::Karney).unwrap();
+        assert!((karney.distance - 10_079_206.163_666).abs() < 1E-3);
+        assert!((karney.forward_azimuth - 190.591_782_60).abs() < 1E-6);
+        let vincenty = inverse(Ellipsoid::WGS84, p1, p2, Method::Vincenty).unwrap();
+        assert!((vincenty.distance - karney.distance).abs() < 1E-3);
+        // coincident points
+        let p = (10.0, 20.0);
+        for method in [Method::Haversine, Method::Vincenty, Method::Karney] {
+            let inv = inverse(Ellipsoid::WGS84, p, p, method).unwrap();
+            assert_eq!(inv.distance, 0.0);
+        }
+        // short line
+        let karney = inverse(
+            Ellipsoid::WGS84,
+            (1.0, 2.0),
+            (1.00001, 2.00001),
+            Method::Karney,
+        )
+        .unwrap();
+        assert!((karney.distance - 1.568_918).abs() < 1E-6);
+    }
+    #[test]
+    fn test_inverse_sphere() {
+        // all methods agree on a sphere
+        let sphere = Ellipsoid::sphere(6.37E6);
+        let (p1, p2) = ((40.6, -73.8), (49.01666667, 2.55));
+        let haversine = inverse(sphere, p1, p2, Method::Haversine).unwrap();
+        for method in [Method::Vincenty, Method::Karney] {
+            let inv = inverse(sphere, p1, p2, method).unwrap();
+            assert!((inv.distance - haversine.distance).abs() < 1E-3);
+            assert!((inv.forward_azimuth - haversine.forward_azimuth).abs() < 1E-6);
+            assert!((inv.reverse_azimuth - haversine.reverse_azimuth).abs() < 1E-6);
+        }
+    }
+    #[test]
+    fn test_direct() {
+        let cases = [
+            (
+                (40.6, -73.8),
+                53.470_218_24,
+                5_853_226.255_6,
+                (49.016_666_669_6, 2.549_999_999_6, 111.593_669_514_1),
+            ),
+            (
+                (10.0, 170.0),
+                90.0,
+                2_000_000.0,
+                (9.504_636_115_9, -171.776_564_781_6, 93.112_577_172_2),
+            ),
+            (
+                (-45.0, -179.0),
+                250.0,
+                500_000.0,
+                (-46.377_741_174_1, 174.887_548_648_3, 254.376_155_809_7),
+            ),
+            (
+                (80.0, 30.0),
+                0.0,
+                2_000_000.0,
+                (82.092_406_267_2, -150.0, 180.0),
+            ),
+            (
+                (10.0, 20.0),
+                123.0,
+                -1000.0,
+                (10.004_923_969_2, 19.992_350_528_4, 122.998_671_359_5),
+            ),
+        ];
+        for (p1, azimuth, distance, (lat, lon, azimuth2)) in cases {
+            for method in [Method::Vincenty, Method::Karney] {
+                let d = direct(Ellipsoid::WGS84, p1, azimuth, distance, method).unwrap();
+                assert!((d.latitude - lat).abs() < 1E-9);
+                assert!((d.longitude - lon).abs() < 1E-9);
+                assert!((d.final_azimuth - azimuth2).abs() < 1E-9);
+            }
+            // spherical approximation
+            let d = direct(Ellipsoid::WGS84, p1, azimuth, distance, Method::Haversine).unwrap();
+            assert!((d.latitude - lat).abs() < 0.1);
+            assert!((d.final_azimuth - azimuth2).abs() < 0.2);
+        }
+    }
+    #[test]
+    fn test_direct_inverse_roundtrip() {
+        let p1 = (-33.8698439, 151.2082848);
+        for (azimuth, distance) in [(0.0, 1.0), (45.0, 1E5), (200.0, 8E6), (300.0, 1.9E7)] {
+            let d = direct(Ellipsoid::WGS84, p1, azimuth, distance, Method::Karney).unwrap();
+            let inv = inverse(
+                Ellipsoid::WGS84,
+                p1,
+                (d.latitude, d.longitude),
+                Method::Karney,
+            )
+            .unwrap();
+            assert!((inv.distance - distance).abs() < 1E-6);
+            assert!((inv.forward_azimuth - azimuth).abs() < 1E-9);
+            assert!((inv.final_azimuth() - d.final_azimuth).abs() < 1E-9);
+        }
+    }
+    #[test]
+    fn test_destination() {
+        let coords = DMS3d::from_ddeg_angles(10.0, 170.0, Some(120.0));
+        let dest = coords
+            .destination(90.0, 2_000_000.0, Method::Karney)
+            .unwrap();
+        assert_eq!(dest.latitude.cardinal, Some(Cardinal::North));
+        assert_eq!(dest.longitude.cardinal, Some(Cardinal::West));
+        assert_eq!(dest.longitude.degrees, 171);
+        assert!((dest.longitude.to_ddeg_angle() - -171.776_564_781_6).abs() < 1E-9);
+        assert_eq!(dest.altitude, Some(120.0));
+        // reaching the north pole
+        let coords = DMS3d::from_ddeg_angles(0.0, 0.0, None);
+        let dest = coords
+            .destination(0.0, 10_001_965.729_3, Method::Karney)
+            .unwrap();
+        assert_eq!(dest.latitude.degrees, 89);
+        assert!((dest.latitude.to_ddeg_angle() - 90.0).abs() < 1E-8);
+        assert_eq!(dest.altitude, None);
+        // crossing the equator
+        let coords = DMS3d::from_ddeg_angles(1.0, -1.0, None);
+        let dest = coords
+            .destination(135.0, 400_000.0, Method::Haversine)
+            .unwrap();
+        assert_eq!(dest.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(dest.longitude.cardinal, Some(Cardinal::East));
+        // other ellipsoid
+        let dest = coords
+            .destination_on(
+                135.0,
+                400_000.0,
+                Method::Vincenty,
+                Ellipsoid::INTERNATIONAL_1924,
+            )
+            .unwrap();
+        let inv = coords
+            .geodesic_inverse_on(dest, Method::Karney, Ellipsoid::INTERNATIONAL_1924)
+            .unwrap();
+        assert!((inv.distance - 400_000.0).abs() < 1E-3);
+    }
+}
@@ -0,0 +1,107 @@
+use dms_coordinates::{Cardinal, DMS3d, Error, Geohash};
+
+#[cfg(test)]
+mod geohash {
+    use super::*;
+    #[test]
+    fn test_encoding() {
+        let coords = DMS3d::from_ddeg_angles(57.64911, 10.40744, None);
+        assert_eq!(coords.to_geohash(11).unwrap().as_str(), "u4pruydqqvj");
+        assert_eq!(coords.to_geohash(1).unwrap().as_str(), "u");
+        let coords = DMS3d::from_ddeg_angles(48.8582, 2.2945, None);
+        assert_eq!(coords.to_geohash(9).unwrap().as_str(), "u09tunqg9");
+        let cases = [
+            ((0.0, 0.0), "s00000000000"),
+            ((-90.0, -180.0), "000000000000"),
+            ((90.0, 180.0), "zzzzzzzzzzzz"),
+        ];
+        for ((lat, lon), expected) in cases {
+            let geohash = Geohash::from_ddeg_angles(lat, lon, 12).unwrap();
+            assert_eq!(geohash.to_string(), expected);
+        }
+        for length in [0, 13] {
+            assert!(matches!(
+                coords.to_geohash(length),
+                Err(Error::InvalidGeohash)
+            ));
+        }
+    }
+    #[test]
+    fn test_decoding() {
+        let geohash: Geohash = "EZS42".parse().unwrap();
+        assert_eq!(geohash.to_string(), "ezs42");
+        assert_eq!(geohash.len(), 5);
+        let (sw, ne) = geohash.bounds();
+        assert!((sw.latitude.to_ddeg_angle() - 42.583_007_812_5).abs() < 1E-9);
+        assert!((sw.longitude.to_ddeg_angle() + 5.625).abs() < 1E-9);
+        assert!((ne.latitude.to_ddeg_angle() - 42.626_953_125).abs() < 1E-9);
+        assert!((ne.longitude.to_ddeg_angle() + 5.581_054_687_5).abs() < 1E-9);
+        let centre = geohash.centre();
+        assert!((centre.latitude.to_ddeg_angle() - 42.604_980_468_75).abs() < 1E-9);
+        assert!((centre.longitude.to_ddeg_angle() + 5.603_027_343_75).abs() < 1E-9);
+        let (height, width) = geohash.size();
+        assert!((height - 0.043_945_312_5).abs() < 1E-12);
+        assert!((width - 0.043_945_312_5).abs() < 1E-12);
+        // centre encodes back to the same cell
+        for length in 1..=12 {
+            let geohash = Geohash::from_ddeg_angles(-33.857, 151.215, length).unwrap();
+            assert_eq!(geohash.centre().to_geohash(length).unwrap(), geohash);
+        }
+        for invalid in ["", "u4pa", "u4pi", "u4pruydqqvjxx"] {
+            assert!(matches!(
+                invalid.parse::<Geohash>(),
+                Err(Error::InvalidGeohash)
+            ));
+        }
+    }
+    #[test]
+    fn test_neighbours() {
+        let geohash: Geohash = "u09tunq".parse().unwrap();
+        let expected = [
+            "u09tunw", "u09tunx", "u09tunr", "u09tunp", "u09tunn", "u09tunj", "u09tunm", "u09tunt",
+        ];
+        for (neighbour, expected) in geohash.neighbours().iter().zip(expected) {
+            assert_eq!(neighbour.unwrap().as_str(), expected);
+        }
+        assert_eq!(
+            geohash.neighbour(Cardinal::North).unwrap().as_str(),
+            "u09tunw"
+        );
+        // antimeridian
+        let geohash: Geohash = "2".parse().unwrap();
+        assert_eq!(geohash.neighbour(Cardinal::West).unwrap().as_str(), "r");
+        let geohash: Geohash = "rz".parse().unwrap();
+        assert_eq!(geohash.neighbour(Cardinal::East).unwrap().as_str(), "2p");
+        // poles
+        let geohash: Geohash = "zzz".parse().unwrap();
+        let neighbours = geohash.neighbours();
+        assert!(neighbours[0].is_none());
+        assert!(neighbours[1].is_none());
+        assert!(neighbours[7].is_none());
+        assert_eq!(neighbours[2].unwrap().as_str(), "bpb");
+        let geohash: Geohash = "0".parse().unwrap();
+        assert!(geohash.neighbour(Cardinal::South).is_none());
+        assert!(geohash.neighbour(Cardinal::SouthEast).is_none());
+    }
+    #[test]
+    fn test_hierarchy() {
+        let geohash: Geohash = "u09tunq".parse().unwrap();
+        let parent = geohash.parent().unwrap();
+        assert_eq!(parent.as_str(), "u09tun");
+        let children = parent.children().unwrap();
+        assert_eq!(children[0].as_str(), "u09tun0");
+        assert_eq!(children[31].as_str(), "u09tunz");
+        assert!(children.contains(&geohash));
+        let (psw, pne) = parent.bounds();
+        for child in children {
+            assert_eq!(child.parent().unwrap(), parent);
+            let (sw, ne) = child.bounds();
+            assert!(sw.latitude.to_ddeg_angle() >= psw.latitude.to_ddeg_angle());
+            assert!(ne.longitude.to_ddeg_angle() <= pne.longitude.to_ddeg_angle());
+        }
+        let geohash: Geohash = "u".parse().unwrap();
+        assert!(geohash.parent().is_none());
+        let geohash: Geohash = "u4pruydqqvjx".parse().unwrap();
+        assert!(geohash.children().is_none());
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(feature = "geojson")]
+use dms_coordinates::{
+    geojson::{Feature, FeatureCollection, Geometry, JsonObject, JsonValue},
+    Cardinal, DMS3d, Error,
+};
+
+#[cfg(feature = "geojson")]
+mod geojson {
+    use super::*;
+    #[test]
+    fn test_geometries() {
+        let paris = DMS3d::from_ddeg_angles(48.8566, 2.3522, Some(35.0));
+        let point = Geometry::from(paris);
+        assert_eq!(
+            point.to_string(),
+            "{\"type\":\"Point\",\"coordinates\":[2.3522,48.8566,35.0]}"
+        );
+        let path = vec![
+            DMS3d::from_ddeg_angles(48.8566, 2.3522, None),
+            DMS3d::from_ddeg_angles(-33.8688, -151.2093, None),
+        ];
+        let line = Geometry::from(path.clone());
+        assert_eq!(
+            line.to_string(),
+            "{\"type\":\"LineString\",\"coordinates\":[[2.3522,48.8566],[-151.2093,-33.8688]]}"
+        );
+        let ring = vec![
+            DMS3d::from_ddeg_angles(0.0, 0.0, None),
+            DMS3d::from_ddeg_angles(0.0, 1.0, None),
+            DMS3d::from_ddeg_angles(1.0, 1.0, None),
+            DMS3d::from_ddeg_angles(0.0, 0.0, None),
+        ];
+        let polygon = Geometry::Polygon(vec![ring]);
+        for geometry in [point, line, polygon] {
+            let parsed: Geometry = geometry.to_string().parse().unwrap();
+            assert_eq!(parsed, geometry);
+        }
+        let parsed: Geometry =
+            "{\"type\":\"LineString\",\"coordinates\":[[2.3522,48.8566],[-151.2093,-33.8688]]}"
+                .parse()
+                .unwrap();
+        match parsed {
+            Geometry::LineString(parsed) => {
+                assert_eq!(parsed.len(), 2);
+                assert_eq!(parsed[1].latitude.cardinal, Some(Cardinal::South));
+                assert_eq!(parsed[1].longitude.cardinal, Some(Cardinal::West));
+                assert!((parsed[1].longitude.to_ddeg_angle() + 151.2093).abs() < 1E-12);
+                assert_eq!(parsed[1].altitude, None);
+            }
+            _ => panic!("expecting a line string"),
+        }
+    }
+    #[test]
+    fn test_features() {
+        let content = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [2.2945, 48.858222, 330.0] },
+                    "properties": { "name": "Eiffel Tower", "height": 330, "tags": ["tower", "paris"] }
+                },
+                {
+                    "type": "Feature",
+                    "geometry": null,
+                    "properties": null
+                }
+            ]
+        }"#;
+        let collection: FeatureCollection = content.parse().unwrap();
+        assert_eq!(collection.features.len(), 2);
+        let eiffel = &collection.features[0];
+        match &eiffel.geometry {
+            Some(Geometry::Point(coords)) => {
+                assert!((coords.latitude.to_ddeg_angle() - 48.858222).abs() < 1E-12);
+                assert!((coords.longitude.to_ddeg_angle() - 2.2945).abs() < 1E-12);
+                assert_eq!(coords.altitude, Some(330.0));
+            }
+            _ => panic!("expecting a point"),
+        }
+        assert_eq!(
+            eiffel.properties.get("name"),
+            Some(&JsonValue::from("Eiffel Tower"))
+        );
+        assert_eq!(eiffel.properties.get("height"), Some(&JsonValue::from(330)));
+        assert_eq!(collection.features[1], Feature::default());
+
+        let parsed: FeatureCollection = collection.to_string().parse().unwrap();
+        assert_eq!(parsed, collection);
+
+        // single features and geometries make a collection of one
+        let parsed: FeatureCollection = eiffel.to_string().parse().unwrap();
+        assert_eq!(parsed.features, vec![eiffel.clone()]);
+        let parsed: Feature = "{\"type\":\"Point\",\"coordinates\":[2.2945,48.858222]}"
+            .parse()
+            .unwrap();
+        assert!(parsed.properties.is_empty());
+
+        let mut properties = JsonObject::new();
+        properties.insert(String::from("name"), JsonValue::from("Paris"));
+        let feature = Feature {
+            geometry: Some(DMS3d::from_ddeg_angles(48.8566, 2.3522, None).into()),
+            properties,
+        };
+        let parsed: Feature = feature.to_string().parse().unwrap();
+        assert_eq!(parsed, feature);
+    }
+    #[test]
+    fn test_serde() {
+        let feature = Feature {
+            geometry: Some(DMS3d::from_ddeg_angles(-33.8688, -151.2093, Some(58.0)).into()),
+            properties: JsonObject::new(),
+        };
+        let value = serde_json::to_value(&feature).unwrap();
+        assert_eq!(value["type"], "Feature");
+        assert_eq!(
+            value["geometry"]["coordinates"],
+            serde_json::json!([-151.2093, -33.8688, 58.0])
+        );
+        let parsed: Feature = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, feature);
+        assert!(serde_json::from_str::<Geometry>(
+            "{\"type\":\"MultiPoint\",\"coordinates\":[[2.0,48.0]]}"
+        )
+        .is_err());
+    }
+    #[test]
+    fn test_errors() {
+        for content in [
+            "{\"type\":\"MultiPoint\",\"coordinates\":[[2.0,48.0]]}",
+            "{\"type\":\"Point\",\"coordinates\":[2.0]}",
+            "{\"type\":\"FeatureCollection\",\"features\":[]}",
+            "not json",
+        ] {
+            assert!(matches!(
+                content.parse::<Geometry>(),
+                Err(Error::GeoJsonError)
+            ));
+        }
+        assert!(matches!(
+            "{\"type\":\"Point\",\"coordinates\":[2.0,95.0]}".parse::<Geometry>(),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            "{\"type\":\"Point\",\"coordinates\":[200.0,45.0]}".parse::<Feature>(),
+            Err(Error::InvalidLongitude)
+        ));
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "gpx")]
+use dms_coordinates::{
+    gpx::{Document, Point, Route, Track},
+    Cardinal, DMS3d,
+};
+
+#[cfg(feature = "gpx")]
+mod gpx {
+    use super::*;
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/paris.gpx");
+    fn assert_same_points(a: &[Point], b: &[Point]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b.iter()) {
+            let (ca, cb) = (a.coords, b.coords);
+            assert!((ca.latitude.to_ddeg_angle() - cb.latitude.to_ddeg_angle()).abs() < 1E-12);
+            assert!((ca.longitude.to_ddeg_angle() - cb.longitude.to_ddeg_angle()).abs() < 1E-12);
+            assert_eq!(ca.latitude.cardinal, cb.latitude.cardinal);
+            assert_eq!(ca.longitude.cardinal, cb.longitude.cardinal);
+            assert_eq!(ca.altitude, cb.altitude);
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.time, b.time);
+        }
+    }
+    #[test]
+    fn test_read() {
+        let file = std::fs::File::open(FIXTURE).unwrap();
+        let document = Document::read(file).unwrap();
+        assert_eq!(document.waypoints.len(), 2);
+        let eiffel = &document.waypoints[0];
+        assert_eq!(eiffel.name.as_deref(), Some("Eiffel Tower"));
+        assert!((eiffel.coords.latitude.to_ddeg_angle() - 48.858222).abs() < 1E-12);
+        assert!((eiffel.coords.longitude.to_ddeg_angle() - 2.2945).abs() < 1E-12);
+        assert_eq!(eiffel.coords.latitude.cardinal, Some(Cardinal::North));
+        assert_eq!(eiffel.coords.altitude, Some(330.0));
+        assert!(eiffel.time.is_some());
+        assert_eq!(document.waypoints[1].time, None);
+
+        assert_eq!(document.tracks.len(), 1);
+        let track = &document.tracks[0];
+        assert_eq!(track.name.as_deref(), Some("Morning walk"));
+        assert_eq!(track.segments.len(), 2);
+        assert_eq!(track.segments[0].len(), 2);
+        assert!(track.segments[0][0].time < track.segments[0][1].time);
+
+        assert_eq!(document.routes.len(), 1);
+        let route = &document.routes[0];
+        assert_eq!(route.name.as_deref(), Some("Across the river"));
+        let antipodes = route.points[2].coords;
+        assert_eq!(antipodes.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(antipodes.longitude.cardinal, Some(Cardinal::West));
+        assert!((antipodes.longitude.to_ddeg_angle() + 151.2153).abs() < 1E-12);
+    }
+    #[test]
+    fn test_round_trip() {
+        let file = std::fs::File::open(FIXTURE).unwrap();
+        let document = Document::read(file).unwrap();
+        let mut buffer = Vec::new();
+        document.write(&mut buffer).unwrap();
+        let parsed = Document::read(buffer.as_slice()).unwrap();
+        assert_same_points(&document.waypoints, &parsed.waypoints);
+        assert_eq!(document.routes.len(), parsed.routes.len());
+        for (a, b) in document.routes.iter().zip(parsed.routes.iter()) {
+            assert_eq!(a.name, b.name);
+            assert_same_points(&a.points, &b.points);
+        }
+        assert_eq!(document.tracks.len(), parsed.tracks.len());
+        for (a, b) in document.tracks.iter().zip(parsed.tracks.iter()) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.segments.len(), b.segments.len());
+            for (a, b) in a.segments.iter().zip(b.segments.iter()) {
+                assert_same_points(a, b);
+            }
+        }
+    }
+    #[test]
+    fn test_write() {
+        let coords = [
+            DMS3d::from_ddeg_angles(40.6, -73.8, Some(4.0)),
+            DMS3d::from_ddeg_angles(49.016667, 2.55, Some(119.0)),
+        ];
+        let document = Document {
+            routes: vec![Route::new(Some(String::from("JFK - CDG")), &coords)],
+            tracks: vec![Track::new(None, &coords)],
+            ..Default::default()
+        };
+        let mut buffer = Vec::new();
+        document.write(&mut buffer).unwrap();
+        let content = String::from_utf8(buffer.clone()).unwrap();
+        assert!(content.contains("lat=\"40.6\" lon=\"-73.8\""));
+        let parsed = Document::read(buffer.as_slice()).unwrap();
+        assert_eq!(parsed.routes[0].name.as_deref(), Some("JFK - CDG"));
+        assert_same_points(&parsed.routes[0].points, &document.routes[0].points);
+        assert_same_points(&parsed.tracks[0].segments[0], &coords.map(Point::from));
+    }
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            Document::read("<gpx version=\"1.1\"><wpt lat=\"1.0\"></wpt></gpx>".as_bytes()),
+            Err(dms_coordinates::Error::GpxParsingError)
+        ));
+    }
+}
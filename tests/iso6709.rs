@@ -0,0 +1,168 @@
+use dms_coordinates::{
+    dms::ParseError,
+    iso6709::{Location, Style},
+    Cardinal, DMS3d, Error, DMS,
+};
+
+#[cfg(test)]
+mod iso6709 {
+    use super::*;
+    #[test]
+    fn test_parsing() {
+        let location = Location::parse("+40.7306-073.9352+10.0CRSWGS_84/").unwrap();
+        assert_eq!(location.style, Style::Degrees);
+        assert_eq!(location.crs, Some("WGS_84"));
+        let coords = location.coords;
+        assert!((coords.latitude.to_ddeg_angle() - 40.7306).abs() < 1E-12);
+        assert!((coords.longitude.to_ddeg_angle() + 73.9352).abs() < 1E-12);
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::North));
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+        assert_eq!(coords.altitude, Some(10.0));
+
+        let location = Location::parse("+404350.196-0735606.871/").unwrap();
+        assert_eq!(location.style, Style::DMS);
+        assert_eq!(location.crs, None);
+        assert_eq!(
+            location.coords.latitude,
+            DMS::new(40, 43, 50.196, Some(Cardinal::North))
+        );
+        assert_eq!(
+            location.coords.longitude,
+            DMS::new(73, 56, 6.871, Some(Cardinal::West))
+        );
+        assert_eq!(location.coords.altitude, None);
+
+        let coords = DMS3d::from_iso6709("-3352.35+15112.7-2.5/").unwrap();
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        assert!((coords.latitude.to_ddeg_angle() + (33.0 + 52.35 / 60.0)).abs() < 1E-12);
+        assert!((coords.longitude.to_ddeg_angle() - (151.0 + 12.7 / 60.0)).abs() < 1E-12);
+        assert_eq!(coords.altitude, Some(-2.5));
+
+        // integer fields only
+        let coords = DMS3d::from_iso6709("+90+180/").unwrap();
+        assert_eq!(coords.latitude.degrees, 90);
+        assert_eq!(coords.longitude.degrees, 180);
+
+        // FromStr
+        let coords: DMS3d = "+404350.196-0735606.871/".parse().unwrap();
+        assert_eq!(coords.latitude.seconds, 50.196);
+    }
+    #[test]
+    fn test_parsing_errors() {
+        for s in [
+            "",
+            "+40.7306-073.9352",
+            "40.7306-073.9352/",
+            "+40.7306/",
+            "+040.7306-073.9352/",
+            "+40.7306-73.9352/",
+            "+4043.5-0735606.871/",
+            "+40.7306-073.9352+10.0+5/",
+            "+40.7306-073.9352CRS/",
+            "+40.7306-073.a352/",
+            "+40.-073.9352/",
+        ] {
+            assert!(
+                matches!(
+                    Location::parse(s),
+                    Err(Error::ParsingError(ParseError::FormatNotRecognized))
+                ),
+                "{}",
+                s
+            );
+        }
+        assert!(matches!(
+            Location::parse("+4060.5-07356.1/"),
+            Err(Error::ParsingError(ParseError::InvalidMinutes))
+        ));
+        assert!(matches!(
+            Location::parse("+404360.0-0735606.871/"),
+            Err(Error::ParsingError(ParseError::InvalidSeconds))
+        ));
+        assert!(matches!(
+            Location::parse("+90.5-073.9352/"),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            Location::parse("+40.7306-180.5/"),
+            Err(Error::InvalidLongitude)
+        ));
+        assert!(matches!(
+            Location::parse("+40.7306-073.9352+abc/"),
+            Err(Error::InvalidAltitude)
+        ));
+    }
+    #[test]
+    fn test_formatting() {
+        let coords = DMS3d::from_ddeg_angles(40.730610, -73.935242, Some(10.0));
+        let iso = coords.to_iso6709(Style::Degrees).with_precision(4);
+        assert_eq!(iso.to_string(), "+40.7306-073.9352+10.0/");
+        assert_eq!(
+            iso.with_crs("WGS_84").to_string(),
+            "+40.7306-073.9352+10.0CRSWGS_84/"
+        );
+        let coords = DMS3d::from_ddeg_angles(-33.8725, 151.211667, Some(-2.5));
+        assert_eq!(
+            coords.to_iso6709(Style::DDM).with_precision(2).to_string(),
+            "-3352.35+15112.70-2.5/"
+        );
+        assert_eq!(
+            coords.to_iso6709(Style::DMS).with_precision(0).to_string(),
+            "-335221+1511242-2.5/"
+        );
+        // rounding carries over to the upper fields
+        let coords = DMS3d::new(
+            DMS::new(8, 59, 59.9996, Some(Cardinal::North)),
+            DMS::new(2, 0, 0.0, Some(Cardinal::East)),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            coords.to_iso6709(Style::DMS).with_precision(3).to_string(),
+            "+090000.000+0020000.000/"
+        );
+        assert_eq!(
+            coords.to_iso6709(Style::DDM).with_precision(4).to_string(),
+            "+0900.0000+00200.0000/"
+        );
+    }
+    #[test]
+    fn test_round_trip() {
+        for s in [
+            "+404350.196-0735606.871/",
+            "-335221.05+1511242.0001+12.25CRSWGS_84/",
+            "+000000-1800000/",
+        ] {
+            let location = Location::parse(s).unwrap();
+            let mut iso = location.coords.to_iso6709(location.style);
+            if let Some(crs) = location.crs {
+                iso = iso.with_crs(crs);
+            }
+            assert_eq!(iso.to_string(), s);
+        }
+        for (s, precision) in [
+            ("+40.7306-073.9352+10.0CRSWGS_84/", 4),
+            ("-3352.35+15112.70/", 2),
+            ("+00.000000+000.000000/", 6),
+        ] {
+            let location = Location::parse(s).unwrap();
+            let iso = location
+                .coords
+                .to_iso6709(location.style)
+                .with_precision(precision)
+                .with_crs(location.crs.unwrap_or(""));
+            assert_eq!(iso.to_string(), s);
+        }
+        // shortest representation preserves every angle
+        let coords = DMS3d::from_ddeg_angles(48.858222, 2.2945, Some(330.75));
+        for style in [Style::Degrees, Style::DDM, Style::DMS] {
+            let parsed = DMS3d::from_iso6709(&coords.to_iso6709(style).to_string()).unwrap();
+            assert!((parsed.latitude.to_ddeg_angle() - 48.858222).abs() < 1E-12);
+            assert!((parsed.longitude.to_ddeg_angle() - 2.2945).abs() < 1E-12);
+            assert_eq!(parsed.altitude, Some(330.75));
+        }
+        let parsed = DMS3d::from_iso6709(&coords.to_iso6709(Style::DMS).to_string()).unwrap();
+        assert_eq!(parsed, coords);
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(feature = "kml")]
+use dms_coordinates::{
+    kml::{AltitudeMode, Document, Folder, Geometry, Placemark},
+    Cardinal, DMS3d, Error,
+};
+
+#[cfg(feature = "kml")]
+mod kml {
+    use super::*;
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/survey.kml");
+    fn assert_same_coords(a: &DMS3d, b: &DMS3d) {
+        assert!((a.latitude.to_ddeg_angle() - b.latitude.to_ddeg_angle()).abs() < 1E-12);
+        assert!((a.longitude.to_ddeg_angle() - b.longitude.to_ddeg_angle()).abs() < 1E-12);
+        assert_eq!(a.latitude.cardinal, b.latitude.cardinal);
+        assert_eq!(a.longitude.cardinal, b.longitude.cardinal);
+        assert_eq!(a.altitude, b.altitude);
+    }
+    fn assert_same_folders(a: &Folder, b: &Folder) {
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.placemarks.len(), b.placemarks.len());
+        for (a, b) in a.placemarks.iter().zip(b.placemarks.iter()) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.description, b.description);
+            assert_eq!(a.altitude_mode, b.altitude_mode);
+            match (&a.geometry, &b.geometry) {
+                (Geometry::Point(a), Geometry::Point(b)) => assert_same_coords(a, b),
+                (Geometry::LineString(a), Geometry::LineString(b)) => {
+                    assert_eq!(a.len(), b.len());
+                    for (a, b) in a.iter().zip(b.iter()) {
+                        assert_same_coords(a, b);
+                    }
+                }
+                _ => panic!("geometries differ"),
+            }
+        }
+        assert_eq!(a.folders.len(), b.folders.len());
+        for (a, b) in a.folders.iter().zip(b.folders.iter()) {
+            assert_same_folders(a, b);
+        }
+    }
+    fn assert_same_documents(a: &Document, b: &Document) {
+        let folder = |d: &Document| Folder {
+            name: d.name.clone(),
+            placemarks: d.placemarks.clone(),
+            folders: d.folders.clone(),
+        };
+        assert_same_folders(&folder(a), &folder(b));
+    }
+    #[test]
+    fn test_read() {
+        let file = std::fs::File::open(FIXTURE).unwrap();
+        let document = Document::read(file).unwrap();
+        assert_eq!(document.name.as_deref(), Some("Survey"));
+        assert_eq!(document.placemarks.len(), 1);
+        let camp = &document.placemarks[0];
+        assert_eq!(camp.name.as_deref(), Some("Base camp"));
+        assert_eq!(camp.description.as_deref(), Some("Tents & <b>supplies</b>"));
+        assert_eq!(camp.altitude_mode, AltitudeMode::Absolute);
+        match camp.geometry {
+            Geometry::Point(coords) => {
+                assert!((coords.latitude.to_ddeg_angle() - 45.8326).abs() < 1E-12);
+                assert!((coords.longitude.to_ddeg_angle() - 6.8652).abs() < 1E-12);
+                assert_eq!(coords.altitude, Some(4808.7));
+            }
+            _ => panic!("expecting a point"),
+        }
+
+        assert_eq!(document.folders.len(), 1);
+        let day = &document.folders[0];
+        assert_eq!(day.name.as_deref(), Some("Day 1"));
+        // polygons are not supported
+        assert_eq!(day.placemarks.len(), 1);
+        let approach = &day.placemarks[0];
+        assert_eq!(approach.altitude_mode, AltitudeMode::RelativeToGround);
+        match &approach.geometry {
+            Geometry::LineString(path) => {
+                assert_eq!(path.len(), 3);
+                assert_eq!(path[2].altitude, Some(3.0));
+            }
+            _ => panic!("expecting a line string"),
+        }
+
+        let samples = &day.folders[0];
+        assert_eq!(samples.name.as_deref(), Some("Samples"));
+        let sample = &samples.placemarks[0];
+        // gx:altitudeMode extension is ignored
+        assert_eq!(sample.altitude_mode, AltitudeMode::ClampToGround);
+        match sample.geometry {
+            Geometry::Point(coords) => {
+                assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+                assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+                assert_eq!(coords.altitude, None);
+            }
+            _ => panic!("expecting a point"),
+        }
+
+        let names: Vec<_> = document
+            .all_placemarks()
+            .iter()
+            .map(|p| p.name.as_deref().unwrap_or(""))
+            .collect();
+        assert_eq!(names, ["Base camp", "Approach", "Sample A"]);
+    }
+    #[test]
+    fn test_round_trip() {
+        let file = std::fs::File::open(FIXTURE).unwrap();
+        let document = Document::read(file).unwrap();
+        let mut buffer = Vec::new();
+        document.write(&mut buffer).unwrap();
+        let parsed = Document::read(buffer.as_slice()).unwrap();
+        assert_same_documents(&parsed, &document);
+    }
+    #[test]
+    fn test_write() {
+        let summit = DMS3d::from_ddeg_angles(45.8326, 6.8652, Some(4808.7));
+        let path = vec![
+            DMS3d::from_ddeg_angles(45.8326, 6.8652, None),
+            DMS3d::from_ddeg_angles(-33.4489, -70.6693, None),
+        ];
+        let placemark = Placemark::from(summit).with_name("Summit");
+        assert_eq!(placemark.altitude_mode, AltitudeMode::Absolute);
+        let document = Document {
+            name: Some(String::from("Expedition")),
+            placemarks: vec![placemark],
+            folders: vec![Folder {
+                name: Some(String::from("Tracks")),
+                placemarks: vec![Placemark::from(path)],
+                folders: Vec::new(),
+            }],
+        };
+        assert_eq!(
+            document.folders[0].placemarks[0].altitude_mode,
+            AltitudeMode::ClampToGround
+        );
+        let mut buffer = Vec::new();
+        document.write(&mut buffer).unwrap();
+        let content = String::from_utf8(buffer.clone()).unwrap();
+        assert!(content.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(content.contains("<kml xmlns=\"http://www.opengis.net/kml/2.2\">"));
+        assert!(content.contains("<altitudeMode>absolute</altitudeMode>"));
+        assert!(content.contains("<coordinates>6.8652,45.8326,4808.7</coordinates>"));
+        assert!(content.contains("<coordinates>6.8652,45.8326 -70.6693,-33.4489</coordinates>"));
+        assert_same_documents(&Document::read(buffer.as_slice()).unwrap(), &document);
+    }
+    #[test]
+    fn test_errors() {
+        for content in [
+            "<kml><Document><Placemark><Point><coordinates>6.8,45.8,1,2</coordinates></Point></Placemark></Document></kml>",
+            "<kml><Document><Placemark><Point><coordinates>6.8</coordinates></Point></Placemark></Document></kml>",
+            "<kml><Document><Placemark><Point><altitudeMode>above</altitudeMode></Point></Placemark></Document></kml>",
+            "<kml><Document><Placemark>",
+        ] {
+            assert!(matches!(
+                Document::read(content.as_bytes()),
+                Err(Error::KmlError)
+            ));
+        }
+        assert!(matches!(
+            Document::read(
+                "<kml><Placemark><Point><coordinates>6.8,95.0</coordinates></Point></Placemark></kml>"
+                    .as_bytes()
+            ),
+            Err(Error::InvalidLatitude)
+        ));
+    }
+}
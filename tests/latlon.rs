@@ -0,0 +1,200 @@
+use core::convert::TryFrom;
+use dms_coordinates::{Cardinal, DMS3d, Error, Latitude, Longitude, Position, DMS};
+
+#[cfg(test)]
+mod latlon {
+    use super::*;
+    #[test]
+    fn test_from_ddeg() {
+        let lat = Latitude::try_from(-33.8725).unwrap();
+        assert_eq!(lat.cardinal(), Cardinal::South);
+        assert!((lat.to_ddeg_angle() + 33.8725).abs() < 1E-12);
+        let lon = Longitude::try_from(151.211667).unwrap();
+        assert_eq!(lon.cardinal(), Cardinal::East);
+        assert!((f64::from(lon) - 151.211667).abs() < 1E-12);
+
+        assert!(Latitude::try_from(90.0).is_ok());
+        assert!(Longitude::try_from(-180.0).is_ok());
+        assert!(matches!(
+            Latitude::try_from(90.5),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            Latitude::try_from(f64::NAN),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            Longitude::try_from(-180.5),
+            Err(Error::InvalidLongitude)
+        ));
+    }
+    #[test]
+    fn test_from_dms() {
+        let dms = DMS::new(40, 43, 50.196, Some(Cardinal::North));
+        let lat = Latitude::try_from(dms).unwrap();
+        assert_eq!(DMS::from(lat), dms);
+        assert_eq!(lat.to_dms(), dms);
+        assert_eq!(lat.to_string(), dms.to_string());
+
+        let mut angle = dms;
+        angle.cardinal = None;
+        assert!(matches!(
+            Latitude::try_from(angle),
+            Err(Error::MissingLatitude)
+        ));
+        assert!(matches!(
+            Longitude::try_from(angle),
+            Err(Error::MissingLongitude)
+        ));
+        assert!(matches!(
+            Longitude::try_from(dms),
+            Err(Error::InvalidLongitude)
+        ));
+        assert!(matches!(
+            Latitude::try_from(dms.with_cardinal(Cardinal::NorthEast)),
+            Err(Error::InvalidLatitude)
+        ));
+        let out_of_range = DMS {
+            degrees: 90,
+            minutes: 0,
+            seconds: 0.5,
+            cardinal: Some(Cardinal::South),
+            negative: false,
+        };
+        assert!(matches!(
+            Latitude::try_from(out_of_range),
+            Err(Error::InvalidLatitude)
+        ));
+        let west = out_of_range.with_cardinal(Cardinal::West);
+        assert!(Longitude::try_from(west).is_ok());
+        let mut invalid_minutes = west;
+        invalid_minutes.minutes = 60;
+        assert!(matches!(
+            Longitude::try_from(invalid_minutes),
+            Err(Error::InvalidLongitude)
+        ));
+    }
+    #[test]
+    fn test_latitude_arithmetics() {
+        let lat = Latitude::try_from(80.0).unwrap();
+        assert_eq!((lat + 5.0).unwrap(), Latitude::try_from(85.0).unwrap());
+        // going over the north pole
+        let over = (lat + 20.0).unwrap();
+        assert!((over.to_ddeg_angle() - 80.0).abs() < 1E-9);
+        assert_eq!(over.cardinal(), Cardinal::North);
+        // going over the south pole
+        let under = (Latitude::try_from(-85.0).unwrap() - 10.0).unwrap();
+        assert!((under.to_ddeg_angle() + 85.0).abs() < 1E-9);
+        assert_eq!(under.cardinal(), Cardinal::South);
+        // crossing the equator
+        let mut lat = Latitude::try_from(10.0).unwrap();
+        lat -= 25.0;
+        assert!((lat.to_ddeg_angle() + 15.0).abs() < 1E-9);
+        assert_eq!(lat.cardinal(), Cardinal::South);
+        lat += 15.0;
+        assert!(lat.to_ddeg_angle().abs() < 1E-9);
+
+        let offset = DMS::new(0, 30, 0.0, Some(Cardinal::South));
+        let lat = (Latitude::try_from(45.0).unwrap() + offset).unwrap();
+        assert!((lat.to_ddeg_angle() - 44.5).abs() < 1E-9);
+        let a = Latitude::try_from(45.0).unwrap();
+        let b = Latitude::try_from(-10.0).unwrap();
+        assert!((a - b - 55.0).abs() < 1E-9);
+    }
+    #[test]
+    fn test_longitude_arithmetics() {
+        // crossing the antimeridian eastward
+        let lon = (Longitude::try_from(170.0).unwrap() + 20.0).unwrap();
+        assert!((lon.to_ddeg_angle() + 170.0).abs() < 1E-9);
+        assert_eq!(lon.cardinal(), Cardinal::West);
+        // and westward
+        let mut lon = Longitude::try_from(-175.0).unwrap();
+        lon -= 10.0;
+        assert!((lon.to_ddeg_angle() - 175.0).abs() < 1E-9);
+        lon += 5.0;
+        assert!((lon.to_ddeg_angle() - 180.0).abs() < 1E-9);
+        // full turns
+        let lon = (Longitude::try_from(2.0).unwrap() + 720.0).unwrap();
+        assert!((lon.to_ddeg_angle() - 2.0).abs() < 1E-9);
+
+        let offset = DMS::new(1, 30, 0.0, None);
+        let lon = (Longitude::try_from(179.0).unwrap() + offset).unwrap();
+        assert!((lon.to_ddeg_angle() + 179.5).abs() < 1E-9);
+
+        // shortest difference goes through the antimeridian
+        let a = Longitude::try_from(-170.0).unwrap();
+        let b = Longitude::try_from(170.0).unwrap();
+        assert!((a - b - 20.0).abs() < 1E-9);
+        assert!((b - a + 20.0).abs() < 1E-9);
+    }
+    #[test]
+    fn test_non_finite_arithmetics() {
+        let mut lat = Latitude::try_from(10.0).unwrap();
+        assert!(matches!(lat + f64::NAN, Err(Error::InvalidLatitude)));
+        assert!(matches!(lat - f64::INFINITY, Err(Error::InvalidLatitude)));
+        let nan = DMS::new(0, 0, f64::NAN, None);
+        assert!(matches!(lat + nan, Err(Error::InvalidLatitude)));
+        lat += f64::NAN;
+        assert_eq!(lat, Latitude::try_from(10.0).unwrap());
+
+        let mut lon = Longitude::try_from(10.0).unwrap();
+        assert!(matches!(lon + f64::NAN, Err(Error::InvalidLongitude)));
+        assert!(matches!(
+            lon - f64::NEG_INFINITY,
+            Err(Error::InvalidLongitude)
+        ));
+        lon -= f64::INFINITY;
+        assert_eq!(lon, Longitude::try_from(10.0).unwrap());
+    }
+    #[test]
+    fn test_dms3d() {
+        let lat = Latitude::try_from(48.858222).unwrap();
+        let lon = Longitude::try_from(2.2945).unwrap();
+        let coords = DMS3d::from_lat_lon(lat, lon, Some(330.0));
+        assert_eq!(coords.latitude, lat.to_dms());
+        assert_eq!(coords.longitude, lon.to_dms());
+        assert_eq!(coords.altitude, Some(330.0));
+        assert_eq!(coords.lat_lon().unwrap(), (lat, lon));
+        assert_eq!(DMS3d::from((lat, lon)).altitude, None);
+
+        let mut invalid = coords;
+        invalid.latitude.cardinal = Some(Cardinal::East);
+        assert!(matches!(invalid.lat_lon(), Err(Error::InvalidLatitude)));
+        let mut invalid = coords;
+        invalid.longitude.cardinal = None;
+        assert!(matches!(invalid.lat_lon(), Err(Error::MissingLongitude)));
+    }
+    #[test]
+    fn test_position() {
+        let lat = Latitude::try_from(48.858222).unwrap();
+        let lon = Longitude::try_from(2.2945).unwrap();
+        let position = Position::new(lat, lon, Some(330.0));
+        let coords = DMS3d::from(position);
+        assert_eq!(coords, DMS3d::from_lat_lon(lat, lon, Some(330.0)));
+        assert_eq!(position.to_string(), coords.to_string());
+        assert_eq!(Position::try_from(coords).unwrap(), position);
+
+        let mut invalid = coords;
+        invalid.latitude.cardinal = None;
+        assert!(matches!(
+            Position::try_from(invalid),
+            Err(Error::MissingLatitude)
+        ));
+        let default = Position::default();
+        assert_eq!(default.latitude.to_ddeg_angle(), 0.0);
+        assert_eq!(default.altitude, None);
+    }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let lat = Latitude::try_from(-33.8725).unwrap();
+        let json = serde_json::to_string(&lat).unwrap();
+        assert_eq!(serde_json::from_str::<Latitude>(&json).unwrap(), lat);
+        // a southern angle is not a longitude
+        assert!(serde_json::from_str::<Longitude>(&json).is_err());
+
+        let position = Position::new(lat, Longitude::try_from(151.2).unwrap(), None);
+        let json = serde_json::to_string(&position).unwrap();
+        assert_eq!(serde_json::from_str::<Position>(&json).unwrap(), position);
+    }
+}
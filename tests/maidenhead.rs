@@ -0,0 +1,80 @@
+use dms_coordinates::{DMS3d, Error, Maidenhead};
+
+#[cfg(test)]
+mod maidenhead {
+    use super::*;
+    #[test]
+    fn test_encoding() {
+        let munich = DMS3d::from_ddeg_angles(48.14666, 11.60833, None);
+        let expected = ["JN", "JN58", "JN58td", "JN58td25", "JN58td25xe"];
+        for (i, expected) in expected.iter().enumerate() {
+            let locator = munich.to_maidenhead(2 * (i + 1)).unwrap();
+            assert_eq!(locator.to_string(), *expected);
+            assert_eq!(locator.len(), expected.len());
+        }
+        let w1aw = DMS3d::from_ddeg_angles(41.714775, -72.727260, None);
+        assert_eq!(w1aw.to_maidenhead(6).unwrap().as_str(), "FN31pr");
+        // edges
+        let cases = [
+            ((0.0, 0.0), "JJ00aa00aa"),
+            ((-90.0, -180.0), "AA00aa00aa"),
+            ((90.0, 180.0), "RR99xx99xx"),
+        ];
+        for ((lat, lon), expected) in cases {
+            let locator = Maidenhead::from_ddeg_angles(lat, lon, 10).unwrap();
+            assert_eq!(locator.to_string(), expected);
+        }
+        for length in [0, 3, 12] {
+            assert!(matches!(
+                munich.to_maidenhead(length),
+                Err(Error::InvalidLocator)
+            ));
+        }
+    }
+    #[test]
+    fn test_decoding() {
+        let locator: Maidenhead = "fn31PR".parse().unwrap();
+        assert_eq!(locator.to_string(), "FN31pr");
+        let (sw, ne) = locator.bounds();
+        assert!((sw.latitude.to_ddeg_angle() - 41.708333).abs() < 1E-6);
+        assert!((sw.longitude.to_ddeg_angle() + 72.75).abs() < 1E-6);
+        assert!((ne.latitude.to_ddeg_angle() - 41.75).abs() < 1E-6);
+        assert!((ne.longitude.to_ddeg_angle() + 72.666667).abs() < 1E-6);
+        let centre = locator.centre();
+        assert!((centre.latitude.to_ddeg_angle() - 41.729167).abs() < 1E-6);
+        assert!((centre.longitude.to_ddeg_angle() + 72.708333).abs() < 1E-6);
+        // centre encodes back to the same square
+        for locator in [
+            "JJ",
+            "FN31",
+            "JN58td",
+            "JN58td25",
+            "JN58td25xe",
+            "RR99xx99xx",
+        ] {
+            let locator: Maidenhead = locator.parse().unwrap();
+            assert_eq!(
+                locator.centre().to_maidenhead(locator.len()).unwrap(),
+                locator
+            );
+        }
+        for invalid in ["", "F", "SN", "FNA1", "FN31py", "FN31pr2", "FN31pr21rn00"] {
+            assert!(matches!(
+                invalid.parse::<Maidenhead>(),
+                Err(Error::InvalidLocator)
+            ));
+        }
+    }
+    #[test]
+    fn test_distance_azimuth() {
+        let munich: Maidenhead = "JN58td".parse().unwrap();
+        let w1aw: Maidenhead = "FN31pr".parse().unwrap();
+        let distance = munich.distance(&w1aw);
+        assert_eq!(distance, munich.centre().distance(w1aw.centre()));
+        assert!((distance - 6_353_097.5).abs() < 1.0);
+        let azimuth = munich.azimuth(&w1aw);
+        assert!((azimuth - 297.69).abs() < 1E-2);
+        assert!((w1aw.azimuth(&munich) - 50.0).abs() < 10.0);
+        assert_eq!(munich.distance(&munich), 0.0);
+    }
+}
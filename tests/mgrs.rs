@@ -0,0 +1,144 @@
+use dms_coordinates::{dms::Scale, mgrs::MGRS, utm::UPS, DMS3d, Ellipsoid, Error};
+
+#[cfg(test)]
+mod mgrs {
+    use super::*;
+    #[test]
+    fn test_encoding() {
+        let cases = [
+            ((51.477881, -0.001469), "30UYC0821507233"),
+            ((48.8582, 2.2945), "31UDQ4825111932"),
+            ((33.3, 44.4), "38SMB4414084706"),
+            ((-33.857, 151.215), "56HLH3487352266"),
+            ((-1.0, -48.5), "22MGD7823489363"),
+            ((72.0, 20.0), "33XXV7227596086"),
+            ((0.0, 180.0), "1NAA6602100000"),
+            // UPS
+            ((90.0, 0.0), "ZAH0000000000"),
+            ((-90.0, 0.0), "BAN0000000000"),
+            ((84.5, -10.0), "YYA9388698202"),
+            ((-85.0, 100.0), "BHM4701803545"),
+        ];
+        for ((lat, lon), expected) in cases {
+            let mgrs = MGRS::from_geodetic(lat, lon, 5).unwrap();
+            assert_eq!(mgrs.to_string(), expected);
+            let parsed: MGRS = expected.parse().unwrap();
+            assert_eq!(parsed, mgrs);
+        }
+    }
+    #[test]
+    fn test_precision() {
+        let coords = DMS3d::from_ddeg_angles(33.3, 44.4, None);
+        let expected = [
+            "38SMB",
+            "38SMB48",
+            "38SMB4484",
+            "38SMB441847",
+            "38SMB44148470",
+            "38SMB4414084706",
+        ];
+        for (precision, expected) in expected.iter().enumerate() {
+            let mgrs = coords.to_mgrs(precision as u8).unwrap();
+            assert_eq!(mgrs.to_string(), *expected);
+        }
+        let mgrs = coords.to_mgrs(9).unwrap();
+        assert_eq!(mgrs.precision, 5);
+        let mgrs = coords.to_mgrs(3).unwrap();
+        assert_eq!(format!("{:#}", mgrs), "38S MB 441 847");
+    }
+    #[test]
+    fn test_decoding() {
+        let scales = [
+            (0, 100_000.0, Scale::Country),
+            (1, 10_000.0, Scale::LargeCity),
+            (2, 1_000.0, Scale::City),
+            (3, 100.0, Scale::Neighborhood),
+            (4, 10.0, Scale::Street),
+            (5, 1.0, Scale::Tree),
+        ];
+        for (precision, size, scale) in scales {
+            let mgrs = MGRS::from_geodetic(48.8582, 2.2945, precision).unwrap();
+            assert_eq!(mgrs.size(), size);
+            let (centre, s) = DMS3d::from_mgrs(mgrs).unwrap();
+            assert_eq!(s, scale);
+            assert!(centre.altitude.is_none());
+            // centre lies within the square
+            let utm = centre.to_utm_zone(31).unwrap();
+            let e0 = 400_000.0 + mgrs.easting as f64;
+            let n0 = 5_400_000.0 + mgrs.northing as f64;
+            assert!((utm.easting - e0 - size / 2.0).abs() < 1E-6);
+            assert!((utm.northing - n0 - size / 2.0).abs() < 1E-6);
+        }
+        // rows repeat every 2000 km
+        for (lat, lon) in [
+            (-79.5, 10.0),
+            (-8.1, -70.0),
+            (-0.1, 33.0),
+            (0.1, 33.0),
+            (83.9, 1.0),
+        ] {
+            let mgrs = MGRS::from_geodetic(lat, lon, 5).unwrap();
+            let (latitude, longitude) = mgrs.to_geodetic().unwrap();
+            assert!((latitude - lat).abs() < 1E-4);
+            assert!((longitude - lon).abs() < 1E-4);
+        }
+        // polar areas
+        for (lat, lon) in [(88.0, 45.0), (85.0, -179.0), (-80.5, -60.0), (-89.0, 120.0)] {
+            let mgrs = MGRS::from_geodetic(lat, lon, 5).unwrap();
+            assert!(mgrs.zone.is_none());
+            let (latitude, longitude) = mgrs.to_geodetic().unwrap();
+            assert!((latitude - lat).abs() < 1E-4);
+            assert_eq!(MGRS::from_geodetic(latitude, longitude, 5).unwrap(), mgrs);
+        }
+    }
+    #[test]
+    fn test_ups() {
+        // scale factor 0.994 at the pole
+        let ups = UPS::project(Ellipsoid::WGS84, 89.9, 0.0);
+        let arc = Ellipsoid::WGS84.meridian_arc(90.0_f64.to_radians())
+            - Ellipsoid::WGS84.meridian_arc(89.9_f64.to_radians());
+        assert_eq!(ups.easting, 2_000_000.0);
+        assert!((2_000_000.0 - ups.northing - 0.994 * arc).abs() < 1E-2);
+        let ups = UPS::project(Ellipsoid::WGS84, -85.0, 90.0);
+        assert!(!ups.northern);
+        let (latitude, longitude) = ups.unproject(Ellipsoid::WGS84);
+        assert!((latitude + 85.0).abs() < 1E-10);
+        assert!((longitude - 90.0).abs() < 1E-10);
+    }
+    #[test]
+    fn test_parsing() {
+        let mgrs: MGRS = "18t wl 83951 07251".parse().unwrap();
+        assert_eq!(mgrs.zone, Some(18));
+        assert_eq!(mgrs.band, 'T');
+        assert_eq!(mgrs.square, ('W', 'L'));
+        assert_eq!(mgrs.easting, 83_951);
+        assert_eq!(mgrs.northing, 7_251);
+        assert_eq!(mgrs.precision, 5);
+        let mgrs: MGRS = "4QFJ1234".parse().unwrap();
+        assert_eq!(mgrs.zone, Some(4));
+        assert_eq!(mgrs.easting, 12_000);
+        assert_eq!(mgrs.northing, 34_000);
+        assert_eq!(mgrs.to_string(), "4QFJ1234");
+
+        assert!(matches!("".parse::<MGRS>(), Err(Error::ParsingError(_))));
+        assert!(matches!(
+            "18TWL839510725".parse::<MGRS>(),
+            Err(Error::ParsingError(_))
+        ));
+        assert!(matches!(
+            "18TWL83951072510".parse::<MGRS>(),
+            Err(Error::ParsingError(_))
+        ));
+        assert!(matches!("61TWL".parse::<MGRS>(), Err(Error::InvalidZone)));
+        assert!(matches!("18IWL".parse::<MGRS>(), Err(Error::InvalidBand)));
+        assert!(matches!("TWL".parse::<MGRS>(), Err(Error::InvalidZone)));
+        assert!(matches!("18ZWL".parse::<MGRS>(), Err(Error::InvalidBand)));
+        // malformed 100 km squares
+        assert!(matches!("18TAL".parse::<MGRS>(), Err(Error::InvalidSquare)));
+        assert!(matches!("18TWW".parse::<MGRS>(), Err(Error::InvalidSquare)));
+        assert!(matches!("18TWO".parse::<MGRS>(), Err(Error::InvalidSquare)));
+        assert!(matches!("ZDH".parse::<MGRS>(), Err(Error::InvalidSquare)));
+        assert!(matches!("AAN".parse::<MGRS>(), Err(Error::InvalidSquare)));
+        assert!(matches!("ZAQ".parse::<MGRS>(), Err(Error::InvalidSquare)));
+    }
+}
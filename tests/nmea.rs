@@ -0,0 +1,242 @@
+use dms_coordinates::{
+    nmea::{checksum, FixQuality, NmeaError, Sentence, SentenceKind, Time},
+    Cardinal, DMS3d, Error, DMS,
+};
+
+#[cfg(test)]
+mod nmea {
+    use super::*;
+    #[test]
+    fn test_gga() {
+        let sentence = Sentence::parse(
+            b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n",
+        )
+        .unwrap();
+        assert_eq!(sentence.talker, *b"GP");
+        assert_eq!(sentence.kind, SentenceKind::GGA);
+        let coords = sentence.coords.unwrap();
+        assert_eq!(coords.latitude.degrees, 48);
+        assert_eq!(coords.latitude.minutes, 7);
+        assert!((coords.latitude.seconds - 2.28).abs() < 1E-6);
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::North));
+        assert_eq!(coords.longitude.degrees, 11);
+        assert_eq!(coords.longitude.minutes, 31);
+        assert!(coords.longitude.seconds.abs() < 1E-6);
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::East));
+        assert_eq!(coords.altitude, Some(545.4));
+        let time = sentence.time.unwrap();
+        assert_eq!((time.hours, time.minutes, time.seconds), (12, 35, 19.0));
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Gps));
+        assert_eq!(sentence.satellites, Some(8));
+        assert_eq!(sentence.geoid_separation, Some(46.9));
+        assert!(sentence.speed.is_none());
+        // no fix
+        let sentence: Sentence = "$GPGGA,,,,,,0,00,,,M,,M,,*66".parse().unwrap();
+        assert!(sentence.coords.is_none());
+        assert!(sentence.time.is_none());
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Invalid));
+    }
+    #[test]
+    fn test_rmc() {
+        let sentence: Sentence =
+            "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A"
+                .parse()
+                .unwrap();
+        assert_eq!(sentence.kind, SentenceKind::RMC);
+        let coords = sentence.coords.unwrap();
+        assert!((coords.latitude.to_ddeg_angle() - 48.1173).abs() < 1E-9);
+        assert!((coords.longitude.to_ddeg_angle() - 11.516_666_667).abs() < 1E-9);
+        assert!(coords.altitude.is_none());
+        assert_eq!(sentence.speed, Some(22.4));
+        assert_eq!(sentence.course, Some(84.4));
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Gps));
+        // southern hemisphere, mode indicator
+        let sentence: Sentence =
+            "$GPRMC,081836,A,3751.65,S,14507.36,E,000.0,360.0,130998,011.3,E,D*0A"
+                .parse()
+                .unwrap();
+        let coords = sentence.coords.unwrap();
+        assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+        assert!((coords.latitude.to_ddeg_angle() + 37.860_833_333).abs() < 1E-9);
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Dgps));
+    }
+    #[test]
+    fn test_gll() {
+        let sentence: Sentence = "$GPGLL,4916.45,N,12311.12,W,225444,A,*1D".parse().unwrap();
+        assert_eq!(sentence.kind, SentenceKind::GLL);
+        let coords = sentence.coords.unwrap();
+        assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+        assert!((coords.latitude.to_ddeg_angle() - 49.274_166_667).abs() < 1E-9);
+        assert!((coords.longitude.to_ddeg_angle() + 123.185_333_333).abs() < 1E-9);
+        let time = sentence.time.unwrap();
+        assert_eq!((time.hours, time.minutes, time.seconds), (22, 54, 44.0));
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Gps));
+    }
+    #[test]
+    fn test_gns() {
+        let sentence: Sentence =
+            "$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70"
+                .parse()
+                .unwrap();
+        assert_eq!(sentence.talker, *b"GN");
+        assert_eq!(sentence.kind, SentenceKind::GNS);
+        let coords = sentence.coords.unwrap();
+        assert!((coords.latitude.to_ddeg_angle() + 43.544_877).abs() < 1E-6);
+        assert!((coords.longitude.to_ddeg_angle() - 172.591_425).abs() < 1E-6);
+        assert_eq!(coords.altitude, Some(25.63));
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Rtk));
+        assert_eq!(sentence.satellites, Some(13));
+        assert_eq!(sentence.geoid_separation, Some(11.24));
+        let sentence: Sentence = "$GNGNS,225444.00,,,,,NN,00,,,,,*7C".parse().unwrap();
+        assert!(sentence.coords.is_none());
+        assert_eq!(sentence.fix_quality, Some(FixQuality::Invalid));
+    }
+    #[test]
+    fn test_errors() {
+        assert_eq!(checksum(b"GPGLL,4916.45,N,12311.12,W,225444,A,"), 0x1D);
+        let cases = [
+            ("", NmeaError::InvalidFormat),
+            (
+                "GPGLL,4916.45,N,12311.12,W,225444,A,*1D",
+                NmeaError::InvalidFormat,
+            ),
+            (
+                "$GPGLL,4916.45,N,12311.12,W,225444,A,",
+                NmeaError::InvalidFormat,
+            ),
+            (
+                "$GPGLL,4916.45,N,12311.12,W,225444,A,*1E",
+                NmeaError::ChecksumMismatch,
+            ),
+            (
+                "$GPGLL,4916.46,N,12311.12,W,225444,A,*1D",
+                NmeaError::ChecksumMismatch,
+            ),
+            ("$GPGSV,1,1,00*79", NmeaError::UnsupportedSentence),
+            ("$GPGLL,4916.45,N,12311.12*0A", NmeaError::InvalidFormat),
+            (
+                "$GPGLL,4976.45,N,12311.12,W,225444,A,*1B",
+                NmeaError::InvalidField,
+            ),
+            (
+                "$GPGLL,4916.45,E,12311.12,W,225444,A,*16",
+                NmeaError::InvalidField,
+            ),
+            (
+                "$GPGLL,4916.45,N,12311.12,W,255444,A,*1A",
+                NmeaError::InvalidField,
+            ),
+            // only decimal numbers are accepted
+            (
+                "$GPGLL,NaN,N,12311.12,W,225444,A,*59",
+                NmeaError::InvalidField,
+            ),
+            (
+                "$GPGLL,4916.45,N,inf,W,225444,A,*61",
+                NmeaError::InvalidField,
+            ),
+            (
+                "$GPGLL,4.9e3,N,12311.12,W,225444,A,*4D",
+                NmeaError::InvalidField,
+            ),
+            (
+                "$GPGLL,4916.45,N,12311.12,W,2254NaN,A,*7C",
+                NmeaError::InvalidField,
+            ),
+        ];
+        for (sentence, expected) in cases {
+            match sentence.parse::<Sentence>() {
+                Err(Error::NmeaError(e)) => assert_eq!(e, expected, "{}", sentence),
+                other => panic!("{}: unexpected {:?}", sentence, other),
+            }
+        }
+    }
+    #[test]
+    fn test_wpl() {
+        let sentence: Sentence = "$GPWPL,4917.16,N,12310.64,W,003*65".parse().unwrap();
+        assert_eq!(sentence.kind, SentenceKind::WPL);
+        let coords = sentence.coords.unwrap();
+        assert!((coords.latitude.to_ddeg_angle() - 49.286).abs() < 1E-9);
+        assert!((coords.longitude.to_ddeg_angle() + 123.177_333_333).abs() < 1E-9);
+    }
+    #[test]
+    fn test_generation() {
+        let coords = DMS3d::from_ddeg_angles(48.1173, 11.516666667, Some(545.4));
+        let time = Time {
+            hours: 12,
+            minutes: 35,
+            seconds: 19.0,
+        };
+        let cases = [
+            (
+                SentenceKind::GGA,
+                "$GPGGA,123519.00,4807.0380,N,01131.0000,E,1,,,545.4,M,,,,*1E",
+            ),
+            (
+                SentenceKind::RMC,
+                "$GPRMC,123519.00,A,4807.0380,N,01131.0000,E,22.4,84.4,,,,A*5D",
+            ),
+            (
+                SentenceKind::GLL,
+                "$GPGLL,4807.0380,N,01131.0000,E,123519.00,A,A*66",
+            ),
+            (SentenceKind::WPL, "$GPWPL,4807.0380,N,01131.0000,E,WPT1*2B"),
+        ];
+        for (kind, expected) in cases {
+            let sentence = coords
+                .to_nmea(kind)
+                .with_time(time)
+                .with_speed(22.4)
+                .with_course(84.4)
+                .with_name("WPT1");
+            assert_eq!(sentence.to_string(), expected);
+            // generated sentences parse back
+            let parsed: Sentence = expected.parse().unwrap();
+            assert_eq!(parsed.kind, kind);
+            let parsed = parsed.coords.unwrap();
+            assert!((parsed.latitude.to_ddeg_angle() - 48.1173).abs() < 1E-9);
+            assert!((parsed.longitude.to_ddeg_angle() - 11.516_666_667).abs() < 1E-8);
+        }
+        let gga = coords
+            .to_nmea(SentenceKind::GGA)
+            .with_talker(*b"GN")
+            .with_fix_quality(FixQuality::Rtk)
+            .with_satellites(9);
+        let parsed: Sentence = gga.to_string().parse().unwrap();
+        assert_eq!(parsed.talker, *b"GN");
+        assert_eq!(parsed.fix_quality, Some(FixQuality::Rtk));
+        assert_eq!(parsed.satellites, Some(9));
+        assert_eq!(parsed.coords.unwrap().altitude, Some(545.4));
+    }
+    #[test]
+    fn test_generation_rounding() {
+        // minutes rounding carries over to degrees, hemisphere from the cardinal
+        let coords = DMS3d {
+            latitude: DMS::new(47, 59, 59.999, Some(Cardinal::South)),
+            longitude: DMS::new(7, 59, 59.9999, Some(Cardinal::West)),
+            altitude: None,
+        };
+        let gll = coords
+            .to_nmea(SentenceKind::GLL)
+            .with_fix_quality(FixQuality::Invalid)
+            .with_time(Time {
+                hours: 10,
+                minutes: 59,
+                seconds: 59.999,
+            });
+        assert_eq!(
+            gll.to_string(),
+            "$GPGLL,4800.0000,S,00800.0000,W,110000.00,V,N*7A"
+        );
+        let coords = DMS3d {
+            latitude: DMS::new(0, 30, 0.0, Some(Cardinal::North)),
+            longitude: DMS::new(179, 59, 59.9, Some(Cardinal::East)),
+            altitude: None,
+        };
+        let wpl = coords.to_nmea(SentenceKind::WPL).with_name("A");
+        let wpl = wpl.to_string();
+        assert!(wpl.starts_with("$GPWPL,0030.0000,N,17959.9983,E,A*"));
+        let parsed: Sentence = wpl.parse().unwrap();
+        assert!(parsed.coords.is_some());
+    }
+}
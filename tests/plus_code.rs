@@ -0,0 +1,135 @@
+use dms_coordinates::{DMS3d, Error, PlusCode};
+
+#[cfg(test)]
+mod plus_code {
+    use super::*;
+    #[test]
+    fn test_encoding() {
+        let cases = [
+            ((20.375, 2.775, 6), "7FG49Q00+"),
+            ((20.3700625, 2.7821875, 10), "7FG49QCJ+2V"),
+            ((20.3701125, 2.782234375, 11), "7FG49QCJ+2VX"),
+            ((20.3701135, 2.78223535156, 13), "7FG49QCJ+2VXGJ"),
+            ((47.0000625, 8.0000625, 10), "8FVC2222+22"),
+            ((-41.2730625, 174.7859375, 10), "4VCPPQGP+Q9"),
+            ((0.5, -179.5, 4), "62G20000+"),
+            ((-89.5, -179.5, 4), "22220000+"),
+            ((-89.9999375, -179.9999375, 10), "22222222+22"),
+            ((0.5, 179.5, 4), "6VGX0000+"),
+            ((1.0, 1.0, 11), "6FH32222+222"),
+            // poles and antimeridian
+            ((90.0, 1.0, 4), "CFX30000+"),
+            ((92.0, 1.0, 4), "CFX30000+"),
+            ((90.0, 1.0, 10), "CFX3X2X2+X2"),
+            ((1.0, 180.0, 4), "62H20000+"),
+            ((1.0, 181.0, 4), "62H30000+"),
+        ];
+        for ((lat, lon, length), expected) in cases {
+            let code = PlusCode::from_ddeg_angles(lat, lon, length).unwrap();
+            assert_eq!(code.to_string(), expected);
+            assert!(code.is_full());
+        }
+        let coords = DMS3d::from_ddeg_angles(47.365590, 8.524997, None);
+        assert_eq!(coords.to_plus_code(10).unwrap().as_str(), "8FVC9G8F+6X");
+        for length in [0, 1, 3, 9, 16] {
+            assert!(matches!(
+                coords.to_plus_code(length),
+                Err(Error::InvalidPlusCode)
+            ));
+        }
+    }
+    #[test]
+    fn test_decoding() {
+        let code: PlusCode = "8fvc9g8f+6x".parse().unwrap();
+        assert_eq!(code.to_string(), "8FVC9G8F+6X");
+        assert_eq!(code.len(), 10);
+        let (sw, ne) = code.bounds().unwrap();
+        assert!((sw.latitude.to_ddeg_angle() - 47.3655).abs() < 1E-9);
+        assert!((sw.longitude.to_ddeg_angle() - 8.524875).abs() < 1E-9);
+        assert!((ne.latitude.to_ddeg_angle() - 47.365625).abs() < 1E-9);
+        assert!((ne.longitude.to_ddeg_angle() - 8.525).abs() < 1E-9);
+        let centre = code.centre().unwrap();
+        assert!((centre.latitude.to_ddeg_angle() - 47.3655625).abs() < 1E-9);
+        assert!((centre.longitude.to_ddeg_angle() - 8.5249375).abs() < 1E-9);
+        let code: PlusCode = "7FG49QCJ+2VX".parse().unwrap();
+        let (sw, ne) = code.bounds().unwrap();
+        assert!((sw.latitude.to_ddeg_angle() - 20.3701).abs() < 1E-9);
+        assert!((sw.longitude.to_ddeg_angle() - 2.78221875).abs() < 1E-9);
+        assert!((ne.latitude.to_ddeg_angle() - 20.370125).abs() < 1E-9);
+        assert!((ne.longitude.to_ddeg_angle() - 2.78225).abs() < 1E-9);
+        let code: PlusCode = "8FVC0000+".parse().unwrap();
+        assert_eq!(code.len(), 4);
+        let (sw, ne) = code.bounds().unwrap();
+        assert!((sw.latitude.to_ddeg_angle() - 47.0).abs() < 1E-9);
+        assert!((ne.longitude.to_ddeg_angle() - 9.0).abs() < 1E-9);
+        // short codes can't be decoded
+        let code: PlusCode = "9G8F+6X".parse().unwrap();
+        assert!(code.is_short());
+        assert!(matches!(code.bounds(), Err(Error::InvalidPlusCode)));
+        for invalid in [
+            "",
+            "G+",
+            "8FVC00+",
+            "8FV0000+",
+            "8F000000+6X",
+            "WFVC9G8F+6X",
+            "8FVC9G8F+6",
+            "8FVC9G8F6X",
+            "8FVC9G8F+6X+",
+            "8FVC9G8F+6A",
+        ] {
+            assert!(matches!(
+                invalid.parse::<PlusCode>(),
+                Err(Error::InvalidPlusCode)
+            ));
+        }
+    }
+    #[test]
+    fn test_shorten_recover() {
+        let code: PlusCode = "9C3W9QCJ+2VX".parse().unwrap();
+        let cases = [
+            ((51.3701125, -1.217765625), "+2VX"),
+            ((51.3708675, -1.217765625), "CJ+2VX"),
+            ((51.3852125, -1.217765625), "9QCJ+2VX"),
+            ((52.0, -1.2), "9C3W9QCJ+2VX"),
+        ];
+        for ((lat, lon), expected) in cases {
+            let reference = DMS3d::from_ddeg_angles(lat, lon, None);
+            let short = code.shorten(reference).unwrap();
+            assert_eq!(short.to_string(), expected);
+            assert_eq!(short.recover(reference).unwrap(), code);
+        }
+        let zurich = DMS3d::from_ddeg_angles(47.4, 8.6, None);
+        let cases = [
+            ("9G8F+6X", "8FVC9G8F+6X"),
+            ("8F+GG", "8FVCCJ8F+GG"),
+            ("CJ+2VX", "8FVCCHCJ+2VX"),
+        ];
+        for (short, expected) in cases {
+            let short: PlusCode = short.parse().unwrap();
+            assert_eq!(short.recover(zurich).unwrap().as_str(), expected);
+        }
+        // nearest area lies across the cell boundary
+        let short: PlusCode = "XXXX+XX".parse().unwrap();
+        let reference = DMS3d::from_ddeg_angles(47.0001, 8.0001, None);
+        let full = short.recover(reference).unwrap();
+        assert_eq!(full.as_str(), "8FR9XXXX+XX");
+        // recovered codes are truncated to 15 digits
+        let cases = [
+            ("9G8F+6X22222222", "8FVC9G8F+6X22222"),
+            ("+222222222222222", "8FVCCJ22+2222222"),
+        ];
+        for (short, expected) in cases {
+            let short: PlusCode = short.parse().unwrap();
+            let full = short.recover(zurich).unwrap();
+            assert_eq!(full.as_str(), expected);
+            assert_eq!(full.len(), 15);
+        }
+        // padded or too short codes can't be shortened
+        let padded: PlusCode = "8FVC0000+".parse().unwrap();
+        assert!(matches!(
+            padded.shorten(zurich),
+            Err(Error::InvalidPlusCode)
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+use dms_coordinates::{
+    rhumb::{direct, inverse},
+    Cardinal, DMS3d, Ellipsoid, Error,
+};
+
+#[cfg(test)]
+mod rhumb {
+    use super::*;
+    #[test]
+    fn test_inverse() {
+        let cases = [
+            // Plymouth -> Boston
+            (
+                (50.3639, -4.1569),
+                (42.3511, -71.0408),
+                5_210_756.527_5,
+                260.157_947_555,
+            ),
+            // across the antimeridian
+            (
+                (10.0, 170.0),
+                (-20.0, -160.0),
+                4_671_614.401_6,
+                135.258_865_539,
+            ),
+            ((-30.0, 20.0), (40.0, 25.0), 7_766_961.853_4, 3.827_009_282),
+            // along a meridian
+            ((0.0, 0.0), (60.0, 0.0), 6_654_072.819_5, 0.0),
+            // along a parallel
+            ((45.0, 10.0), (45.0, -20.0), 2_365_405.052_8, 270.0),
+            (
+                (45.0, 10.0),
+                (45.000_000_000_1, -20.0),
+                2_365_405.052_8,
+                270.0,
+            ),
+        ];
+        for (p1, p2, distance, bearing) in cases {
+            let rhumb = inverse(Ellipsoid::WGS84, p1, p2);
+            assert!((rhumb.distance - distance).abs() < 1E-3);
+            assert!((rhumb.bearing - bearing).abs() < 1E-8);
+            // reverse course
+            let rhumb = inverse(Ellipsoid::WGS84, p2, p1);
+            assert!((rhumb.distance - distance).abs() < 1E-3);
+            assert!((rhumb.bearing - (bearing + 180.0) % 360.0).abs() < 1E-8);
+            // destination
+            let (lat, lon) = direct(Ellipsoid::WGS84, p1, rhumb.bearing + 180.0, distance).unwrap();
+            assert!((lat - p2.0).abs() < 1E-9);
+            assert!((lon - p2.1).abs() < 1E-9);
+        }
+    }
+    #[test]
+    fn test_sphere() {
+        // Mercator sailing on a sphere
+        let sphere = Ellipsoid::sphere(6_371E3);
+        let rhumb = inverse(sphere, (50.3639, -4.1569), (42.3511, -71.0408));
+        assert!((rhumb.distance - 5_196_341.3).abs() < 0.1);
+        let course = rhumb.course();
+        assert_eq!(course.degrees, 260);
+        assert_eq!(course.minutes, 7);
+        assert!((course.seconds - 38.0).abs() < 1.0);
+        assert_eq!(course.cardinal, None);
+        assert_eq!(rhumb.cardinal(), Cardinal::West);
+        // east-west special case
+        let rhumb = inverse(sphere, (0.0, 179.0), (0.0, -179.0));
+        assert!((rhumb.distance - 2.0 * 6_371E3 * 1.0_f64.to_radians()).abs() < 1E-6);
+        assert_eq!(rhumb.bearing, 90.0);
+        assert_eq!(rhumb.cardinal(), Cardinal::East);
+    }
+    #[test]
+    fn test_poles() {
+        let quarter_meridian = 10_001_965.729_3;
+        // reaching the pole
+        let (lat, _) = direct(Ellipsoid::WGS84, (0.0, 0.0), 0.0, quarter_meridian).unwrap();
+        assert!((lat - 90.0).abs() < 1E-9);
+        let rhumb = inverse(Ellipsoid::WGS84, (89.0, 0.0), (90.0, 50.0));
+        assert_eq!(rhumb.bearing, 0.0);
+        assert!((rhumb.distance - 111_693.864_9).abs() < 1E-3);
+        // passing the pole
+        let result = direct(Ellipsoid::WGS84, (80.0, 0.0), 10.0, 3_000_000.0);
+        assert!(matches!(result, Err(Error::InvalidLatitude)));
+        let result = direct(Ellipsoid::WGS84, (-89.0, 0.0), 180.0, 111_700.0);
+        assert!(matches!(result, Err(Error::InvalidLatitude)));
+    }
+    #[test]
+    fn test_dms3d() {
+        let start = DMS3d::from_ddeg_angles(10.0, 170.0, Some(5.0));
+        let end = DMS3d::from_ddeg_angles(-20.0, -160.0, None);
+        let rhumb = start.rhumb_line(end);
+        assert_eq!(rhumb.cardinal(), Cardinal::SouthEast);
+        let dest = start
+            .rhumb_destination(rhumb.bearing, rhumb.distance)
+            .unwrap();
+        assert_eq!(dest.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(dest.longitude.cardinal, Some(Cardinal::West));
+        assert!((dest.latitude.to_ddeg_angle() - -20.0).abs() < 1E-9);
+        assert!((dest.longitude.to_ddeg_angle() - -160.0).abs() < 1E-9);
+        assert_eq!(dest.altitude, Some(5.0));
+        // rhumb line is longer than the geodesic
+        let geodesic = start
+            .geodesic_inverse(end, dms_coordinates::geodesic::Method::Karney)
+            .unwrap();
+        assert!(rhumb.distance > geodesic.distance);
+        let rhumb = start.rhumb_line_on(end, Ellipsoid::INTERNATIONAL_1924);
+        assert!((rhumb.distance - 4_671_614.4).abs() < 1000.0);
+    }
+}
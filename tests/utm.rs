@@ -0,0 +1,119 @@
+use dms_coordinates::{
+    utm::{band, zone},
+    Cardinal, DMS3d, Ellipsoid, Error, UTM,
+};
+
+#[cfg(test)]
+mod utm {
+    use super::*;
+    #[test]
+    fn test_zones() {
+        assert_eq!(zone(0.0, 0.0), 31);
+        assert_eq!(zone(0.0, -180.0), 1);
+        assert_eq!(zone(0.0, 180.0), 1);
+        assert_eq!(zone(0.0, 179.9), 60);
+        assert_eq!(zone(-33.857, 151.215), 56);
+        // Norway
+        assert_eq!(zone(60.0, 2.9), 31);
+        assert_eq!(zone(60.0, 5.0), 32);
+        assert_eq!(zone(64.0, 5.0), 31);
+        // Svalbard
+        assert_eq!(zone(78.0, 8.0), 31);
+        assert_eq!(zone(78.0, 10.0), 33);
+        assert_eq!(zone(78.0, 15.0), 33);
+        assert_eq!(zone(78.0, 22.0), 35);
+        assert_eq!(zone(78.0, 32.0), 35);
+        assert_eq!(zone(78.0, 34.0), 37);
+        assert_eq!(zone(70.0, 10.0), 32);
+    }
+    #[test]
+    fn test_bands() {
+        assert_eq!(band(-80.0).unwrap(), 'C');
+        assert_eq!(band(-0.1).unwrap(), 'M');
+        assert_eq!(band(0.0).unwrap(), 'N');
+        assert_eq!(band(48.8).unwrap(), 'U');
+        assert_eq!(band(72.0).unwrap(), 'X');
+        assert_eq!(band(84.0).unwrap(), 'X');
+        assert!(matches!(band(84.1), Err(Error::InvalidLatitude)));
+        assert!(matches!(band(-80.5), Err(Error::InvalidLatitude)));
+    }
+    #[test]
+    fn test_projection() {
+        let cases = [
+            ((48.8582, 2.2945), (31, 'U', 448_251.795, 5_411_932.678)),
+            ((0.0, 0.0), (31, 'N', 166_021.443, 0.0)),
+            ((-33.857, 151.215), (56, 'H', 334_873.199, 6_252_266.092)),
+            ((60.0, 5.0), (32, 'V', 276_979.926, 6_658_157.202)),
+            ((78.0, 15.0), (33, 'X', 500_000.0, 8_658_369.586)),
+            ((-1.0, -48.5), (22, 'M', 778_234.163, 9_889_363.852)),
+        ];
+        for ((lat, lon), (zone, band, easting, northing)) in cases {
+            let utm = UTM::project(Ellipsoid::WGS84, lat, lon, None).unwrap();
+            assert_eq!(utm.zone, zone);
+            assert_eq!(utm.band, band);
+            assert!((utm.easting - easting).abs() < 1E-3);
+            assert!((utm.northing - northing).abs() < 1E-3);
+            let (latitude, longitude) = utm.unproject(Ellipsoid::WGS84);
+            assert!((latitude - lat).abs() < 1E-10);
+            assert!((longitude - lon).abs() < 1E-10);
+        }
+    }
+    #[test]
+    fn test_forced_zone() {
+        let utm = UTM::project(Ellipsoid::WGS84, 1.0, 3.0, Some(30)).unwrap();
+        assert_eq!(utm.zone, 30);
+        assert!((utm.easting - 1_168_779.742).abs() < 1E-3);
+        let (latitude, longitude) = utm.unproject(Ellipsoid::WGS84);
+        assert!((latitude - 1.0).abs() < 1E-10);
+        assert!((longitude - 3.0).abs() < 1E-10);
+        assert!(matches!(
+            UTM::project(Ellipsoid::WGS84, 1.0, 3.0, Some(61)),
+            Err(Error::InvalidZone)
+        ));
+    }
+    #[test]
+    fn test_parsing() {
+        let utm: UTM = "31U 448251.795 5411932.678".parse().unwrap();
+        assert_eq!(utm.zone, 31);
+        assert_eq!(utm.band, 'U');
+        assert!(utm.is_northern());
+        assert_eq!(utm.to_string(), "31U 448251.795 5411932.678");
+        let utm: UTM = " 22 m 778234 9889364".parse().unwrap();
+        assert_eq!(utm.band, 'M');
+        assert!(!utm.is_northern());
+        assert!(matches!("0U 1 2".parse::<UTM>(), Err(Error::InvalidZone)));
+        assert!(matches!("U 1 2".parse::<UTM>(), Err(Error::InvalidZone)));
+        assert!(matches!("31O 1 2".parse::<UTM>(), Err(Error::InvalidBand)));
+        assert!(matches!("31UV 1 2".parse::<UTM>(), Err(Error::InvalidBand)));
+        assert!(matches!(
+            "31U 448251".parse::<UTM>(),
+            Err(Error::ParsingError(_))
+        ));
+        assert!(matches!(
+            "31U 448251 5411932 12".parse::<UTM>(),
+            Err(Error::ParsingError(_))
+        ));
+    }
+    #[test]
+    fn test_dms3d() {
+        let coords = DMS3d::from_ddeg_angles(-33.857, 151.215, Some(10.0));
+        let utm = coords.to_utm().unwrap();
+        assert_eq!((utm.zone, utm.band), (56, 'H'));
+        let back = DMS3d::from_utm(utm);
+        assert_eq!(back.latitude.cardinal, Some(Cardinal::South));
+        assert_eq!(back.longitude.cardinal, Some(Cardinal::East));
+        assert_eq!(back.latitude.degrees, coords.latitude.degrees);
+        assert_eq!(back.latitude.minutes, coords.latitude.minutes);
+        assert!((back.latitude.seconds - coords.latitude.seconds).abs() < 1E-6);
+        assert!((back.longitude.seconds - coords.longitude.seconds).abs() < 1E-6);
+        assert_eq!(back.altitude, None);
+        // neighbouring zone
+        let utm = coords.to_utm_zone(55).unwrap();
+        assert_eq!(utm.zone, 55);
+        let back = DMS3d::from_utm(utm);
+        assert!((back.longitude.to_ddeg_angle() - 151.215).abs() < 1E-9);
+        // polar areas
+        let coords = DMS3d::from_ddeg_angles(85.0, 10.0, None);
+        assert!(matches!(coords.to_utm(), Err(Error::InvalidLatitude)));
+    }
+}
@@ -0,0 +1,209 @@
+#[cfg(feature = "wkt")]
+use dms_coordinates::{
+    wkt::{ByteOrder, Geometry, WktError},
+    Cardinal, DMS3d, Error,
+};
+
+#[cfg(feature = "wkt")]
+mod wkt {
+    use super::*;
+    fn fixture(name: &str) -> Vec<u8> {
+        let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+        std::fs::read(path).unwrap()
+    }
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+    fn square() -> Geometry {
+        Geometry::Polygon(vec![vec![
+            DMS3d::from_ddeg_angles(0.0, 0.0, Some(1.0)),
+            DMS3d::from_ddeg_angles(0.0, 1.0, Some(1.0)),
+            DMS3d::from_ddeg_angles(1.0, 1.0, Some(1.0)),
+            DMS3d::from_ddeg_angles(0.0, 0.0, Some(1.0)),
+        ]])
+    }
+    #[test]
+    fn test_wkt() {
+        let eiffel = Geometry::from(DMS3d::from_ddeg_angles(48.858222, 2.2945, Some(330.0)));
+        assert_eq!(eiffel.to_string(), "POINT Z (2.2945 48.858222 330)");
+        let path = Geometry::from(vec![
+            DMS3d::from_ddeg_angles(48.8566, 2.3522, None),
+            DMS3d::from_ddeg_angles(-33.8688, -151.2093, None),
+        ]);
+        assert_eq!(
+            path.to_string(),
+            "LINESTRING (2.3522 48.8566, -151.2093 -33.8688)"
+        );
+        assert_eq!(
+            square().to_string(),
+            "POLYGON Z ((0 0 1, 1 0 1, 1 1 1, 0 0 1))"
+        );
+        assert_eq!(
+            Geometry::LineString(Vec::new()).to_string(),
+            "LINESTRING EMPTY"
+        );
+        for geometry in [eiffel, path, square(), Geometry::Polygon(Vec::new())] {
+            let parsed: Geometry = geometry.to_string().parse().unwrap();
+            assert_eq!(parsed, geometry);
+        }
+
+        let parsed: Geometry = "point(-70.6693 -33.4489)".parse().unwrap();
+        match parsed {
+            Geometry::Point(coords) => {
+                assert_eq!(coords.latitude.cardinal, Some(Cardinal::South));
+                assert_eq!(coords.longitude.cardinal, Some(Cardinal::West));
+                assert!((coords.latitude.to_ddeg_angle() + 33.4489).abs() < 1E-12);
+                assert_eq!(coords.altitude, None);
+            }
+            _ => panic!("expecting a point"),
+        }
+        // three values without Z marker
+        let parsed: Geometry = "POINT (2.2945 48.858222 330)".parse().unwrap();
+        assert_eq!(parsed.to_string(), "POINT Z (2.2945 48.858222 330)");
+        let parsed: Geometry = "POLYGON((0 0,1 0,1 1,0 0),(0.2 0.2,0.4 0.2,0.4 0.4,0.2 0.2))"
+            .parse()
+            .unwrap();
+        match parsed {
+            Geometry::Polygon(rings) => assert_eq!(rings.len(), 2),
+            _ => panic!("expecting a polygon"),
+        }
+    }
+    #[test]
+    fn test_wkt_errors() {
+        for (wkt, expected) in [
+            ("", WktError::InvalidText),
+            ("POINT", WktError::InvalidText),
+            ("POINT (1)", WktError::InvalidText),
+            ("POINT (1 2 3 4)", WktError::InvalidText),
+            ("POINT Z (1 2)", WktError::InvalidText),
+            ("POINT (1 2", WktError::InvalidText),
+            ("POINT (1 2) POINT", WktError::InvalidText),
+            ("POINT (a 2)", WktError::InvalidText),
+            ("POINT EMPTY", WktError::UnsupportedGeometry),
+            ("POINT M (1 2 3)", WktError::UnsupportedGeometry),
+            ("MULTIPOINT ((1 2))", WktError::UnsupportedGeometry),
+            ("LINESTRING (1 2)", WktError::TooFewPositions),
+            ("POLYGON ((0 0, 1 0, 0 0))", WktError::TooFewPositions),
+            ("POLYGON ((0 0, 1 0, 1 1, 0 1))", WktError::UnclosedRing),
+        ] {
+            match wkt.parse::<Geometry>() {
+                Err(Error::WktError(e)) => assert_eq!(e, expected, "{}", wkt),
+                _ => panic!("{} should fail", wkt),
+            }
+        }
+        assert!(matches!(
+            "POINT (1 91)".parse::<Geometry>(),
+            Err(Error::InvalidLatitude)
+        ));
+        assert!(matches!(
+            "POINT (181 1)".parse::<Geometry>(),
+            Err(Error::InvalidLongitude)
+        ));
+    }
+    #[test]
+    fn test_wkb_fixtures() {
+        let eiffel = Geometry::from(DMS3d::from_ddeg_angles(48.858222, 2.2945, Some(330.0)));
+        let bytes = fixture("point_z_ndr.wkb");
+        assert_eq!(Geometry::from_wkb(&bytes).unwrap(), eiffel);
+        assert_eq!(eiffel.to_wkb(ByteOrder::LittleEndian), bytes);
+
+        let path = Geometry::from(vec![
+            DMS3d::from_ddeg_angles(48.8566, 2.3522, None),
+            DMS3d::from_ddeg_angles(-33.8688, -151.2093, None),
+        ]);
+        let bytes = fixture("linestring_xdr.wkb");
+        assert_eq!(Geometry::from_wkb(&bytes).unwrap(), path);
+        assert_eq!(path.to_wkb(ByteOrder::BigEndian), bytes);
+
+        let bytes = fixture("polygon_z_ewkb_ndr.wkb");
+        assert_eq!(Geometry::from_ewkb(&bytes).unwrap(), (square(), Some(4326)));
+        assert_eq!(Geometry::from_wkb(&bytes).unwrap(), square());
+        assert_eq!(square().to_ewkb(ByteOrder::LittleEndian, Some(4326)), bytes);
+
+        let point = Geometry::from(DMS3d::from_ddeg_angles(2.0, 1.0, None));
+        let bytes = fixture("point_ewkb_xdr.wkb");
+        assert_eq!(
+            Geometry::from_ewkb(&bytes).unwrap(),
+            (point.clone(), Some(4326))
+        );
+        assert_eq!(point.to_ewkb(ByteOrder::BigEndian, Some(4326)), bytes);
+        // PostGIS: ST_AsEWKB('SRID=4326;POINT(1 2)')
+        let bytes = hex("0101000020E6100000000000000000F03F0000000000000040");
+        assert_eq!(point.to_ewkb(ByteOrder::LittleEndian, Some(4326)), bytes);
+        assert_eq!(
+            Geometry::from_ewkb(&bytes).unwrap(),
+            (point.clone(), Some(4326))
+        );
+        // no SRID
+        let bytes = point.to_ewkb(ByteOrder::LittleEndian, None);
+        assert_eq!(bytes, point.to_wkb(ByteOrder::LittleEndian));
+        assert_eq!(Geometry::from_ewkb(&bytes).unwrap(), (point, None));
+    }
+    #[test]
+    fn test_wkb_round_trip() {
+        let geometries = [
+            Geometry::from(DMS3d::from_ddeg_angles(-33.4489, -70.6693, None)),
+            Geometry::from(vec![
+                DMS3d::from_ddeg_angles(48.8566, 2.3522, Some(35.0)),
+                DMS3d::from_ddeg_angles(-33.8688, -151.2093, Some(58.0)),
+            ]),
+            Geometry::LineString(Vec::new()),
+            square(),
+            Geometry::Polygon(Vec::new()),
+        ];
+        for geometry in geometries {
+            for order in [ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+                let wkb = geometry.to_wkb(order);
+                assert_eq!(Geometry::from_wkb(&wkb).unwrap(), geometry);
+                let ewkb = geometry.to_ewkb(order, Some(3857));
+                assert_eq!(
+                    Geometry::from_ewkb(&ewkb).unwrap(),
+                    (geometry.clone(), Some(3857))
+                );
+            }
+        }
+    }
+    #[test]
+    fn test_wkb_errors() {
+        let bytes = fixture("point_z_ndr.wkb");
+        let expect = |bytes: &[u8], expected: WktError| match Geometry::from_wkb(bytes) {
+            Err(Error::WktError(e)) => assert_eq!(e, expected),
+            _ => panic!("expecting {:?}", expected),
+        };
+        expect(&[], WktError::InvalidLength);
+        expect(&bytes[..bytes.len() - 1], WktError::InvalidLength);
+        let mut extra = bytes.clone();
+        extra.push(0);
+        expect(&extra, WktError::InvalidLength);
+        let mut order = bytes.clone();
+        order[0] = 2;
+        expect(&order, WktError::InvalidByteOrder);
+        // MULTIPOINT
+        expect(&hex("010400000000000000"), WktError::UnsupportedGeometry);
+        // POINT M
+        let mut m = bytes.clone();
+        m[1..5].copy_from_slice(&2001_u32.to_le_bytes());
+        expect(&m, WktError::UnsupportedGeometry);
+        // POINT EMPTY
+        expect(
+            &hex("0101000000000000000000F87F000000000000F87F"),
+            WktError::UnsupportedGeometry,
+        );
+        // LINESTRING with a single position
+        expect(
+            &hex("010200000001000000000000000000F03F0000000000000040"),
+            WktError::TooFewPositions,
+        );
+        // huge position count
+        expect(&hex("0102000000FFFFFFFF"), WktError::InvalidLength);
+        // POINT (1 91)
+        let bytes = hex("0101000000000000000000F03F0000000000C05640");
+        assert!(matches!(
+            Geometry::from_wkb(&bytes),
+            Err(Error::InvalidLatitude)
+        ));
+    }
+}